@@ -127,6 +127,10 @@ impl BatchCommand {
                     continue;
                 }
             };
+            if !success.warnings.is_empty() {
+                let warnings = SourceIssuesRenderer::render(&success.warnings, &source.directory);
+                debug!("{} for {source}\n{warnings}", "Warnings".bold());
+            }
             if success.verified() {
                 debug!("{} {}", "Verified".bold(), source);
             } else {
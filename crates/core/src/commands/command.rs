@@ -20,6 +20,7 @@ pub enum Command {
         SharedOptions,
         VerifyOptions,
         TargetOptions,
+        Id3Options,
         SpectrogramOptions,
         SoxOptions,
         CopyOptions,
@@ -57,8 +58,23 @@ pub enum Command {
     /// Generate markdown documentation for configuration options.
     Docs,
 
+    /// Run every verify check of a FLAC source and explain the result of each.
+    ///
+    /// Also explains which torrents of the group were matched as existing formats.
+    #[options(
+        SourceArg,
+        ConfigOptions,
+        SharedOptions,
+        CacheOptions,
+        TargetOptions,
+        Id3Options,
+        VerifyOptions,
+        ReportOptions
+    )]
+    Explain,
+
     /// Inspect audio file metadata in a directory.
-    #[options(InspectArg)]
+    #[options(InspectArg, ConfigOptions, Id3Options)]
     Inspect,
 
     /// Add FLAC sources to the queue without transcoding
@@ -82,6 +98,7 @@ pub enum Command {
         ConfigOptions,
         SharedOptions,
         TargetOptions,
+        Id3Options,
         SoxOptions,
         CopyOptions,
         FileOptions,
@@ -101,6 +118,7 @@ pub enum Command {
         ConfigOptions,
         SharedOptions,
         TargetOptions,
+        Id3Options,
         UploadOptions,
         QbitOptions,
         QbitUploadOptions,
@@ -115,6 +133,7 @@ pub enum Command {
         SharedOptions,
         CacheOptions,
         TargetOptions,
+        Id3Options,
         VerifyOptions,
         ReportOptions
     )]
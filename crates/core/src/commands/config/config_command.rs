@@ -11,6 +11,7 @@ pub struct ConfigCommand {
     cross_config_options: Ref<CrossConfigOptions>,
     cross_options: Ref<CrossOptions>,
     file_options: Ref<FileOptions>,
+    id3_options: Ref<Id3Options>,
     queue_add_args: Ref<QueueAddArgs>,
     report_options: Ref<ReportOptions>,
     runner_options: Ref<RunnerOptions>,
@@ -70,6 +71,7 @@ impl ConfigCommand {
             yaml_to_value(&*self.cross_config_options)?,
             yaml_to_value(&*self.cross_options)?,
             yaml_to_value(&*self.file_options)?,
+            yaml_to_value(&*self.id3_options)?,
             yaml_to_value(&*self.queue_add_args)?,
             yaml_to_value(&*self.report_options)?,
             yaml_to_value(&*self.runner_options)?,
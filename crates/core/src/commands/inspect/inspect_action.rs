@@ -13,6 +13,8 @@ pub(crate) enum InspectAction {
     ReadFlacFile,
     #[error("read MPEG file")]
     ReadMpegFile,
+    #[error("map ID3 tags")]
+    MapId3Tags,
 }
 
 /// Errors returned when inspecting audio files.
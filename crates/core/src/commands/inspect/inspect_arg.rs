@@ -6,6 +6,14 @@ pub struct InspectArg {
     /// Path to directory containing audio files.
     #[arg(value_name = "PATH")]
     pub inspect_path: PathBuf,
+
+    /// Show the `ID3v2` frames MP3 transcodes of each FLAC would be tagged with instead.
+    ///
+    /// Applies the `id3_mapping`, `id3_artist_separator`, `id3_genre_separator` and
+    /// `id3_version` options without transcoding. Multiple values of a frame are shown on
+    /// separate lines.
+    #[arg(long)]
+    pub id3_dry_run: bool,
 }
 
 impl OptionsContract for InspectArg {
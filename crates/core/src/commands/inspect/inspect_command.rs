@@ -4,13 +4,20 @@ use crate::prelude::*;
 #[injectable]
 pub(crate) struct InspectCommand {
     arg: Ref<InspectArg>,
+    id3_options: Ref<Id3Options>,
 }
 
 impl InspectCommand {
     /// Execute [`InspectCommand`] from the CLI.
+    ///
+    /// With `id3_dry_run` the `ID3v2` frames of MP3 transcodes are shown instead.
     pub(crate) fn execute_cli(&self) -> Result<bool, Failure<InspectAction>> {
         let factory = InspectFactory::new(true);
-        let output = factory.create(&self.arg.inspect_path)?;
+        let output = if self.arg.id3_dry_run {
+            factory.create_id3_dry_run(&self.arg.inspect_path, &self.id3_options)?
+        } else {
+            factory.create(&self.arg.inspect_path)?
+        };
         println!("{output}");
         Ok(true)
     }
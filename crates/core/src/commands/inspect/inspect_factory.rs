@@ -1,6 +1,7 @@
 use super::picture_info::PictureInfo;
 use super::track_info::{TagEntry, TrackInfo};
 use crate::prelude::*;
+use lofty::id3::v2::Frame;
 
 /// Max width of the tag value column.
 const MAX_VALUE_WIDTH: usize = 46;
@@ -39,11 +40,53 @@ impl InspectFactory {
         Ok((properties, tags))
     }
 
+    /// Get the `ID3v2` frames MP3 transcodes of each FLAC in a directory would be tagged with.
+    ///
+    /// Frames are mapped by [`map_id3_tags`] with the `options` and listed in the order they
+    /// are written. Multiple values of a frame are shown on separate lines.
+    pub(crate) fn create_id3_dry_run(
+        &self,
+        dir: &Path,
+        options: &Id3Options,
+    ) -> Result<String, Failure<InspectAction>> {
+        let mut output = String::new();
+        for (i, flac) in Collector::get_tracks(&dir.to_path_buf()).iter().enumerate() {
+            if i > 0 {
+                output.push_str(&self.divider());
+            }
+            let tags =
+                map_id3_tags(flac, options).map_err(Failure::wrap(InspectAction::MapId3Tags))?;
+            let mut frames: Vec<(String, String)> = (&tags)
+                .into_iter()
+                .map(|frame| (get_id3v2_frame_key(frame), format_frame_value(frame)))
+                .collect();
+            frames.sort();
+            let sub_path = flac
+                .path
+                .strip_prefix(dir)
+                .unwrap_or(&flac.path)
+                .to_string_lossy();
+            output.push('\n');
+            output.push_str(&self.style_path(sub_path));
+            output.push_str("\n\n");
+            let mut builder = TableBuilder::new()
+                .ansi(self.style)
+                .max_column_width(1, MAX_VALUE_WIDTH)
+                .max_cell_lines(MAX_VALUE_LINES);
+            for (key, value) in frames {
+                builder = builder.row([self.style_key(key), value]);
+            }
+            output.push_str(&builder.build());
+        }
+        Ok(output)
+    }
+
     /// Format the audio properties table.
     pub(crate) fn format_properties_table(&self, tracks: &[TrackInfo]) -> String {
         const L: bool = false;
         const R: bool = true;
         let has_flac_columns = tracks.iter().any(|t| t.bit_depth.is_some());
+        let has_lame_columns = tracks.iter().any(|t| t.lame.is_some());
         let has_mixed_types = tracks
             .first()
             .is_some_and(|first| tracks.iter().any(|t| t.file_type != first.file_type));
@@ -67,6 +110,18 @@ impl InspectFactory {
             headers.push(vec!["Bit", "Depth"]);
             align.push(L);
         }
+        if has_lame_columns {
+            headers.extend([
+                vec!["Encoder"],
+                vec!["Preset"],
+                vec!["VBR", "Method"],
+                vec!["Low", "Pass", &khz],
+                vec!["Delay"],
+                vec!["Padding"],
+                vec!["Music", "CRC"],
+            ]);
+            align.extend([L, L, L, R, R, R, L]);
+        }
         let headers = self.style_headers(headers);
         let mut builder = TableBuilder::new()
             .ansi(self.style)
@@ -91,11 +146,32 @@ impl InspectFactory {
             if has_flac_columns {
                 row.push(track.bit_depth.map_or_else(String::new, |d| d.to_string()));
             }
+            if has_lame_columns {
+                row.extend(format_lame_columns(track.lame.as_ref()));
+            }
             builder = builder.row(row);
         }
         builder.build()
     }
 
+    /// Get the audio properties of a track as label and value pairs.
+    ///
+    /// Values match the columns of the properties table.
+    pub(crate) fn get_properties(&self, track: &TrackInfo) -> Vec<(&'static str, String)> {
+        let mut properties = vec![
+            ("Type", track.file_type.clone()),
+            ("Time", track.format_duration()),
+            ("Size", self.format_size(track.file_size)),
+            ("Bit Rate", format!("{} kbps", track.bit_rate)),
+            ("Sample Rate", format!("{} kHz", track.format_sample_rate())),
+            ("Channels", track.channels.clone()),
+        ];
+        if let Some(bit_depth) = track.bit_depth {
+            properties.push(("Bit Depth", bit_depth.to_string()));
+        }
+        properties
+    }
+
     /// Format all per-track tags and pictures.
     pub(crate) fn format_all_tags(&self, tracks: &[TrackInfo]) -> String {
         let mut output = String::new();
@@ -183,6 +259,24 @@ impl InspectFactory {
 }
 
 impl TrackInfo {
+    /// Path relative to the inspected directory.
+    #[must_use]
+    pub(crate) fn get_sub_path(&self) -> &str {
+        &self.sub_path
+    }
+
+    /// Disc and track number from tags, e.g. `Disc 1 Track 3`.
+    ///
+    /// Returns `None` if the track number is not tagged.
+    #[must_use]
+    pub(crate) fn get_position(&self) -> Option<String> {
+        let track = self.track.as_ref()?;
+        Some(match &self.disc {
+            Some(disc) => format!("Disc {disc} Track {track}"),
+            None => format!("Track {track}"),
+        })
+    }
+
     /// Duration formatted as MM:SS.
     #[expect(
         clippy::integer_division,
@@ -271,6 +365,58 @@ impl PictureInfo {
     }
 }
 
+/// Format the LAME tag columns of a track, or empty cells if there is no LAME tag.
+fn format_lame_columns(lame: Option<&LameTag>) -> [String; 7] {
+    let Some(lame) = lame else {
+        return Default::default();
+    };
+    let crc = if lame.is_tag_crc_valid {
+        format!("{:04X}", lame.music_crc)
+    } else {
+        format!("{:04X} (bad tag CRC)", lame.music_crc)
+    };
+    [
+        lame.encoder.clone(),
+        lame.format_preset(),
+        lame.format_vbr_method(),
+        format_lowpass(lame.lowpass),
+        lame.encoder_delay.to_string(),
+        lame.padding.to_string(),
+        crc,
+    ]
+}
+
+/// Format the value of an `ID3v2` frame with null separated values on separate lines.
+///
+/// Pictures are shown by their MIME type and size, other binary frames are empty.
+fn format_frame_value(frame: &Frame<'_>) -> String {
+    let value = match frame {
+        Frame::Text(text) => text.value.to_string(),
+        Frame::UserText(text) => text.content.to_string(),
+        Frame::Comment(comment) => comment.content.to_string(),
+        Frame::Timestamp(timestamp) => timestamp.timestamp.to_string(),
+        Frame::Picture(picture) => {
+            let picture = &picture.picture;
+            let mime = picture
+                .mime_type()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            format!("{mime} {} bytes", picture.data().len())
+        }
+        _ => String::new(),
+    };
+    value.replace('\0', "\n")
+}
+
+/// Lowpass frequency formatted as kHz.
+#[expect(
+    clippy::integer_division,
+    reason = "intentional integer division for kHz conversion"
+)]
+fn format_lowpass(lowpass: u32) -> String {
+    format!("{}.{}", lowpass / 1000, lowpass % 1000 / 100)
+}
+
 /// Split a CamelCase string into space-separated words.
 fn split_camel_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
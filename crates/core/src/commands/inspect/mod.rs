@@ -4,7 +4,6 @@ pub(crate) use inspect_action::*;
 pub(crate) use inspect_arg::*;
 pub(crate) use inspect_command::*;
 pub(crate) use inspect_factory::*;
-#[cfg(test)]
 pub(crate) use track_info::*;
 
 mod inspect_action;
@@ -203,3 +203,68 @@ fn format_tags_table_with_multiline_value() {
     // Assert
     assert_snapshot!(output);
 }
+
+/// Test that properties of a hi-res FLAC include the bit depth.
+#[test]
+fn inspect_factory_get_properties() {
+    // Arrange
+    let factory = InspectFactory::new(false);
+    let track = TrackInfo {
+        duration: Duration::from_secs(245),
+        file_size: 47_710_208,
+        bit_rate: 1558,
+        sample_rate: 96000,
+        bit_depth: Some(24),
+        ..TrackInfo::mock_flac()
+    };
+
+    // Act
+    let properties = factory.get_properties(&track);
+
+    // Assert
+    assert_eq!(
+        properties,
+        vec![
+            ("Type", "FLAC".to_owned()),
+            ("Time", "04:05".to_owned()),
+            ("Size", "45.5 MiB".to_owned()),
+            ("Bit Rate", "1558 kbps".to_owned()),
+            ("Sample Rate", "96 kHz".to_owned()),
+            ("Channels", "2".to_owned()),
+            ("Bit Depth", "24".to_owned()),
+        ]
+    );
+    assert_eq!(track.get_position(), Some("Disc 1 Track 1".to_owned()));
+}
+
+/// Test that [`InspectFactory::create_id3_dry_run`] lists the mapped `ID3v2` frames of a FLAC.
+#[test]
+fn inspect_factory_id3_dry_run() {
+    // Arrange
+    let dir = TempDirectory::create("inspect_factory_id3_dry_run");
+    PcmFlacGenerator::new()
+        .with_filename("01 Track.flac")
+        .with_vorbis_tag("TITLE", "Track")
+        .with_vorbis_tag("ARTIST", "Artist")
+        .with_vorbis_tag("TRACKNUMBER", "1")
+        .with_vorbis_tag("CATALOGNUMBER", "CAT-1")
+        .generate(&dir);
+    let options = Id3Options {
+        id3_mapping: vec![
+            "CATALOGNUMBER=TXXX:CATALOG NUMBER"
+                .parse()
+                .expect("should parse"),
+        ],
+        id3_version: Id3Version::V23,
+        ..Id3Options::default()
+    };
+    let factory = InspectFactory::new(false);
+
+    // Act
+    let output = factory
+        .create_id3_dry_run(&dir, &options)
+        .expect("should map tags");
+
+    // Assert
+    assert_snapshot!(output);
+}
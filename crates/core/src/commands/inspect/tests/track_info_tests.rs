@@ -89,8 +89,10 @@ async fn transcode_to_mp3(
         variant: Variant::Transcode(
             Decode {
                 input: flac.path.clone(),
+                range: None,
                 resample_rate: None,
                 repeatable: true,
+                backend: ResampleBackend::Sox,
                 sox,
             },
             Encode {
@@ -99,7 +101,11 @@ async fn transcode_to_mp3(
             },
         ),
         tags: Some(flac.id3_tags()?.clone()),
+        id3_version: Id3Version::V24,
         exclude_vorbis_comments: Vec::new(),
+        replay_gain: None,
+        comments: Vec::new(),
+        artwork: None,
     };
     job.execute().await
 }
@@ -49,6 +49,8 @@ pub(crate) struct TrackInfo {
     pub(super) channels: String,
     /// Bit depth (FLAC only).
     pub(super) bit_depth: Option<u8>,
+    /// LAME tag of the Xing header (MP3 only).
+    pub(super) lame: Option<LameTag>,
     /// Tag entries.
     pub(super) tags: Vec<TagEntry>,
     /// Embedded pictures.
@@ -78,6 +80,7 @@ impl TrackInfo {
             sample_rate: 44100,
             channels: "2".to_owned(),
             bit_depth: Some(16),
+            lame: None,
             tags: Vec::new(),
             pictures: Vec::new(),
             parsing_mode: None,
@@ -99,6 +102,18 @@ impl TrackInfo {
             sample_rate: 44100,
             channels: "Joint stereo".to_owned(),
             bit_depth: None,
+            lame: Some(LameTag {
+                encoder: "LAME3.100".to_owned(),
+                revision: 0,
+                vbr_method: 1,
+                lowpass: 20_500,
+                encoder_delay: 576,
+                padding: 1260,
+                preset: 320,
+                music_length: 2_457_600,
+                music_crc: 0xBEEF,
+                is_tag_crc_valid: true,
+            }),
             tags: Vec::new(),
             pictures: Vec::new(),
             parsing_mode: None,
@@ -122,7 +137,7 @@ impl TrackInfo {
     }
 
     /// Read metadata from a single audio file, dispatching on extension.
-    pub(super) fn read(base: &Path, path: &Path) -> Result<Self, Failure<InspectAction>> {
+    pub(crate) fn read(base: &Path, path: &Path) -> Result<Self, Failure<InspectAction>> {
         let mut file =
             File::open(path).map_err(Failure::wrap_with_path(InspectAction::OpenFile, path))?;
         let file_size = file
@@ -173,6 +188,7 @@ impl TrackInfo {
             sample_rate: props.sample_rate(),
             channels: props.channels().to_string(),
             bit_depth: Some(props.bit_depth()),
+            lame: None,
             tags: collect_tags(&tagged),
             pictures: collect_pictures(&tagged),
             parsing_mode: None,
@@ -195,6 +211,7 @@ impl TrackInfo {
             sample_rate: props.sample_rate(),
             channels: format_channel_mode(*props.channel_mode()),
             bit_depth: None,
+            lame: None,
             tags: collect_tags(&tagged),
             pictures: collect_pictures(&tagged),
             parsing_mode: None,
@@ -260,6 +277,7 @@ fn log_parsing_fallbacks(tracks: &[TrackInfo]) {
 /// - Returns the result from the strictest mode that succeeds
 /// - Only retries on `BadTimestamp` errors; other errors propagate immediately
 /// - Populates `parsing_mode` and `parsing_error` on the returned [`TrackInfo`]
+/// - Populates `lame` from the Xing header of the first MPEG frame
 fn read_mpeg_with_fallback(
     file: &mut File,
     path: &Path,
@@ -282,6 +300,9 @@ fn read_mpeg_with_fallback(
                 let mut info = TrackInfo::from_mpeg(mpeg);
                 info.parsing_mode = Some(mode);
                 info.parsing_error = last_error.map(|e| format!("{e}"));
+                info.lame = XingHeader::read(path)
+                    .map_err(Failure::wrap_with_path(InspectAction::ReadMpegFile, path))?
+                    .and_then(|header| header.lame);
                 return Ok(info);
             }
             Err(e) => {
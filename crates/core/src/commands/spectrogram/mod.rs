@@ -1,17 +1,27 @@
 //! Spectrogram generation for FLAC files.
 
+pub(crate) use native_spectrogram::*;
 pub(crate) use size::*;
 pub(crate) use spectrogram_action::*;
+pub(crate) use spectrogram_backend::*;
 pub(crate) use spectrogram_command::*;
+pub(crate) use spectrogram_image::*;
 pub(crate) use spectrogram_job::*;
 pub(crate) use spectrogram_job_factory::*;
+pub(crate) use spectrogram_review::*;
 pub(crate) use spectrogram_status::*;
+pub(crate) use spectrogram_variant::*;
 
+mod native_spectrogram;
 mod size;
 mod spectrogram_action;
+mod spectrogram_backend;
 mod spectrogram_command;
+mod spectrogram_image;
 mod spectrogram_job;
 mod spectrogram_job_factory;
+mod spectrogram_review;
 mod spectrogram_status;
+mod spectrogram_variant;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,344 @@
+use crate::prelude::*;
+use claxon::Error as ClaxonError;
+use claxon::FlacReader;
+use claxon::frame::Block;
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::collections::VecDeque;
+
+/// Shape of the Kaiser window, suited to the default range of `sox spectrogram -z 120`.
+const KAISER_BETA: f64 = 12.27;
+
+/// Level in dB of each FFT bin of each column of a spectrogram.
+pub(crate) struct SpectrogramLevels {
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Position of the first column in the track in seconds.
+    pub start: f64,
+    /// Duration covered by the columns in seconds.
+    pub duration: f64,
+    /// Dynamic range in dB below full scale.
+    pub range: f64,
+    /// Level in dB of each bin from DC to the Nyquist frequency, for each column.
+    pub columns: Vec<Vec<f64>>,
+}
+
+impl SpectrogramLevels {
+    /// Number of bins in each column.
+    #[must_use]
+    pub(crate) fn rows(&self) -> usize {
+        self.columns.first().map(Vec::len).unwrap_or_default()
+    }
+}
+
+/// Render a spectrogram image of a FLAC in process.
+///
+/// Equivalent to `sox input -n remix {channel} spectrogram -x {width} -y {height} -z {range}
+/// -w Kaiser`:
+/// - [`Size::Zoom`] covers the `duration` of the variant from `zoom_start` seconds
+/// - [`Size::Full`] covers the whole track
+pub(crate) fn render_spectrogram(
+    source_path: &Path,
+    output_path: &Path,
+    title: &str,
+    variant: &SpectrogramVariant,
+    zoom_start: u32,
+) -> Result<(), Failure<SpectrogramAction>> {
+    let levels = analyze(source_path, variant, zoom_start).map_err(Failure::wrap_with_path(
+        SpectrogramAction::Decode,
+        source_path,
+    ))?;
+    draw_spectrogram(&levels, title)
+        .save(output_path)
+        .map_err(Failure::wrap_with_path(
+            SpectrogramAction::GenerateSpectrogram,
+            output_path,
+        ))
+}
+
+/// Decode the channel of a FLAC selected by the variant into [`SpectrogramLevels`].
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+fn analyze(
+    path: &Path,
+    variant: &SpectrogramVariant,
+    zoom_start: u32,
+) -> Result<SpectrogramLevels, ClaxonError> {
+    let mut reader = FlacReader::open(path)?;
+    let info = reader.streaminfo();
+    trace!("Analyzing FLAC {}", path.display());
+    let total = match info.samples {
+        Some(samples) if samples > 0 => samples,
+        _ => count_samples(path)?,
+    };
+    let rate = u64::from(info.sample_rate);
+    let (start, length) = match variant.size {
+        Size::Full => (0, total),
+        Size::Zoom => {
+            let start = (u64::from(zoom_start) * rate).min(total);
+            (
+                start,
+                (u64::from(variant.duration) * rate).min(total - start),
+            )
+        }
+    };
+    let end = start + length;
+    let scale = get_scale(info.bits_per_sample);
+    let range = f64::from(variant.range);
+    let mut analyzer = SpectrogramAnalyzer::new(
+        usize::try_from(variant.width).expect("width should fit in usize"),
+        usize::try_from(variant.height).expect("height should fit in usize"),
+        length,
+        range,
+    );
+    let mut position: u64 = 0;
+    let mut buffer = Vec::new();
+    let mut blocks = reader.blocks();
+    'decode: while let Some(block) = blocks.read_next_or_eof(buffer)? {
+        for index in 0..block.duration() {
+            if position >= end {
+                break 'decode;
+            }
+            if position >= start {
+                analyzer.push(mix_sample(&block, index, variant.channel) * scale);
+            }
+            position += 1;
+        }
+        buffer = block.into_buffer();
+    }
+    Ok(SpectrogramLevels {
+        sample_rate: info.sample_rate,
+        start: start as f64 / rate as f64,
+        duration: length as f64 / rate as f64,
+        range,
+        columns: analyzer.finish(),
+    })
+}
+
+/// Find the start in seconds of the loudest window of `duration` seconds of a FLAC.
+///
+/// Loudness is the energy of the channel mix in whole seconds.
+pub(crate) fn find_loudest_start(
+    path: &Path,
+    duration: u32,
+    channel: SpectrogramChannel,
+) -> Result<u32, ClaxonError> {
+    let mut reader = FlacReader::open(path)?;
+    let info = reader.streaminfo();
+    let scale = get_scale(info.bits_per_sample);
+    let mut energies = Vec::new();
+    let mut energy = 0.0;
+    let mut count = 0;
+    let mut buffer = Vec::new();
+    let mut blocks = reader.blocks();
+    while let Some(block) = blocks.read_next_or_eof(buffer)? {
+        for index in 0..block.duration() {
+            let sample = mix_sample(&block, index, channel) * scale;
+            energy += sample * sample;
+            count += 1;
+            if count == info.sample_rate {
+                energies.push(energy);
+                energy = 0.0;
+                count = 0;
+            }
+        }
+        buffer = block.into_buffer();
+    }
+    if count > 0 {
+        energies.push(energy);
+    }
+    let window = usize::try_from(duration).expect("duration should fit in usize");
+    let start = get_loudest_start(&energies, window);
+    Ok(u32::try_from(start).expect("start should fit in u32"))
+}
+
+/// Get the index of the window of `window` consecutive values with the largest sum.
+///
+/// The earliest window is returned if several are equal.
+#[must_use]
+pub(crate) fn get_loudest_start(energies: &[f64], window: usize) -> usize {
+    let window = window.max(1);
+    let mut best = (0, f64::NEG_INFINITY);
+    for (index, slice) in energies.windows(window).enumerate() {
+        let sum: f64 = slice.iter().sum();
+        if sum > best.1 {
+            best = (index, sum);
+        }
+    }
+    best.0
+}
+
+/// Mix a sample of a block to a single channel.
+///
+/// A mono block is treated as identical left and right channels.
+fn mix_sample(block: &Block, index: u32, channel: SpectrogramChannel) -> f64 {
+    let left = f64::from(block.sample(0, index));
+    let right = if block.channels() > 1 {
+        f64::from(block.sample(1, index))
+    } else {
+        left
+    };
+    channel.mix(left, right)
+}
+
+/// Get the factor that scales samples of `bits_per_sample` so 1.0 is full scale.
+fn get_scale(bits_per_sample: u32) -> f64 {
+    let shift = i32::try_from(bits_per_sample).expect("bit depth should fit in i32") - 1;
+    2_f64.powi(-shift)
+}
+
+/// Count the samples per channel of a FLAC without a total in its stream info.
+fn count_samples(path: &Path) -> Result<u64, ClaxonError> {
+    let mut reader = FlacReader::open(path)?;
+    let mut total = 0;
+    let mut buffer = Vec::new();
+    let mut blocks = reader.blocks();
+    while let Some(block) = blocks.read_next_or_eof(buffer)? {
+        total += u64::from(block.duration());
+        buffer = block.into_buffer();
+    }
+    Ok(total)
+}
+
+/// Windowed FFT of evenly spaced columns of a mono signal of known length.
+///
+/// - Each column is centered on its share of the signal, so windows overlap or skip samples
+/// - Samples outside the signal are treated as silence
+/// - Only the most recent window of samples is held in memory
+pub(crate) struct SpectrogramAnalyzer {
+    fft: Arc<dyn Fft<f64>>,
+    window: Vec<f64>,
+    gain: f64,
+    range: f64,
+    columns: usize,
+    rows: usize,
+    length: u64,
+    history: VecDeque<f64>,
+    position: u64,
+    levels: Vec<Vec<f64>>,
+}
+
+impl SpectrogramAnalyzer {
+    /// Create a [`SpectrogramAnalyzer`] of `columns` with `rows` bins each for a signal of
+    /// `length` samples, with levels clamped to `range` dB below full scale.
+    #[must_use]
+    pub(crate) fn new(columns: usize, rows: usize, length: u64, range: f64) -> Self {
+        let size = get_fft_size(rows);
+        let window = get_kaiser_window(size);
+        let gain = 2.0 / window.iter().sum::<f64>();
+        Self {
+            fft: FftPlanner::new().plan_fft_forward(size),
+            window,
+            gain,
+            range,
+            columns,
+            rows,
+            length,
+            history: VecDeque::with_capacity(size),
+            position: 0,
+            levels: Vec::with_capacity(columns),
+        }
+    }
+
+    /// Add a sample, scaled so 1.0 is full scale.
+    pub(crate) fn push(&mut self, sample: f64) {
+        if self.history.len() == self.window.len() {
+            self.history.pop_front();
+        }
+        self.history.push_back(sample);
+        self.position += 1;
+        let position = to_i64(self.position);
+        while self.levels.len() < self.columns && self.get_window_end(self.levels.len()) <= position
+        {
+            self.analyze_column();
+        }
+    }
+
+    /// Analyze the remaining columns and return the level in dB of each bin of each column.
+    #[must_use]
+    pub(crate) fn finish(mut self) -> Vec<Vec<f64>> {
+        while self.levels.len() < self.columns {
+            self.analyze_column();
+        }
+        self.levels
+    }
+
+    /// Get the sample position after the end of the window of `column`.
+    #[expect(clippy::integer_division, reason = "FFT size is even")]
+    fn get_window_end(&self, column: usize) -> i64 {
+        let column = u64::try_from(column).expect("column should fit in u64");
+        let columns = u64::try_from(self.columns).expect("columns should fit in u64");
+        let center = ((2 * column + 1) * self.length)
+            .checked_div(2 * columns)
+            .unwrap_or_default();
+        to_i64(center) + self.get_size() / 2
+    }
+
+    /// Get the FFT size as a signed sample count.
+    fn get_size(&self) -> i64 {
+        i64::try_from(self.window.len()).expect("window size should fit in i64")
+    }
+
+    /// Run the FFT of the next column from the samples in the history.
+    fn analyze_column(&mut self) {
+        let start = self.get_window_end(self.levels.len()) - self.get_size();
+        let position = to_i64(self.position);
+        let history_start =
+            position - i64::try_from(self.history.len()).expect("history should fit in i64");
+        let mut buffer: Vec<Complex<f64>> = self
+            .window
+            .iter()
+            .zip(start..)
+            .map(|(weight, index)| {
+                let sample = if index >= history_start && index < position {
+                    usize::try_from(index - history_start)
+                        .ok()
+                        .and_then(|offset| self.history.get(offset))
+                        .copied()
+                        .unwrap_or_default()
+                } else {
+                    0.0
+                };
+                Complex::new(sample * weight, 0.0)
+            })
+            .collect();
+        self.fft.process(&mut buffer);
+        let column = buffer
+            .iter()
+            .take(self.rows)
+            .map(|bin| to_db(bin.norm() * self.gain, self.range))
+            .collect();
+        self.levels.push(column);
+    }
+}
+
+/// Get the FFT size that produces `rows` bins from DC to the Nyquist frequency.
+#[must_use]
+pub(crate) fn get_fft_size(rows: usize) -> usize {
+    rows.saturating_sub(1).max(1) * 2
+}
+
+/// Get a Kaiser window of `size` samples with a shape of [`KAISER_BETA`].
+#[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+fn get_kaiser_window(size: usize) -> Vec<f64> {
+    let last = (size.max(2) - 1) as f64;
+    let denominator = bessel_i0(KAISER_BETA);
+    (0..size)
+        .map(|index| {
+            let ratio = 2.0 * index as f64 / last - 1.0;
+            bessel_i0(KAISER_BETA * (1.0 - ratio * ratio).max(0.0).sqrt()) / denominator
+        })
+        .collect()
+}
+
+/// Convert an amplitude relative to full scale to dB, clamped to `range` dB below full scale.
+fn to_db(amplitude: f64, range: f64) -> f64 {
+    if amplitude <= 0.0 {
+        return -range;
+    }
+    (20.0 * amplitude.log10()).clamp(-range, 0.0)
+}
+
+/// Convert a sample position to a signed position.
+fn to_i64(value: u64) -> i64 {
+    i64::try_from(value).expect("position should fit in i64")
+}
@@ -10,3 +10,32 @@ pub enum Size {
     /// Zoomed 2-second sample at 1:00.
     Zoom,
 }
+
+impl Size {
+    /// Suffix appended to the FLAC file name for the spectrogram image.
+    #[must_use]
+    pub(crate) fn get_suffix(self) -> &'static str {
+        match self {
+            Size::Full => ".full.png",
+            Size::Zoom => ".zoom.png",
+        }
+    }
+
+    /// Width in pixels of the spectrogram plot.
+    #[must_use]
+    pub(crate) fn get_columns(self) -> u32 {
+        match self {
+            Size::Full => 3000,
+            Size::Zoom => 500,
+        }
+    }
+
+    /// Height in pixels of the spectrogram plot, one per frequency bin.
+    #[must_use]
+    pub(crate) fn get_rows(self) -> u32 {
+        match self {
+            Size::Full => 513,
+            Size::Zoom => 1025,
+        }
+    }
+}
@@ -7,8 +7,16 @@ pub enum SpectrogramAction {
     GetSource,
     #[error("create output directory")]
     CreateOutputDirectory,
+    #[error("decode FLAC")]
+    Decode,
     #[error("generate spectrogram")]
     GenerateSpectrogram,
     #[error("execute spectrogram runner")]
     ExecuteRunner,
+    #[error("read track properties")]
+    ReadTrack,
+    #[error("create contact sheet")]
+    CreateContactSheet,
+    #[error("write review page")]
+    WriteReviewPage,
 }
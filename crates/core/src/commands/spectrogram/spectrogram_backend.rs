@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+
+/// Backend used to render spectrogram images.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SpectrogramBackend {
+    /// Render with the `SoX` binary.
+    #[default]
+    Sox,
+    /// Render in process without `SoX`.
+    Native,
+}
@@ -7,6 +7,7 @@ pub(crate) struct SpectrogramCommand {
     paths: Ref<PathManager>,
     factory: Ref<SpectrogramJobFactory>,
     runner: Ref<JobRunner>,
+    review: Ref<SpectrogramReviewWriter>,
 }
 
 impl SpectrogramCommand {
@@ -37,6 +38,8 @@ impl SpectrogramCommand {
         if path.is_dir() {
             info!("{} existing spectrograms {source}", "Found".bold());
             debug!("in {}", path.display());
+            let collection = Collector::get_flacs(&source.directory);
+            self.review.execute(source, &collection).await?;
             return Ok(SpectrogramSuccess { path, count: 0 });
         }
         info!("{} spectrograms for {}", "Creating".bold(), source);
@@ -50,6 +53,7 @@ impl SpectrogramCommand {
             .map_err(Failure::wrap(SpectrogramAction::ExecuteRunner))?;
         info!("{} {count} spectrograms for {source}", "Created".bold());
         debug!("in {}", path.display());
+        self.review.execute(source, &collection).await?;
         Ok(SpectrogramSuccess { path, count })
     }
 }
@@ -0,0 +1,322 @@
+use crate::prelude::*;
+use font8x8::legacy::{BASIC_LEGACY, LATIN_LEGACY};
+use image::{Rgb, RgbImage};
+use std::f64::consts::PI;
+
+/// Width in pixels of the margin left of the plot for frequency labels.
+const LEFT: u32 = 58;
+
+/// Width in pixels of the margin right of the plot for the colour scale.
+const RIGHT: u32 = 90;
+
+/// Height in pixels of the margin above the plot for the title.
+const TOP: u32 = 34;
+
+/// Height in pixels of the margin below the plot for time labels.
+const BOTTOM: u32 = 48;
+
+/// Width and height in pixels of a glyph.
+pub(crate) const GLYPH: u32 = 8;
+
+/// Length in pixels of an axis tick.
+const TICK: u32 = 5;
+
+/// Gap in pixels between text and the tick or edge it labels.
+const GAP: u32 = 3;
+
+/// Gap in pixels between the plot and the colour scale.
+const COLOUR_SCALE_GAP: u32 = 15;
+
+/// Width in pixels of the colour scale.
+const COLOUR_SCALE_WIDTH: u32 = 12;
+
+/// Interval in dB between colour scale labels.
+const LEVEL_STEP_DB: f64 = 20.0;
+
+/// Candidate intervals in Hz between frequency labels.
+const FREQUENCY_STEPS: [u32; 6] = [500, 1_000, 2_000, 5_000, 10_000, 20_000];
+
+/// Candidate intervals in seconds between time labels.
+const TIME_STEPS: [f64; 13] = [
+    0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0,
+];
+
+/// Minimum distance in pixels between frequency labels.
+const MIN_FREQUENCY_SPACING: f64 = 40.0;
+
+/// Minimum distance in pixels between time labels.
+const MIN_TIME_SPACING: f64 = 80.0;
+
+/// Colour of the background.
+const BACKGROUND: Rgb<u8> = Rgb([0, 0, 0]);
+
+/// Colour of text, ticks and the plot border.
+const FOREGROUND: Rgb<u8> = Rgb([208, 208, 208]);
+
+/// Draw [`SpectrogramLevels`] with a title, axis labels and colour scale in the style of `sox`.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss
+)]
+#[expect(clippy::integer_division, reason = "whole pixels")]
+pub(crate) fn draw_spectrogram(levels: &SpectrogramLevels, title: &str) -> RgbImage {
+    let columns = levels.columns.len() as u32;
+    let rows = levels.rows() as u32;
+    let width = LEFT + columns + RIGHT;
+    let height = TOP + rows + BOTTOM;
+    let mut image = RgbImage::from_pixel(width, height, BACKGROUND);
+    for (x, column) in (LEFT..).zip(&levels.columns) {
+        for (y, level) in (TOP..TOP + rows).rev().zip(column) {
+            image.put_pixel(x, y, get_colour(get_intensity(*level, levels.range)));
+        }
+    }
+    draw_rectangle(&mut image, LEFT - 1, TOP - 1, columns + 2, rows + 2);
+    draw_frequency_axis(&mut image, levels.sample_rate, rows);
+    draw_time_axis(&mut image, levels.start, levels.duration, columns, rows);
+    draw_colour_scale(&mut image, columns, rows, levels.range);
+    let title = truncate(title, width / GLYPH);
+    let title_x = width.saturating_sub(get_text_width(&title)) / 2;
+    draw_text(&mut image, title_x, (TOP - GLYPH) / 2, &title);
+    draw_text(&mut image, GAP, height - GLYPH - GAP, APP_NAME);
+    image
+}
+
+/// Draw frequency ticks and labels left of the plot.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+#[expect(clippy::integer_division, reason = "whole pixels")]
+fn draw_frequency_axis(image: &mut RgbImage, sample_rate: u32, rows: u32) {
+    let nyquist = f64::from(sample_rate) / 2.0;
+    let scale = f64::from(rows.saturating_sub(1)) / nyquist;
+    let step = FREQUENCY_STEPS
+        .into_iter()
+        .find(|step| f64::from(*step) * scale >= MIN_FREQUENCY_SPACING)
+        .or_else(|| FREQUENCY_STEPS.last().copied())
+        .unwrap_or_default();
+    let bottom = TOP + rows - 1;
+    let mut frequency = 0;
+    while f64::from(frequency) <= nyquist {
+        let y = bottom - (f64::from(frequency) * scale).round() as u32;
+        draw_horizontal(image, LEFT - 1 - TICK, y, TICK);
+        let label = format_frequency(frequency, step);
+        let x = (LEFT - 1 - TICK - GAP).saturating_sub(get_text_width(&label));
+        draw_text(image, x, y.saturating_sub(GLYPH / 2), &label);
+        frequency += step;
+    }
+    let label = "kHz";
+    let x = (LEFT - 1 - TICK - GAP).saturating_sub(get_text_width(label));
+    draw_text(image, x, TOP - GLYPH - GAP * 2, label);
+}
+
+/// Draw time ticks and labels below the plot.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+#[expect(clippy::integer_division, reason = "whole pixels")]
+fn draw_time_axis(image: &mut RgbImage, start: f64, duration: f64, columns: u32, rows: u32) {
+    let y = TOP + rows + 1;
+    if duration > 0.0 {
+        let scale = f64::from(columns) / duration;
+        let step = TIME_STEPS
+            .into_iter()
+            .find(|step| step * scale >= MIN_TIME_SPACING)
+            .or_else(|| TIME_STEPS.last().copied())
+            .unwrap_or_default();
+        let mut index = 0_u32;
+        loop {
+            let time = f64::from(index) * step;
+            if time > duration {
+                break;
+            }
+            let x = LEFT + ((time * scale).round() as u32).min(columns);
+            draw_vertical(image, x, y, TICK);
+            let label = format_time(start + time, step);
+            let label_x = x.saturating_sub(get_text_width(&label) / 2);
+            draw_text(image, label_x, y + TICK + GAP, &label);
+            index += 1;
+        }
+    }
+    let label = "Time";
+    let x = LEFT + columns.saturating_sub(get_text_width(label)) / 2;
+    draw_text(image, x, y + TICK + GAP * 2 + GLYPH * 2, label);
+}
+
+/// Draw the colour scale with level labels right of the plot.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+#[expect(clippy::integer_division, reason = "whole pixels")]
+fn draw_colour_scale(image: &mut RgbImage, columns: u32, rows: u32, range: f64) {
+    let left = LEFT + columns + COLOUR_SCALE_GAP;
+    let last = f64::from(rows.saturating_sub(1).max(1));
+    for y in 0..rows {
+        let colour = get_colour(1.0 - f64::from(y) / last);
+        for x in left..left + COLOUR_SCALE_WIDTH {
+            image.put_pixel(x, TOP + y, colour);
+        }
+    }
+    draw_rectangle(image, left - 1, TOP - 1, COLOUR_SCALE_WIDTH + 2, rows + 2);
+    let mut level = 0.0;
+    while level >= -range {
+        let y = TOP + (-level / range * last).round() as u32;
+        let label = format!("{level:.0}");
+        draw_text(
+            image,
+            left + COLOUR_SCALE_WIDTH + GAP,
+            y.saturating_sub(GLYPH / 2),
+            &label,
+        );
+        level -= LEVEL_STEP_DB;
+    }
+    draw_text(image, left, TOP - GLYPH - GAP * 2, "dBFS");
+}
+
+/// Map a level in dB to an intensity from 0 at `range` dB below full scale to 1 at full scale.
+fn get_intensity(level: f64, range: f64) -> f64 {
+    ((level + range) / range).clamp(0.0, 1.0)
+}
+
+/// Get the colour of an intensity from 0 to 1 using the default `sox` palette.
+///
+/// Black through blue, purple, red, orange and yellow to white.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn get_colour(intensity: f64) -> Rgb<u8> {
+    let x = intensity.clamp(0.0, 1.0);
+    let red = if x < 0.13 {
+        0.0
+    } else if x < 0.73 {
+        ((x - 0.13) / 0.60 * PI / 2.0).sin()
+    } else {
+        1.0
+    };
+    let green = if x < 0.60 {
+        0.0
+    } else if x < 0.91 {
+        ((x - 0.60) / 0.31 * PI / 2.0).sin()
+    } else {
+        1.0
+    };
+    let blue = if x < 0.60 {
+        0.5 * (x / 0.60 * PI).sin()
+    } else if x < 0.78 {
+        0.0
+    } else {
+        (x - 0.78) / 0.22
+    };
+    let to_u8 = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Rgb([to_u8(red), to_u8(green), to_u8(blue)])
+}
+
+/// Format a frequency in Hz as a kHz label.
+fn format_frequency(frequency: u32, step: u32) -> String {
+    let khz = f64::from(frequency) / 1000.0;
+    if step < 1_000 {
+        format!("{khz:.1}")
+    } else {
+        format!("{khz:.0}")
+    }
+}
+
+/// Format a position in seconds as a time label.
+///
+/// Tenths of a second are shown if `step` is less than a second, otherwise `m:ss`.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+#[expect(clippy::integer_division, reason = "whole minutes")]
+fn format_time(seconds: f64, step: f64) -> String {
+    if step < 1.0 {
+        return format!("{seconds:.1}");
+    }
+    let seconds = seconds.round() as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Truncate `text` to at most `length` characters.
+pub(crate) fn truncate(text: &str, length: u32) -> String {
+    let length = usize::try_from(length).expect("length should fit in usize");
+    text.chars().take(length).collect()
+}
+
+/// Get the width in pixels of `text`.
+pub(crate) fn get_text_width(text: &str) -> u32 {
+    u32::try_from(text.chars().count()).expect("length should fit in u32") * GLYPH
+}
+
+/// Draw `text` with its top left corner at `x` and `y`.
+///
+/// Characters without a glyph are drawn as `?`.
+pub(crate) fn draw_text(image: &mut RgbImage, x: u32, y: u32, text: &str) {
+    let step = usize::try_from(GLYPH).expect("glyph width should fit in usize");
+    for (left, character) in (x..).step_by(step).zip(text.chars()) {
+        for (top, row) in (y..).zip(get_glyph(character)) {
+            for bit in 0..GLYPH {
+                if row & (1 << bit) != 0 {
+                    set_pixel(image, left + bit, top);
+                }
+            }
+        }
+    }
+}
+
+/// Get the 8x8 bitmap of a character, with bit 0 of each row as the leftmost pixel.
+fn get_glyph(character: char) -> [u8; 8] {
+    let code = u32::from(character);
+    let glyph = match code {
+        0x20..0x7F => usize::try_from(code).ok().and_then(|i| BASIC_LEGACY.get(i)),
+        0xA0..=0xFF => usize::try_from(code - 0xA0)
+            .ok()
+            .and_then(|i| LATIN_LEGACY.get(i)),
+        _ => None,
+    };
+    glyph
+        .or_else(|| BASIC_LEGACY.get(usize::from(b'?')))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Draw the outline of a rectangle.
+fn draw_rectangle(image: &mut RgbImage, x: u32, y: u32, width: u32, height: u32) {
+    draw_horizontal(image, x, y, width);
+    draw_horizontal(image, x, y + height - 1, width);
+    draw_vertical(image, x, y, height);
+    draw_vertical(image, x + width - 1, y, height);
+}
+
+/// Draw a horizontal line of `length` pixels from `x` and `y`.
+fn draw_horizontal(image: &mut RgbImage, x: u32, y: u32, length: u32) {
+    for x in x..x + length {
+        set_pixel(image, x, y);
+    }
+}
+
+/// Draw a vertical line of `length` pixels from `x` and `y`.
+fn draw_vertical(image: &mut RgbImage, x: u32, y: u32, length: u32) {
+    for y in y..y + length {
+        set_pixel(image, x, y);
+    }
+}
+
+/// Set a pixel to the [`FOREGROUND`] colour if it is within the image.
+fn set_pixel(image: &mut RgbImage, x: u32, y: u32) {
+    if let Some(pixel) = image.get_pixel_mut_checked(x, y) {
+        *pixel = FOREGROUND;
+    }
+}
@@ -1,13 +1,12 @@
 use crate::prelude::*;
-use std::process::Output;
 
 /// Duration of the zoom spectrogram capture window in seconds.
-const ZOOM_DURATION: u32 = 2;
+pub(crate) const ZOOM_DURATION: u32 = 2;
 
 /// Standard start position for zoom spectrogram in seconds (1:00).
 const TYPICAL_ZOOM_START: u32 = 60;
 
-/// A command to generate a spectrogram image of a FLAC file using sox.
+/// A command to generate a spectrogram image of a FLAC file using sox or in process.
 ///
 /// A [command design pattern](https://refactoring.guru/design-patterns/command) is used
 /// so the execution of the command can be deferred and multiple commands can be executed
@@ -21,10 +20,12 @@ pub(crate) struct SpectrogramJob {
     pub output_path: PathBuf,
     /// Title to embed in the spectrogram image.
     pub image_title: String,
-    /// Spectrogram size variant.
-    pub size: Size,
+    /// Size, channel, dimensions and zoom window of the spectrogram.
+    pub variant: SpectrogramVariant,
     /// Duration of the FLAC file in seconds, if known.
     pub duration_secs: Option<u32>,
+    /// Backend to render the spectrogram with.
+    pub backend: SpectrogramBackend,
     /// Factory for creating sox commands.
     pub sox: Ref<SoxFactory>,
 }
@@ -40,67 +41,84 @@ impl SpectrogramJob {
             SpectrogramAction::CreateOutputDirectory,
             output_dir,
         ))?;
-        match self.size {
-            Size::Full => self.execute_full().await,
-            Size::Zoom => self.execute_zoom().await,
-        }?;
-        Ok(())
+        let zoom_start = self.get_zoom_start().await?;
+        match self.backend {
+            SpectrogramBackend::Sox => self.execute_sox(zoom_start).await,
+            SpectrogramBackend::Native => self.execute_native(zoom_start).await,
+        }
     }
 
-    async fn execute_zoom(&self) -> Result<Output, Failure<SpectrogramAction>> {
-        let start_time = calculate_zoom_start(self.duration_secs);
-        let duration = format!("0:{ZOOM_DURATION:02}");
-        self.sox
-            .create()
-            .to_command()
-            .arg(&self.source_path)
-            .arg("-n")
-            .arg("remix")
-            .arg("1")
-            .arg("spectrogram")
-            .arg("-x")
-            .arg("500")
-            .arg("-y")
-            .arg("1025")
-            .arg("-z")
-            .arg("120")
-            .arg("-w")
-            .arg("Kaiser")
-            .arg("-S")
-            .arg(&start_time)
-            .arg("-d")
-            .arg(&duration)
-            .arg("-t")
-            .arg(&self.image_title)
-            .arg("-c")
-            .arg(APP_NAME)
-            .arg("-o")
-            .arg(&self.output_path)
-            .run()
-            .await
-            .map_err(Failure::wrap_with_path(
-                SpectrogramAction::GenerateSpectrogram,
+    /// Get the start of the zoom window in seconds.
+    ///
+    /// The loudest section is found by decoding the FLAC on the blocking pool.
+    async fn get_zoom_start(&self) -> Result<u32, Failure<SpectrogramAction>> {
+        let window = self.variant.duration;
+        let start = match self.variant.start {
+            _ if self.variant.size == Size::Full => 0,
+            ZoomStart::Auto => get_zoom_start(self.duration_secs, window),
+            ZoomStart::Time(start) => match self.duration_secs {
+                Some(duration) => start.min(duration.saturating_sub(window)),
+                None => start,
+            },
+            ZoomStart::Percent(percent) => match self.duration_secs {
+                Some(duration) => get_percent_start(duration, percent, window),
+                None => get_zoom_start(None, window),
+            },
+            ZoomStart::Loudest => {
+                let path = PathBuf::from(&self.source_path);
+                let channel = self.variant.channel;
+                spawn_blocking(move || find_loudest_start(&path, window, channel))
+                    .await
+                    .expect("loudest section task should not panic")
+                    .map_err(Failure::wrap_with_path(
+                        SpectrogramAction::Decode,
+                        &self.source_path,
+                    ))?
+            }
+        };
+        Ok(start)
+    }
+
+    /// Render the spectrogram in process on the blocking pool.
+    async fn execute_native(self, zoom_start: u32) -> Result<(), Failure<SpectrogramAction>> {
+        spawn_blocking(move || {
+            render_spectrogram(
+                Path::new(&self.source_path),
                 &self.output_path,
-            ))
+                &self.image_title,
+                &self.variant,
+                zoom_start,
+            )
+        })
+        .await
+        .expect("spectrogram task should not panic")
     }
 
-    async fn execute_full(&self) -> Result<Output, Failure<SpectrogramAction>> {
-        self.sox
-            .create()
-            .to_command()
+    /// Render the spectrogram with sox.
+    async fn execute_sox(&self, zoom_start: u32) -> Result<(), Failure<SpectrogramAction>> {
+        let mut command = self.sox.create().to_command();
+        command
             .arg(&self.source_path)
             .arg("-n")
             .arg("remix")
-            .arg("1")
+            .arg(self.variant.channel.get_remix())
             .arg("spectrogram")
             .arg("-x")
-            .arg("3000")
+            .arg(self.variant.width.to_string())
             .arg("-y")
-            .arg("513")
+            .arg(self.variant.height.to_string())
             .arg("-z")
-            .arg("120")
+            .arg(self.variant.range.to_string())
             .arg("-w")
-            .arg("Kaiser")
+            .arg("Kaiser");
+        if self.variant.size == Size::Zoom {
+            command
+                .arg("-S")
+                .arg(format_time(zoom_start))
+                .arg("-d")
+                .arg(format_time(self.variant.duration));
+        }
+        command
             .arg("-t")
             .arg(&self.image_title)
             .arg("-c")
@@ -112,31 +130,59 @@ impl SpectrogramJob {
             .map_err(Failure::wrap_with_path(
                 SpectrogramAction::GenerateSpectrogram,
                 &self.output_path,
-            ))
+            ))?;
+        Ok(())
     }
 }
 
-/// Calculate the start time for zoom spectrogram.
+/// Format seconds as `m:ss` for sox.
+#[expect(clippy::integer_division, reason = "whole minutes")]
+fn format_time(seconds: u32) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Get the start time for a zoom spectrogram of `window` seconds.
 ///
-/// For tracks >= [`TYPICAL_ZOOM_START`] + [`ZOOM_DURATION`], uses the standard position (1:00).
+/// For tracks >= [`TYPICAL_ZOOM_START`] + `window`, uses the standard position (1:00).
 /// For shorter tracks, uses 50% of the duration minus half the capture window to center it.
 #[expect(clippy::integer_division, reason = "sub-second precision not needed")]
-fn calculate_zoom_start(duration_secs: Option<u32>) -> String {
+fn get_zoom_start(duration_secs: Option<u32>, window: u32) -> u32 {
     match duration_secs {
-        Some(duration) if duration < TYPICAL_ZOOM_START + ZOOM_DURATION => {
+        Some(duration) if duration < TYPICAL_ZOOM_START + window => {
             // Center the capture window at 50% of the track
             let midpoint = duration / 2;
-            let start = midpoint.saturating_sub(ZOOM_DURATION / 2);
-            format!("0:{start:02}")
+            midpoint.saturating_sub(window / 2)
         }
-        _ => format!("{}:{:02}", TYPICAL_ZOOM_START / 60, TYPICAL_ZOOM_START % 60),
+        _ => TYPICAL_ZOOM_START,
     }
 }
 
+/// Get the start time for a zoom spectrogram of `window` seconds centered on `percent` of the
+/// duration, kept within the track.
+#[expect(clippy::integer_division, reason = "sub-second precision not needed")]
+fn get_percent_start(duration: u32, percent: u32, window: u32) -> u32 {
+    let center = u64::from(duration) * u64::from(percent) / 100;
+    let center = u32::try_from(center).expect("center should fit in u32");
+    center
+        .saturating_sub(window / 2)
+        .min(duration.saturating_sub(window))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn calculate_zoom_start(duration_secs: Option<u32>) -> String {
+        format_time(get_zoom_start(duration_secs, ZOOM_DURATION))
+    }
+
+    #[test]
+    fn percent_start_centers_window() {
+        assert_eq!(get_percent_start(200, 50, 2), 99);
+        assert_eq!(get_percent_start(200, 0, 2), 0);
+        assert_eq!(get_percent_start(200, 100, 2), 198);
+    }
+
     #[test]
     fn zoom_start_unknown_duration_uses_typical() {
         assert_eq!(calculate_zoom_start(None), "1:00");
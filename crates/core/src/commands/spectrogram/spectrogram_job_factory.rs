@@ -4,17 +4,22 @@ use crate::prelude::*;
 #[injectable]
 pub(crate) struct SpectrogramJobFactory {
     paths: Ref<PathManager>,
+    spectrogram_options: Ref<SpectrogramOptions>,
     sox: Ref<SoxFactory>,
 }
 
 impl SpectrogramJobFactory {
-    /// Create a [`SpectrogramJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
+    /// Create a [`SpectrogramJob`] for each variant of each [`FlacFile`].
+    ///
+    /// Variants are the standard `spectrogram_size` followed by each `spectrogram_variant`.
     #[must_use]
     pub(crate) fn create(&self, flacs: &[FlacFile], source: &Source) -> Vec<Job> {
+        let variants = self.spectrogram_options.get_variants();
         let mut jobs = Vec::new();
         for (index, flac) in flacs.iter().enumerate() {
-            jobs.push(self.create_single(source, index, flac, Size::Zoom));
-            jobs.push(self.create_single(source, index, flac, Size::Full));
+            for variant in &variants {
+                jobs.push(self.create_single(source, index, flac, variant));
+            }
         }
         jobs
     }
@@ -24,32 +29,45 @@ impl SpectrogramJobFactory {
     /// Arguments:
     ///
     /// * `flac_file`: Path to the flac file.
-    /// * `size`: Size of the spectrogram to create.
-    fn create_single(&self, source: &Source, index: usize, flac: &FlacFile, size: Size) -> Job {
-        let out_filename = flac.file_name.clone()
-            + match size {
-                Size::Full => ".full.png",
-                Size::Zoom => ".zoom.png",
-            };
-        let id = format!("Spectrogram {size:<4?}{index:>3}");
+    /// * `variant`: Variant of the spectrogram to create.
+    fn create_single(
+        &self,
+        source: &Source,
+        index: usize,
+        flac: &FlacFile,
+        variant: &SpectrogramVariant,
+    ) -> Job {
+        let out_filename = flac.file_name.clone() + &variant.get_suffix();
+        let id = if variant.is_standard() {
+            format!("Spectrogram {:<4?}{index:>3}", variant.size)
+        } else {
+            format!("Spectrogram {index:>3} {variant}")
+        };
         let source_path = flac.get_path_string();
         let output_path = self
             .paths
             .get_spectrogram_dir(source)
             .join(&flac.sub_dir)
             .join(out_filename);
-        let image_title = flac.file_name.clone();
-        let duration_secs = flac
-            .get_stream_info()
-            .ok()
-            .and_then(|info| get_duration(&info));
+        let info = flac.get_stream_info().ok();
+        let image_title = if variant.is_standard() {
+            flac.file_name.clone()
+        } else {
+            format!("{} [{variant}]", flac.file_name)
+        };
+        let duration_secs = info.as_ref().and_then(get_duration);
+        let variant = match &info {
+            Some(info) => variant.for_channels(info.channels),
+            None => variant.clone(),
+        };
         Job::Spectrogram(SpectrogramJob {
             id,
             source_path,
             output_path,
             image_title,
-            size,
+            variant,
             duration_secs,
+            backend: self.spectrogram_options.spectrogram_backend,
             sox: self.sox.clone(),
         })
     }
@@ -0,0 +1,282 @@
+use crate::prelude::*;
+use image::imageops::{FilterType, overlay, resize};
+use image::{ImageError, Rgb, RgbImage, open as open_image};
+use std::fs::write;
+
+/// File name of the contact sheet.
+pub(crate) const CONTACT_SHEET: &str = "contact_sheet.png";
+
+/// File name of the HTML review page.
+pub(crate) const REVIEW_PAGE: &str = "index.html";
+
+/// Height in pixels of each spectrogram on the contact sheet.
+const THUMBNAIL_HEIGHT: u32 = 300;
+
+/// Height in pixels of the label above each track on the contact sheet.
+const LABEL_HEIGHT: u32 = 16;
+
+/// Gap in pixels around and between spectrograms on the contact sheet.
+const SPACING: u32 = 10;
+
+/// Colour of the contact sheet background.
+const BACKGROUND: Rgb<u8> = Rgb([0, 0, 0]);
+
+/// Stylesheet of the HTML review page.
+const STYLE: &str = "body { background: #111; color: #ddd; font-family: sans-serif; margin: 2em; }
+a { color: #8cf; }
+section { border-top: 1px solid #444; padding-top: 1em; margin-top: 1em; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th { color: #8cf; text-align: left; padding-right: 2em; }
+img { display: block; max-width: 100%; margin-bottom: 1em; }";
+
+/// A track of the review with the paths of its spectrograms.
+pub(crate) struct ReviewTrack {
+    /// Disc, track number and path of the track.
+    pub label: String,
+    /// Audio properties as label and value pairs.
+    pub properties: Vec<(&'static str, String)>,
+    /// Paths of the spectrograms relative to the spectrogram directory.
+    pub images: Vec<PathBuf>,
+}
+
+/// Write a contact sheet and HTML review page of the spectrograms of a source.
+#[injectable]
+pub(crate) struct SpectrogramReviewWriter {
+    paths: Ref<PathManager>,
+    spectrogram_options: Ref<SpectrogramOptions>,
+}
+
+impl SpectrogramReviewWriter {
+    /// Write [`CONTACT_SHEET`] and [`REVIEW_PAGE`] to the spectrogram directory.
+    ///
+    /// - Skips entirely unless `spectrogram_review` is set
+    /// - Skips if the review page already exists
+    /// - Spectrograms that do not exist are left out
+    pub(crate) async fn execute(
+        &self,
+        source: &Source,
+        flacs: &[FlacFile],
+    ) -> Result<(), Failure<SpectrogramAction>> {
+        if !self.spectrogram_options.spectrogram_review {
+            return Ok(());
+        }
+        let dir = self.paths.get_spectrogram_dir(source);
+        if dir.join(REVIEW_PAGE).exists() {
+            debug!("{} existing spectrogram review {source}", "Found".bold());
+            return Ok(());
+        }
+        let mut tracks = Vec::new();
+        let factory = InspectFactory::new(false);
+        for flac in flacs {
+            tracks.push(self.get_track(source, &dir, flac, &factory)?);
+        }
+        let title = SourceName::get_unsanitized(&source.metadata);
+        let sheet_dir = dir.clone();
+        let sheet_tracks = spawn_blocking(move || {
+            create_contact_sheet(&sheet_dir, &tracks)
+                .map_err(Failure::wrap_with_path(
+                    SpectrogramAction::CreateContactSheet,
+                    &sheet_dir,
+                ))?
+                .save(sheet_dir.join(CONTACT_SHEET))
+                .map_err(Failure::wrap_with_path(
+                    SpectrogramAction::CreateContactSheet,
+                    sheet_dir.join(CONTACT_SHEET),
+                ))?;
+            Ok::<_, Failure<SpectrogramAction>>(tracks)
+        })
+        .await
+        .expect("contact sheet task should not panic")?;
+        let path = dir.join(REVIEW_PAGE);
+        write(&path, render_review_page(&title, &sheet_tracks)).map_err(
+            Failure::wrap_with_path(SpectrogramAction::WriteReviewPage, &path),
+        )?;
+        info!("{} spectrogram review for {source}", "Created".bold());
+        debug!("in {}", path.display());
+        Ok(())
+    }
+
+    /// Read the properties of a FLAC and find its spectrograms.
+    fn get_track(
+        &self,
+        source: &Source,
+        dir: &Path,
+        flac: &FlacFile,
+        factory: &InspectFactory,
+    ) -> Result<ReviewTrack, Failure<SpectrogramAction>> {
+        let info = TrackInfo::read(&source.directory, &flac.path).map_err(
+            Failure::wrap_with_path(SpectrogramAction::ReadTrack, &flac.path),
+        )?;
+        let label = match info.get_position() {
+            Some(position) => format!("{position}: {}", info.get_sub_path()),
+            None => info.get_sub_path().to_owned(),
+        };
+        let images = self
+            .spectrogram_options
+            .get_variants()
+            .iter()
+            .map(|variant| {
+                flac.sub_dir
+                    .join(flac.file_name.clone() + &variant.get_suffix())
+            })
+            .filter(|path| dir.join(path).is_file())
+            .collect();
+        Ok(ReviewTrack {
+            label,
+            properties: factory.get_properties(&info),
+            images,
+        })
+    }
+}
+
+/// Composite the spectrograms of every track into a single image.
+///
+/// Each track is a row of its spectrograms scaled to [`THUMBNAIL_HEIGHT`] below its label.
+#[expect(clippy::integer_division, reason = "whole pixels")]
+pub(crate) fn create_contact_sheet(
+    dir: &Path,
+    tracks: &[ReviewTrack],
+) -> Result<RgbImage, ImageError> {
+    let mut rows = Vec::with_capacity(tracks.len());
+    for track in tracks {
+        let mut thumbnails = Vec::with_capacity(track.images.len());
+        for path in &track.images {
+            let image = open_image(dir.join(path))?.to_rgb8();
+            let width = (image.width() * THUMBNAIL_HEIGHT / image.height().max(1)).max(1);
+            thumbnails.push(resize(
+                &image,
+                width,
+                THUMBNAIL_HEIGHT,
+                FilterType::Triangle,
+            ));
+        }
+        rows.push(thumbnails);
+    }
+    let row_height = LABEL_HEIGHT + THUMBNAIL_HEIGHT + SPACING;
+    let width = rows
+        .iter()
+        .map(|thumbnails| {
+            thumbnails
+                .iter()
+                .map(|thumbnail| thumbnail.width() + SPACING)
+                .sum::<u32>()
+        })
+        .max()
+        .unwrap_or_default()
+        .max(THUMBNAIL_HEIGHT)
+        + SPACING;
+    let count = u32::try_from(tracks.len()).expect("track count should fit in u32");
+    let height = SPACING + count * row_height;
+    let mut sheet = RgbImage::from_pixel(width, height, BACKGROUND);
+    for ((track, thumbnails), top) in tracks
+        .iter()
+        .zip(&rows)
+        .zip((SPACING..).step_by(usize::try_from(row_height).expect("row height should fit")))
+    {
+        let label = truncate(&track.label, (width - SPACING * 2) / GLYPH);
+        draw_text(&mut sheet, SPACING, top, &label);
+        let mut left = SPACING;
+        for thumbnail in thumbnails {
+            overlay(
+                &mut sheet,
+                thumbnail,
+                i64::from(left),
+                i64::from(top + LABEL_HEIGHT),
+            );
+            left += thumbnail.width() + SPACING;
+        }
+    }
+    Ok(sheet)
+}
+
+/// Render an HTML page listing each track with its properties and spectrograms.
+#[must_use]
+pub(crate) fn render_review_page(title: &str, tracks: &[ReviewTrack]) -> String {
+    let mut out = String::new();
+    write_review_page(&mut out, title, tracks).expect("writing to a String is infallible");
+    out
+}
+
+fn write_review_page(out: &mut String, title: &str, tracks: &[ReviewTrack]) -> FmtResult {
+    let title = escape_html(title);
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html lang=\"en\">")?;
+    writeln!(out, "<head>")?;
+    writeln!(out, "<meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>{title}</title>")?;
+    writeln!(out, "<style>\n{STYLE}\n</style>")?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+    writeln!(out, "<h1>{title}</h1>")?;
+    writeln!(out, "<p><a href=\"{CONTACT_SHEET}\">Contact sheet</a></p>")?;
+    for track in tracks {
+        write_review_track(out, track)?;
+    }
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+    Ok(())
+}
+
+fn write_review_track(out: &mut String, track: &ReviewTrack) -> FmtResult {
+    writeln!(out, "<section>")?;
+    writeln!(out, "<h2>{}</h2>", escape_html(&track.label))?;
+    writeln!(out, "<table>")?;
+    for (label, value) in &track.properties {
+        writeln!(
+            out,
+            "<tr><th>{label}</th><td>{}</td></tr>",
+            escape_html(value)
+        )?;
+    }
+    writeln!(out, "</table>")?;
+    for path in &track.images {
+        let src = encode_uri(path);
+        let alt = escape_html(&path.to_string_lossy());
+        writeln!(
+            out,
+            "<a href=\"{src}\"><img src=\"{src}\" alt=\"{alt}\" loading=\"lazy\"></a>"
+        )?;
+    }
+    writeln!(out, "</section>")?;
+    Ok(())
+}
+
+/// Escape text for use in HTML content and attribute values.
+#[must_use]
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(character),
+        }
+    }
+    output
+}
+
+/// Percent encode a relative path for use as a URI.
+///
+/// Path separators are normalized to `/` and every byte other than unreserved characters is
+/// encoded.
+#[must_use]
+pub(crate) fn encode_uri(path: &Path) -> String {
+    let segments: Vec<String> = path
+        .components()
+        .map(|component| {
+            let mut segment = String::new();
+            for byte in component.as_os_str().to_string_lossy().bytes() {
+                if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                    segment.push(char::from(byte));
+                } else {
+                    write!(segment, "%{byte:02X}").expect("writing to a String is infallible");
+                }
+            }
+            segment
+        })
+        .collect();
+    segments.join("/")
+}
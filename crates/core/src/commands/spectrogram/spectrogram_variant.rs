@@ -0,0 +1,338 @@
+use crate::prelude::*;
+
+/// Default dynamic range in dB, equivalent to `sox spectrogram -z 120`.
+pub(crate) const DEFAULT_RANGE: u32 = 120;
+
+/// Lowest dynamic range in dB accepted by `sox`.
+const MIN_RANGE: u32 = 20;
+
+/// Highest dynamic range in dB accepted by `sox`.
+const MAX_RANGE: u32 = 180;
+
+/// Lowest width or height in pixels of a spectrogram plot.
+const MIN_DIMENSION: u32 = 2;
+
+/// Highest width or height in pixels of a spectrogram plot.
+const MAX_DIMENSION: u32 = 20_000;
+
+/// Channel or mix of channels analyzed by a spectrogram.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SpectrogramChannel {
+    /// First channel, equivalent to `remix 1`.
+    #[default]
+    Left,
+    /// Second channel, equivalent to `remix 2`.
+    Right,
+    /// Sum of both channels.
+    Mid,
+    /// Difference of both channels, which reveals joint stereo artefacts.
+    Side,
+}
+
+impl SpectrogramChannel {
+    /// Lowercase name of the channel.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SpectrogramChannel::Left => "left",
+            SpectrogramChannel::Right => "right",
+            SpectrogramChannel::Mid => "mid",
+            SpectrogramChannel::Side => "side",
+        }
+    }
+
+    /// Get the `sox remix` argument that mixes the channel.
+    #[must_use]
+    pub(crate) fn get_remix(self) -> &'static str {
+        match self {
+            SpectrogramChannel::Left => "1",
+            SpectrogramChannel::Right => "2",
+            SpectrogramChannel::Mid => "1v0.5,2v0.5",
+            SpectrogramChannel::Side => "1v0.5,2v-0.5",
+        }
+    }
+
+    /// Mix a sample of the left and right channels.
+    #[must_use]
+    pub(crate) fn mix(self, left: f64, right: f64) -> f64 {
+        match self {
+            SpectrogramChannel::Left => left,
+            SpectrogramChannel::Right => right,
+            SpectrogramChannel::Mid => f64::midpoint(left, right),
+            SpectrogramChannel::Side => (left - right) / 2.0,
+        }
+    }
+}
+
+impl FromStr for SpectrogramChannel {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "left" => Ok(SpectrogramChannel::Left),
+            "right" => Ok(SpectrogramChannel::Right),
+            "mid" => Ok(SpectrogramChannel::Mid),
+            "side" => Ok(SpectrogramChannel::Side),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Position of the window of a zoom spectrogram.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ZoomStart {
+    /// 1:00, or centered on shorter tracks.
+    #[default]
+    Auto,
+    /// Fixed time in seconds.
+    Time(u32),
+    /// Centered on a percentage of the duration.
+    Percent(u32),
+    /// Loudest section of the track.
+    Loudest,
+}
+
+impl FromStr for ZoomStart {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.to_lowercase();
+        match value.as_str() {
+            "auto" => return Ok(ZoomStart::Auto),
+            "loudest" => return Ok(ZoomStart::Loudest),
+            _ => {}
+        }
+        if let Some(percent) = value.strip_suffix('%') {
+            return match percent.parse() {
+                Ok(percent) if percent <= 100 => Ok(ZoomStart::Percent(percent)),
+                _ => Err(()),
+            };
+        }
+        if let Some((minutes, seconds)) = value.split_once(':') {
+            let minutes: u32 = minutes.parse().map_err(|_| ())?;
+            let seconds: u32 = seconds.parse().map_err(|_| ())?;
+            if seconds >= 60 {
+                return Err(());
+            }
+            return Ok(ZoomStart::Time(minutes * 60 + seconds));
+        }
+        value.parse().map(ZoomStart::Time).map_err(|_| ())
+    }
+}
+
+impl Display for ZoomStart {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ZoomStart::Auto => write!(formatter, "auto"),
+            ZoomStart::Time(seconds) => write!(formatter, "{}:{:02}", seconds / 60, seconds % 60),
+            ZoomStart::Percent(percent) => write!(formatter, "{percent}%"),
+            ZoomStart::Loudest => write!(formatter, "loudest"),
+        }
+    }
+}
+
+/// A spectrogram to render, declared as a size followed by optional settings.
+///
+/// - `full` or `zoom` alone are the standard sizes
+/// - Settings follow a colon as comma separated `key=value` pairs
+///   - `channel`: `left`, `right`, `mid` or `side`
+///   - `width` and `height`: dimensions of the plot in pixels
+///   - `range`: dynamic range in dB from 20 to 180
+///   - `at`: zoom only, `auto`, a time such as `90` or `1:30`, a percentage such as `50%`, or
+///     `loudest`
+///   - `duration`: zoom only, length of the window in seconds
+///
+/// For example `zoom:at=loudest,channel=side` or `full:channel=right,range=140`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct SpectrogramVariant {
+    /// Full track or zoomed window.
+    pub size: Size,
+    /// Channel or mix of channels to analyze.
+    pub channel: SpectrogramChannel,
+    /// Width of the plot in pixels.
+    pub width: u32,
+    /// Height of the plot in pixels, one per frequency bin.
+    pub height: u32,
+    /// Dynamic range in dB.
+    pub range: u32,
+    /// Position of the zoom window.
+    pub start: ZoomStart,
+    /// Duration of the zoom window in seconds.
+    pub duration: u32,
+}
+
+impl SpectrogramVariant {
+    /// Check if the variant has the default settings of its size.
+    #[must_use]
+    pub(crate) fn is_standard(&self) -> bool {
+        *self == Self::from(self.size)
+    }
+
+    /// Suffix appended to the FLAC file name for the spectrogram image.
+    ///
+    /// Standard variants use the suffix of their [`Size`], others a suffix derived from their
+    /// settings such as `.zoom-at-loudest-channel-side.png`.
+    #[must_use]
+    pub(crate) fn get_suffix(&self) -> String {
+        if self.is_standard() {
+            return self.size.get_suffix().to_owned();
+        }
+        let mut slug = String::new();
+        for character in self.to_string().replace('%', "pc").chars() {
+            if character.is_ascii_alphanumeric() {
+                slug.push(character);
+            } else if !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        format!(".{}.png", slug.trim_end_matches('-'))
+    }
+
+    /// Get the variant to render for a FLAC with `channels`.
+    ///
+    /// A mono FLAC only has a left channel so every channel falls back to it.
+    #[must_use]
+    pub(crate) fn for_channels(&self, channels: u32) -> Self {
+        let mut variant = self.clone();
+        if channels < 2 {
+            variant.channel = SpectrogramChannel::Left;
+        }
+        variant
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), SpectrogramVariantError> {
+        let invalid = || SpectrogramVariantError::InvalidValue {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        };
+        match key {
+            "channel" => self.channel = value.parse().map_err(|()| invalid())?,
+            "width" => {
+                self.width =
+                    parse_bounded(value, MIN_DIMENSION, MAX_DIMENSION).ok_or_else(invalid)?;
+            }
+            "height" => {
+                self.height =
+                    parse_bounded(value, MIN_DIMENSION, MAX_DIMENSION).ok_or_else(invalid)?;
+            }
+            "range" => {
+                self.range = parse_bounded(value, MIN_RANGE, MAX_RANGE).ok_or_else(invalid)?;
+            }
+            "at" | "duration" if self.size != Size::Zoom => {
+                return Err(SpectrogramVariantError::ZoomOnly(key.to_owned()));
+            }
+            "at" => self.start = value.parse().map_err(|()| invalid())?,
+            "duration" => self.duration = parse_bounded(value, 1, u32::MAX).ok_or_else(invalid)?,
+            _ => return Err(SpectrogramVariantError::UnknownKey(key.to_owned())),
+        }
+        Ok(())
+    }
+}
+
+impl From<Size> for SpectrogramVariant {
+    fn from(size: Size) -> Self {
+        Self {
+            size,
+            channel: SpectrogramChannel::Left,
+            width: size.get_columns(),
+            height: size.get_rows(),
+            range: DEFAULT_RANGE,
+            start: ZoomStart::Auto,
+            duration: ZOOM_DURATION,
+        }
+    }
+}
+
+impl FromStr for SpectrogramVariant {
+    type Err = SpectrogramVariantError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (size, settings) = value.split_once(':').unwrap_or((value, ""));
+        let size = match size.trim().to_lowercase().as_str() {
+            "full" => Size::Full,
+            "zoom" => Size::Zoom,
+            _ => return Err(SpectrogramVariantError::UnknownSize(size.to_owned())),
+        };
+        let mut variant = Self::from(size);
+        for setting in settings.split(',').filter(|s| !s.trim().is_empty()) {
+            let (key, value) = setting
+                .split_once('=')
+                .ok_or_else(|| SpectrogramVariantError::InvalidSetting(setting.to_owned()))?;
+            variant.set(&key.trim().to_lowercase(), value.trim())?;
+        }
+        Ok(variant)
+    }
+}
+
+impl TryFrom<String> for SpectrogramVariant {
+    type Error = SpectrogramVariantError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<SpectrogramVariant> for String {
+    fn from(value: SpectrogramVariant) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for SpectrogramVariant {
+    /// Format with only the settings that differ from the defaults of the size.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let standard = Self::from(self.size);
+        let mut settings = Vec::new();
+        if self.start != standard.start {
+            settings.push(format!("at={}", self.start));
+        }
+        if self.duration != standard.duration {
+            settings.push(format!("duration={}", self.duration));
+        }
+        if self.channel != standard.channel {
+            settings.push(format!("channel={}", self.channel.as_str()));
+        }
+        if self.width != standard.width {
+            settings.push(format!("width={}", self.width));
+        }
+        if self.height != standard.height {
+            settings.push(format!("height={}", self.height));
+        }
+        if self.range != standard.range {
+            settings.push(format!("range={}", self.range));
+        }
+        let size = match self.size {
+            Size::Full => "full",
+            Size::Zoom => "zoom",
+        };
+        if settings.is_empty() {
+            write!(formatter, "{size}")
+        } else {
+            write!(formatter, "{size}:{}", settings.join(","))
+        }
+    }
+}
+
+/// Errors parsing a [`SpectrogramVariant`].
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum SpectrogramVariantError {
+    #[error("unknown spectrogram size: {0}")]
+    UnknownSize(String),
+    #[error("expected key=value: {0}")]
+    InvalidSetting(String),
+    #[error("unknown spectrogram setting: {0}")]
+    UnknownKey(String),
+    #[error("invalid value for {key}: {value}")]
+    InvalidValue { key: String, value: String },
+    #[error("{0} only applies to zoom spectrograms")]
+    ZoomOnly(String),
+}
+
+/// Parse a number from `min` to `max` inclusive.
+fn parse_bounded(value: &str, min: u32, max: u32) -> Option<u32> {
+    value
+        .parse()
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+}
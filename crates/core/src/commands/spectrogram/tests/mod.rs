@@ -1 +1,4 @@
+mod native_spectrogram_tests;
 mod spectrogram_command_tests;
+mod spectrogram_review_tests;
+mod spectrogram_variant_tests;
@@ -0,0 +1,157 @@
+use crate::testing_prelude::*;
+use std::f64::consts::TAU;
+
+/// Sample rate of the generated signals.
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Rows of a full spectrogram.
+const ROWS: usize = 513;
+
+/// Dynamic range in dB.
+const RANGE: f64 = 120.0;
+
+/// Analyze one second of a sine at `frequency` with `amplitude`.
+fn analyze_sine(frequency: f64, amplitude: f64, columns: usize) -> Vec<Vec<f64>> {
+    let length = u64::from(SAMPLE_RATE);
+    let mut analyzer = SpectrogramAnalyzer::new(columns, ROWS, length, RANGE);
+    for index in 0..SAMPLE_RATE {
+        let time = f64::from(index) / f64::from(SAMPLE_RATE);
+        analyzer.push(amplitude * (TAU * frequency * time).sin());
+    }
+    analyzer.finish()
+}
+
+/// Get the row and level of the loudest bin of a column.
+fn get_peak(column: &[f64]) -> (usize, f64) {
+    column
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("column should not be empty")
+}
+
+#[test]
+fn spectrogram_analyzer_sine() {
+    // Arrange
+    // Act
+    let columns = analyze_sine(1_000.0, 0.5, 10);
+
+    // Assert
+    assert_eq!(columns.len(), 10);
+    let column = columns.get(5).expect("should have a middle column");
+    assert_eq!(column.len(), ROWS);
+    let (row, level) = get_peak(column);
+    // 1 kHz is bin 23.2 of a 1024 point FFT at 44.1 kHz
+    assert_eq!(row, 23);
+    assert!(
+        (level - -6.02).abs() < 1.0,
+        "level should be -6 dB, got {level}"
+    );
+}
+
+#[test]
+fn spectrogram_analyzer_silence() {
+    // Arrange
+    let mut analyzer = SpectrogramAnalyzer::new(20, ROWS, 1_000, RANGE);
+
+    // Act
+    for _ in 0..1_000 {
+        analyzer.push(0.0);
+    }
+    let columns = analyzer.finish();
+
+    // Assert
+    assert_eq!(columns.len(), 20);
+    assert!(
+        columns
+            .iter()
+            .flatten()
+            .all(|level| (level + RANGE).abs() < f64::EPSILON)
+    );
+}
+
+#[test]
+fn spectrogram_analyzer_empty() {
+    // Arrange
+    let analyzer = SpectrogramAnalyzer::new(500, 1025, 0, RANGE);
+
+    // Act
+    let columns = analyzer.finish();
+
+    // Assert
+    assert_eq!(columns.len(), 500);
+    assert!(columns.iter().all(|column| column.len() == 1025));
+}
+
+#[test]
+fn draw_spectrogram_dimensions() {
+    // Arrange
+    let levels = SpectrogramLevels {
+        sample_rate: SAMPLE_RATE,
+        start: 60.0,
+        duration: 2.0,
+        range: RANGE,
+        columns: analyze_sine(440.0, 0.5, 500),
+    };
+
+    // Act
+    let image = draw_spectrogram(&levels, "01 - Track.flac");
+
+    // Assert
+    assert_eq!(image.width(), 500 + 58 + 90);
+    assert_eq!(image.height(), 513 + 34 + 48);
+}
+
+#[test]
+fn get_colour_palette() {
+    // Arrange
+    // Act
+    let black = get_colour(0.0);
+    let white = get_colour(1.0);
+
+    // Assert
+    assert_eq!(black.0, [0, 0, 0]);
+    assert_eq!(white.0, [255, 255, 255]);
+}
+
+#[test]
+fn spectrogram_analyzer_range() {
+    // Arrange
+    let analyzer = SpectrogramAnalyzer::new(10, ROWS, 0, 140.0);
+
+    // Act
+    let columns = analyzer.finish();
+
+    // Assert
+    assert!(
+        columns
+            .iter()
+            .flatten()
+            .all(|level| (level + 140.0).abs() < f64::EPSILON)
+    );
+}
+
+#[test]
+fn get_loudest_start_finds_loudest_window() {
+    // Arrange
+    let energies = [1.0, 2.0, 1.0, 5.0, 6.0, 1.0, 9.0];
+
+    // Act
+    let start = get_loudest_start(&energies, 2);
+
+    // Assert
+    assert_eq!(start, 3);
+}
+
+#[test]
+fn get_loudest_start_shorter_than_window() {
+    // Arrange
+    let energies = [1.0, 2.0];
+
+    // Act
+    let start = get_loudest_start(&energies, 5);
+
+    // Assert
+    assert_eq!(start, 0);
+}
@@ -0,0 +1,93 @@
+use crate::testing_prelude::*;
+use image::{Rgb, RgbImage};
+
+fn mock_tracks() -> Vec<ReviewTrack> {
+    vec![
+        ReviewTrack {
+            label: "Disc 1 Track 1: CD1/01 - Intro.flac".to_owned(),
+            properties: vec![("Type", "FLAC".to_owned()), ("Time", "01:00".to_owned())],
+            images: vec![
+                PathBuf::from("CD1/01 - Intro.full.png"),
+                PathBuf::from("CD1/01 - Intro.zoom.png"),
+            ],
+        },
+        ReviewTrack {
+            label: "Track 2: 02 - <Rock & Roll>.flac".to_owned(),
+            properties: vec![("Type", "FLAC".to_owned())],
+            images: vec![PathBuf::from("02 - <Rock & Roll>.full.png")],
+        },
+    ]
+}
+
+#[test]
+fn render_review_page_lists_tracks() {
+    // Arrange
+    let tracks = mock_tracks();
+
+    // Act
+    let output = render_review_page("Artist - Album [2020] [FLAC]", &tracks);
+
+    // Assert
+    assert_snapshot!(output);
+}
+
+#[test]
+fn encode_uri_encodes_reserved_characters() {
+    // Arrange
+    let path = PathBuf::from("CD1/01 - Café #1.full.png");
+
+    // Act
+    let output = encode_uri(&path);
+
+    // Assert
+    assert_eq!(output, "CD1/01%20-%20Caf%C3%A9%20%231.full.png");
+}
+
+#[test]
+fn escape_html_escapes_markup() {
+    // Arrange
+    // Act
+    let output = escape_html("<a href=\"x\">Rock & 'Roll'</a>");
+
+    // Assert
+    assert_eq!(
+        output,
+        "&lt;a href=&quot;x&quot;&gt;Rock &amp; &#39;Roll&#39;&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn create_contact_sheet_composites_rows() {
+    // Arrange
+    let dir = TempDirectory::create("create_contact_sheet_composites_rows");
+    let tracks = vec![
+        ReviewTrack {
+            label: "Track 1: 01.flac".to_owned(),
+            properties: Vec::new(),
+            images: vec![PathBuf::from("01.full.png"), PathBuf::from("01.zoom.png")],
+        },
+        ReviewTrack {
+            label: "Track 2: 02.flac".to_owned(),
+            properties: Vec::new(),
+            images: vec![PathBuf::from("02.full.png")],
+        },
+    ];
+    let full = RgbImage::from_pixel(1200, 200, Rgb([255, 0, 0]));
+    let zoom = RgbImage::from_pixel(200, 400, Rgb([0, 0, 255]));
+    full.save(dir.join("01.full.png"))
+        .expect("should save image");
+    zoom.save(dir.join("01.zoom.png"))
+        .expect("should save image");
+    full.save(dir.join("02.full.png"))
+        .expect("should save image");
+
+    // Act
+    let sheet = create_contact_sheet(&dir, &tracks).expect("should create contact sheet");
+
+    // Assert
+    // Full is scaled to 1800 x 300 and zoom to 150 x 300, with 10 px spacing
+    assert_eq!(sheet.width(), 10 + 1800 + 10 + 150 + 10);
+    assert_eq!(sheet.height(), 10 + (16 + 300 + 10) * 2);
+    assert_eq!(sheet.get_pixel(20, 10 + 16 + 150), &Rgb([255, 0, 0]));
+    assert_eq!(sheet.get_pixel(1900, 10 + 16 + 150), &Rgb([0, 0, 255]));
+}
@@ -0,0 +1,199 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn spectrogram_variant_parse_standard() {
+    // Arrange
+    // Act
+    let full: SpectrogramVariant = "full".parse().expect("should parse");
+    let zoom: SpectrogramVariant = "Zoom".parse().expect("should parse");
+
+    // Assert
+    assert_eq!(full, SpectrogramVariant::from(Size::Full));
+    assert_eq!(zoom, SpectrogramVariant::from(Size::Zoom));
+    assert!(full.is_standard());
+    assert_eq!(full.get_suffix(), ".full.png");
+    assert_eq!(zoom.get_suffix(), ".zoom.png");
+}
+
+#[test]
+fn spectrogram_variant_parse_settings() {
+    // Arrange
+    let value = "zoom:at=loudest, channel=side,duration=4,width=800,height=2049,range=140";
+
+    // Act
+    let variant: SpectrogramVariant = value.parse().expect("should parse");
+
+    // Assert
+    assert_eq!(
+        variant,
+        SpectrogramVariant {
+            size: Size::Zoom,
+            channel: SpectrogramChannel::Side,
+            width: 800,
+            height: 2049,
+            range: 140,
+            start: ZoomStart::Loudest,
+            duration: 4,
+        }
+    );
+    assert!(!variant.is_standard());
+}
+
+#[test]
+fn spectrogram_variant_parse_zoom_start() {
+    // Arrange
+    let values = ["zoom:at=90", "zoom:at=1:30", "zoom:at=25%", "zoom:at=auto"];
+
+    // Act
+    let starts: Vec<ZoomStart> = values
+        .iter()
+        .map(|value| {
+            value
+                .parse::<SpectrogramVariant>()
+                .expect("should parse")
+                .start
+        })
+        .collect();
+
+    // Assert
+    assert_eq!(
+        starts,
+        vec![
+            ZoomStart::Time(90),
+            ZoomStart::Time(90),
+            ZoomStart::Percent(25),
+            ZoomStart::Auto,
+        ]
+    );
+}
+
+#[test]
+fn spectrogram_variant_parse_errors() {
+    // Arrange
+    let values = [
+        "wide",
+        "full:at=1:00",
+        "zoom:at=101%",
+        "zoom:channel=surround",
+        "full:range=500",
+        "full:height=1",
+        "full:colour=red",
+        "full:channel",
+    ];
+
+    // Act
+    let errors: Vec<String> = values
+        .iter()
+        .map(|value| {
+            value
+                .parse::<SpectrogramVariant>()
+                .expect_err("should not parse")
+                .to_string()
+        })
+        .collect();
+
+    // Assert
+    assert_eq!(
+        errors,
+        vec![
+            "unknown spectrogram size: wide",
+            "at only applies to zoom spectrograms",
+            "invalid value for at: 101%",
+            "invalid value for channel: surround",
+            "invalid value for range: 500",
+            "invalid value for height: 1",
+            "unknown spectrogram setting: colour",
+            "expected key=value: channel",
+        ]
+    );
+}
+
+#[test]
+fn spectrogram_variant_display_round_trip() {
+    // Arrange
+    let variant: SpectrogramVariant = "zoom:channel=right,at=50%,range=140"
+        .parse()
+        .expect("should parse");
+
+    // Act
+    let output = variant.to_string();
+    let parsed: SpectrogramVariant = output.parse().expect("should parse");
+
+    // Assert
+    assert_eq!(output, "zoom:at=50%,channel=right,range=140");
+    assert_eq!(parsed, variant);
+    assert_eq!(
+        variant.get_suffix(),
+        ".zoom-at-50pc-channel-right-range-140.png"
+    );
+}
+
+#[test]
+fn spectrogram_variant_yaml_round_trip() {
+    // Arrange
+    let yaml = "- full\n- zoom:at=loudest,channel=side\n";
+
+    // Act
+    let variants: Vec<SpectrogramVariant> = yaml_from_str(yaml).expect("should parse");
+    let output = yaml_to_string(&variants).expect("should serialize");
+
+    // Assert
+    assert_eq!(output, yaml);
+}
+
+#[test]
+fn spectrogram_variant_for_mono() {
+    // Arrange
+    let variant: SpectrogramVariant = "full:channel=side".parse().expect("should parse");
+
+    // Act
+    let mono = variant.for_channels(1);
+    let stereo = variant.for_channels(2);
+
+    // Assert
+    assert_eq!(mono.channel, SpectrogramChannel::Left);
+    assert_eq!(stereo.channel, SpectrogramChannel::Side);
+}
+
+#[test]
+fn spectrogram_channel_mix() {
+    // Arrange
+    let (left, right) = (0.5, 0.25);
+
+    // Act
+    let mixes: Vec<f64> = [
+        SpectrogramChannel::Left,
+        SpectrogramChannel::Right,
+        SpectrogramChannel::Mid,
+        SpectrogramChannel::Side,
+    ]
+    .iter()
+    .map(|channel| channel.mix(left, right))
+    .collect();
+
+    // Assert
+    assert_eq!(mixes, vec![0.5, 0.25, 0.375, 0.125]);
+}
+
+#[test]
+fn spectrogram_options_get_variants_removes_duplicates() {
+    // Arrange
+    let options = SpectrogramOptionsPartial {
+        spectrogram_variant: Some(vec![
+            "zoom".parse().expect("should parse"),
+            "zoom:channel=side".parse().expect("should parse"),
+        ]),
+        ..SpectrogramOptionsPartial::default()
+    }
+    .resolve_without_validation();
+
+    // Act
+    let variants: Vec<String> = options
+        .get_variants()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    // Assert
+    assert_eq!(variants, vec!["full", "zoom", "zoom:channel=side"]);
+}
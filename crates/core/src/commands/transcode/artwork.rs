@@ -0,0 +1,129 @@
+use crate::prelude::*;
+use lofty::config::ParseOptions;
+use lofty::file::AudioFile;
+use lofty::flac::FlacFile as LoftyFlacFile;
+use lofty::ogg::OggPictureStorage;
+use lofty::picture::{Picture, PictureType};
+
+/// File names of a folder cover, in order of preference.
+const COVER_FILE_NAMES: [&str; 4] = ["cover.jpg", "folder.jpg", "cover.png", "folder.png"];
+
+/// Information needed to apply an [`ArtworkPolicy`] to the pictures of a transcode.
+pub(crate) struct Artwork {
+    /// Policy to apply.
+    pub policy: ArtworkPolicy,
+    /// Path to the source FLAC.
+    pub input: PathBuf,
+    /// Path to the folder cover of the source, if there is one.
+    pub cover: Option<PathBuf>,
+    /// Maximum size in pixels of embedded pictures.
+    pub max_pixel_size: u32,
+    /// Quality percentage to apply for JPG compression.
+    pub quality: u8,
+}
+
+impl Artwork {
+    /// Get the pictures to embed in the output.
+    ///
+    /// - `keep` returns the pictures of the source
+    /// - `strip` returns no pictures
+    /// - `resize` downscales any picture larger than `max_pixel_size`
+    /// - `embed` returns the pictures of the source, or if there are none the folder cover
+    ///   downscaled as with `resize`
+    pub(crate) fn get_pictures(&self) -> Result<Vec<Picture>, Failure<TranscodeAction>> {
+        match self.policy {
+            ArtworkPolicy::Strip => Ok(Vec::new()),
+            ArtworkPolicy::Keep => self.read_pictures(),
+            ArtworkPolicy::Resize => self
+                .read_pictures()?
+                .into_iter()
+                .map(|picture| self.resize(picture))
+                .collect(),
+            ArtworkPolicy::Embed => {
+                let pictures = self.read_pictures()?;
+                if !pictures.is_empty() {
+                    return Ok(pictures);
+                }
+                let Some(cover) = &self.cover else {
+                    return Ok(pictures);
+                };
+                let mut file = File::open(cover)
+                    .map_err(Failure::wrap_with_path(TranscodeAction::ReadCover, cover))?;
+                let mut picture = Picture::from_reader(&mut file)
+                    .map_err(Failure::wrap_with_path(TranscodeAction::ReadCover, cover))?;
+                picture.set_pic_type(PictureType::CoverFront);
+                Ok(vec![self.resize(picture)?])
+            }
+        }
+    }
+
+    /// Read the pictures embedded in the source.
+    fn read_pictures(&self) -> Result<Vec<Picture>, Failure<TranscodeAction>> {
+        let mut file = File::open(&self.input).map_err(Failure::wrap_with_path(
+            TranscodeAction::ReadFlac,
+            &self.input,
+        ))?;
+        let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default()).map_err(
+            Failure::wrap_with_path(TranscodeAction::ReadFlac, &self.input),
+        )?;
+        Ok(flac
+            .pictures()
+            .iter()
+            .map(|(picture, _)| picture.clone())
+            .collect())
+    }
+
+    /// Downscale a picture larger than `max_pixel_size`, keeping its type and description.
+    fn resize(&self, picture: Picture) -> Result<Picture, Failure<TranscodeAction>> {
+        let resized = resize_image_data(
+            picture.data(),
+            &self.input,
+            self.max_pixel_size,
+            self.quality,
+        )
+        .map_err(Failure::wrap(TranscodeAction::ResizeImage))?;
+        let Some(data) = resized else {
+            return Ok(picture);
+        };
+        let mut builder = Picture::unchecked(data).pic_type(picture.pic_type());
+        if let Some(mime_type) = picture.mime_type() {
+            builder = builder.mime_type(mime_type.clone());
+        }
+        if let Some(description) = picture.description() {
+            builder = builder.description(description.to_owned());
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Find the folder cover of a FLAC in its directory or the source directory.
+///
+/// File names are matched case-insensitively against [`COVER_FILE_NAMES`].
+#[must_use]
+pub(crate) fn find_cover(flac: &FlacFile, source_dir: &Path) -> Option<PathBuf> {
+    let flac_dir = flac.path.parent();
+    [flac_dir, Some(source_dir)]
+        .into_iter()
+        .flatten()
+        .find_map(find_cover_in_dir)
+}
+
+/// Find the first of [`COVER_FILE_NAMES`] in `dir`.
+fn find_cover_in_dir(dir: &Path) -> Option<PathBuf> {
+    let mut files: Vec<PathBuf> = read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    COVER_FILE_NAMES.iter().find_map(|name| {
+        files
+            .iter()
+            .find(|path| {
+                path.file_name()
+                    .is_some_and(|file_name| file_name.to_string_lossy().eq_ignore_ascii_case(name))
+            })
+            .cloned()
+    })
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+
+/// How to handle pictures embedded in the tags of transcoded output.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ArtworkPolicy {
+    /// Embed the pictures of the source unchanged.
+    #[default]
+    Keep,
+    /// Remove all embedded pictures.
+    Strip,
+    /// Downscale embedded pictures larger than `max_pixel_size`.
+    Resize,
+    /// Embed the folder cover as the front cover if the source has no embedded pictures.
+    Embed,
+}
@@ -4,22 +4,65 @@ use crate::prelude::*;
 pub(crate) struct Decode {
     /// Path to the input file
     pub input: PathBuf,
+    /// Samples of the input to decode, or `None` for the whole file
+    ///
+    /// A range is only decoded in process so the backend must be [`ResampleBackend::Native`]
+    pub range: Option<SampleRange>,
     /// Optional resample rate
     pub resample_rate: Option<u32>,
     /// Use repeatable mode for `SoX` (deterministic dithering)
     pub repeatable: bool,
+    /// Backend to resample with
+    pub backend: ResampleBackend,
     /// Factory for creating sox commands
     pub sox: Ref<SoxFactory>,
 }
 
 impl Decode {
+    /// Is the FLAC decoded in process rather than by a child process?
+    ///
+    /// True unless a resample is required with the [`ResampleBackend::Sox`] backend.
+    #[must_use]
+    pub(crate) fn is_in_process(&self) -> bool {
+        self.resample_rate.is_none() || self.backend == ResampleBackend::Native
+    }
+
     /// Get the [`CommandInfo`] for the decode command.
+    ///
+    /// Returns `None` if the FLAC is decoded in process with [`decode_flac_to_wav`]
+    /// or [`resample_flac_to_wav`].
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_info(self) -> CommandInfo {
+    pub(crate) fn to_info(self) -> Option<CommandInfo> {
+        if self.is_in_process() {
+            return None;
+        }
+        let rate = self.resample_rate?;
+        Some(self.decode_with_resample(rate))
+    }
+
+    /// Get a string representation of the decode step.
+    ///
+    /// In process steps have no CLI command so are described by the decoder and input.
+    #[must_use]
+    pub(crate) fn display(self) -> String {
+        if !self.is_in_process() {
+            return self
+                .to_info()
+                .expect("decode should have a command")
+                .display();
+        }
+        let decode = format!(
+            "claxon (in-process) {}{}",
+            self.input.to_string_lossy(),
+            display_range(self.range)
+        );
         match self.resample_rate {
-            Some(rate) => self.decode_with_resample(rate),
-            None => decode_without_resample(self.input),
+            Some(rate) => format!(
+                "{decode} | {}",
+                display_native_resample(rate, self.repeatable)
+            ),
+            None => decode,
         }
     }
 
@@ -45,14 +88,3 @@ impl Decode {
         info
     }
 }
-
-fn decode_without_resample(input: PathBuf) -> CommandInfo {
-    CommandInfo {
-        program: FLAC.to_owned(),
-        args: vec![
-            "-dcs".to_owned(),
-            "--".to_owned(),
-            input.to_string_lossy().to_string(),
-        ],
-    }
-}
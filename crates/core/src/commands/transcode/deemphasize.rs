@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// Information needed to de-emphasize a pre-emphasized FLAC before encoding.
+pub(crate) struct Deemphasize {
+    /// Path to the input file
+    pub input: PathBuf,
+    /// Samples of the input to de-emphasize, or `None` for the whole file
+    ///
+    /// A range is only de-emphasized in process so the backend must be
+    /// [`ResampleBackend::Native`]
+    pub range: Option<SampleRange>,
+    /// Use repeatable mode for `SoX` (deterministic dithering)
+    pub repeatable: bool,
+    /// Backend to de-emphasize with
+    pub backend: ResampleBackend,
+    /// Factory for creating sox commands
+    pub sox: Ref<SoxFactory>,
+}
+
+impl Deemphasize {
+    /// Is the FLAC de-emphasized in process rather than by `SoX`?
+    ///
+    /// True if the backend is [`ResampleBackend::Native`].
+    #[must_use]
+    pub(crate) fn is_in_process(&self) -> bool {
+        self.backend == ResampleBackend::Native
+    }
+
+    /// Get the [`CommandInfo`] for `SoX` to de-emphasize the FLAC.
+    ///
+    /// - Writes a 16-bit FLAC to `output`, or a 16-bit WAV to stdout if there is no `output`
+    /// - Returns `None` if the FLAC is de-emphasized in process with [`PcmPipeline`]
+    #[must_use]
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_info(self, output: Option<&Path>) -> Option<CommandInfo> {
+        if self.is_in_process() {
+            return None;
+        }
+        let mut info = self.sox.create();
+        if self.repeatable {
+            info.args.push("-R".to_owned());
+        }
+        info.args.extend([
+            self.input.to_string_lossy().to_string(),
+            "-G".to_owned(),
+            "-b".to_owned(),
+            "16".to_owned(),
+        ]);
+        match output {
+            Some(output) => info.args.push(output.to_string_lossy().to_string()),
+            None => info
+                .args
+                .extend(["-t".to_owned(), "wav".to_owned(), "-".to_owned()]),
+        }
+        info.args.extend(["deemph".to_owned(), "dither".to_owned()]);
+        Some(info)
+    }
+
+    /// Get a string representation of the de-emphasis and encode steps.
+    ///
+    /// FLAC output is written by `SoX` or in process so only MP3 output has an encode command.
+    #[must_use]
+    pub(crate) fn display(self, encode: Encode) -> String {
+        let is_flac = encode.format == TargetFormat::Flac;
+        if !self.is_in_process() {
+            let output = is_flac.then_some(encode.output.as_path());
+            let info = self
+                .to_info(output)
+                .expect("deemphasize should have a command");
+            if is_flac {
+                return info.display();
+            }
+            return format!("{} | {}", info.display(), encode.to_info().display());
+        }
+        let deemphasize = format!(
+            "claxon (in-process) {}{} | {}",
+            self.input.to_string_lossy(),
+            display_range(self.range),
+            display_native_deemphasis(self.repeatable)
+        );
+        if is_flac {
+            format!(
+                "{deemphasize} | flacenc (in-process) {}",
+                encode.output.to_string_lossy()
+            )
+        } else {
+            format!("{deemphasize} | {}", encode.to_info().display())
+        }
+    }
+}
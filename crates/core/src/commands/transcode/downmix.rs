@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+/// Information needed to downmix a multichannel FLAC to stereo before encoding.
+pub(crate) struct Downmix {
+    /// Path to the input file
+    pub input: PathBuf,
+    /// Samples of the input to downmix, or `None` for the whole file
+    pub range: Option<SampleRange>,
+    /// Number of channels of the input
+    pub channels: u32,
+    /// Optional resample rate
+    pub resample_rate: Option<u32>,
+    /// Undo the CD pre-emphasis of the input before mixing
+    pub deemphasize: bool,
+    /// Use repeatable mode for deterministic dithering
+    pub repeatable: bool,
+}
+
+impl Downmix {
+    /// Get a string representation of the downmix step.
+    ///
+    /// The downmix is in process so it is described by the decoder and each processing step.
+    #[must_use]
+    pub(crate) fn display(&self) -> String {
+        let mut steps = vec![format!(
+            "claxon (in-process) {}{}",
+            self.input.to_string_lossy(),
+            display_range(self.range)
+        )];
+        if self.deemphasize {
+            steps.push("deemph (in-process)".to_owned());
+        }
+        steps.push(display_native_downmix(self.channels));
+        match self.resample_rate {
+            Some(rate) => steps.push(display_native_resample(rate, self.repeatable)),
+            None if self.repeatable => steps.push("dither (in-process, repeatable)".to_owned()),
+            None => steps.push("dither (in-process)".to_owned()),
+        }
+        steps.join(" | ")
+    }
+}
@@ -0,0 +1,126 @@
+use crate::prelude::*;
+use claxon::FlacReader;
+use claxon::frame::Block;
+use claxon::metadata::StreamInfo;
+use std::ops::Range;
+
+/// Bit depth of [`FlacDecoder::for_each_chunk`] output.
+const OUTPUT_BITS_PER_SAMPLE: u32 = 16;
+
+/// Decode FLAC frames in process with `claxon`.
+///
+/// Decode errors are reported with the path and the number of samples per channel decoded
+/// before the failure.
+///
+/// If there is a [`SampleRange`] only the samples in the range are decoded, so a track can be
+/// split from a FLAC image.
+pub(crate) struct FlacDecoder {
+    path: PathBuf,
+    reader: FlacReader<File>,
+    range: Option<SampleRange>,
+}
+
+impl FlacDecoder {
+    /// Open a FLAC file and read its metadata.
+    pub(crate) fn open(path: &Path) -> Result<Self, Failure<TranscodeAction>> {
+        trace!("Decoding FLAC {}", path.display());
+        let reader = FlacReader::open(path)
+            .map_err(Failure::wrap_with_path(TranscodeAction::Decode, path))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            reader,
+            range: None,
+        })
+    }
+
+    /// Only decode the samples in `range`, or every sample if `None`.
+    #[must_use]
+    pub(crate) fn with_range(mut self, range: Option<SampleRange>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the [`StreamInfo`] of the FLAC.
+    ///
+    /// If there is a [`SampleRange`] the number of samples is the length of the range.
+    #[must_use]
+    pub(crate) fn stream_info(&self) -> StreamInfo {
+        let mut info = self.reader.streaminfo();
+        if let Some(range) = self.range {
+            info.samples = range.get_length(info.samples);
+        }
+        info
+    }
+
+    /// Decode every frame to 16-bit without any processing, passing the interleaved samples of
+    /// each [`Block`] to `f` until it returns `false`.
+    ///
+    /// Sources deeper than 16-bit must be resampled instead.
+    pub(crate) fn for_each_chunk(
+        &mut self,
+        mut f: impl FnMut(&[i16]) -> bool,
+    ) -> Result<(), Failure<TranscodeAction>> {
+        let info = self.stream_info();
+        if info.bits_per_sample > OUTPUT_BITS_PER_SAMPLE {
+            return Err(Failure::new(
+                TranscodeAction::Decode,
+                TranscodeError::UnsupportedBitDepth(info.bits_per_sample),
+            )
+            .with_path(&self.path));
+        }
+        let shift = OUTPUT_BITS_PER_SAMPLE - info.bits_per_sample;
+        let mut chunk = Vec::new();
+        self.for_each_block(|block, indexes| {
+            chunk.clear();
+            for index in indexes {
+                for channel in 0..block.channels() {
+                    let sample = i16::try_from(block.sample(channel, index) << shift)
+                        .expect("sample should fit in 16 bits");
+                    chunk.push(sample);
+                }
+            }
+            f(&chunk)
+        })
+    }
+
+    /// Decode every frame, passing each [`Block`] and the indexes of its samples to decode to
+    /// `f` until it returns `false`.
+    ///
+    /// Blocks with no samples in the [`SampleRange`] are skipped, and decoding stops at the end
+    /// of the range.
+    pub(crate) fn for_each_block(
+        &mut self,
+        mut f: impl FnMut(&Block, Range<u32>) -> bool,
+    ) -> Result<(), Failure<TranscodeAction>> {
+        let range = self.range;
+        let mut decoded: u64 = 0;
+        let mut buffer = Vec::new();
+        let mut blocks = self.reader.blocks();
+        loop {
+            let block = match blocks.read_next_or_eof(buffer) {
+                Ok(Some(block)) => block,
+                Ok(None) => return Ok(()),
+                Err(error) => {
+                    return Err(Failure::new(TranscodeAction::Decode, error)
+                        .with("sample", decoded.to_string())
+                        .with_path(&self.path));
+                }
+            };
+            let offset = decoded;
+            decoded += u64::from(block.duration());
+            let indexes = match range {
+                Some(range) => range.get_block_indexes(offset, block.duration()),
+                None => Some(0..block.duration()),
+            };
+            if let Some(indexes) = indexes
+                && !f(&block, indexes)
+            {
+                return Ok(());
+            }
+            if range.is_some_and(|range| range.is_past_end(decoded)) {
+                return Ok(());
+            }
+            buffer = block.into_buffer();
+        }
+    }
+}
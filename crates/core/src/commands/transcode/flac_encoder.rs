@@ -0,0 +1,190 @@
+use crate::prelude::*;
+use flacenc::bitsink::ByteSink;
+use flacenc::component::BitRepr;
+use flacenc::component::{Stream, StreamInfo};
+use flacenc::config::{Encoder as FlacEncoderConfig, OrderSel};
+use flacenc::encode_fixed_size_frame;
+use flacenc::error::{Verified, Verify};
+use flacenc::source::{Fill, FrameBuf};
+use md5::{Digest, Md5};
+use std::fmt::Debug;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+
+/// Bit depth of encoded output.
+const OUTPUT_BITS_PER_SAMPLE: u32 = 16;
+
+/// Highest LPC order of the FLAC subset for sample rates up to 48 kHz, as used by `flac --best`.
+const BEST_LPC_ORDER: usize = 12;
+
+/// Encode 16-bit PCM to FLAC in process with `flacenc`, one frame at a time.
+///
+/// - Samples are buffered until there is a full block so the output is never held in memory
+/// - `STREAMINFO` is written first and rewritten by [`FlacEncoder::finish`] once the MD5
+///   signature and number of samples are known
+/// - Frames are encoded individually rather than with `encode_with_fixed_block_size` which pads
+///   the final frame with silence to a full block, and includes the padding in the MD5 signature
+pub(crate) struct FlacEncoder<W: Write + Seek> {
+    writer: W,
+    path: Option<PathBuf>,
+    config: Verified<FlacEncoderConfig>,
+    info: StreamInfo,
+    channels: usize,
+    md5: Md5,
+    buffer: Vec<i32>,
+    frame_number: usize,
+    samples: usize,
+}
+
+impl FlacEncoder<BufWriter<File>> {
+    /// Create a FLAC file at `path` to encode samples to.
+    pub(crate) fn create(
+        path: &Path,
+        channels: u32,
+        sample_rate: u32,
+    ) -> Result<Self, Failure<TranscodeAction>> {
+        let file = File::create(path)
+            .map_err(Failure::wrap_with_path(TranscodeAction::EncodeFlac, path))?;
+        let mut encoder = Self::new(BufWriter::new(file), channels, sample_rate)?;
+        encoder.path = Some(path.to_path_buf());
+        Ok(encoder)
+    }
+}
+
+impl<W: Write + Seek> FlacEncoder<W> {
+    /// Create a [`FlacEncoder`] that writes to `writer`.
+    pub(crate) fn new(
+        writer: W,
+        channels: u32,
+        sample_rate: u32,
+    ) -> Result<Self, Failure<TranscodeAction>> {
+        let config = FlacEncoderConfig::default()
+            .into_verified()
+            .expect("default FLAC encoder config should be valid");
+        let channels = usize::try_from(channels).expect("channels should fit in usize");
+        let bits_per_sample =
+            usize::try_from(OUTPUT_BITS_PER_SAMPLE).expect("bit depth should fit in usize");
+        let sample_rate = usize::try_from(sample_rate).expect("sample rate should fit in usize");
+        let info = StreamInfo::new(sample_rate, channels, bits_per_sample)
+            .map_err(|error| encode_error(None, error))?;
+        let buffer = Vec::with_capacity(config.block_size * channels);
+        let mut encoder = Self {
+            writer,
+            path: None,
+            config,
+            info,
+            channels,
+            md5: Md5::new(),
+            buffer,
+            frame_number: 0,
+            samples: 0,
+        };
+        encoder.write_stream_info()?;
+        Ok(encoder)
+    }
+
+    /// Encode with the strongest compression of `flacenc` that stays within the FLAC subset.
+    ///
+    /// - LPC order is raised to [`BEST_LPC_ORDER`]
+    /// - Fixed predictor order is selected by counting the encoded bits of each order rather
+    ///   than estimating them
+    /// - Slower than the default so it's only used where the size of the output matters
+    #[must_use]
+    pub(crate) fn with_best_compression(mut self) -> Self {
+        let mut config = FlacEncoderConfig::default();
+        config.subframe_coding.qlpc.lpc_order = BEST_LPC_ORDER;
+        config.subframe_coding.fixed.order_sel = OrderSel::BitCount;
+        self.config = config
+            .into_verified()
+            .expect("best FLAC encoder config should be valid");
+        self
+    }
+
+    /// Encode interleaved samples, writing each full block as a frame.
+    pub(crate) fn write(&mut self, samples: &[i16]) -> Result<(), Failure<TranscodeAction>> {
+        let block = self.config.block_size * self.channels;
+        for sample in samples {
+            self.md5.update(sample.to_le_bytes());
+            self.buffer.push(i32::from(*sample));
+            if self.buffer.len() == block {
+                self.write_frame()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Encode the remaining samples and rewrite `STREAMINFO`.
+    ///
+    /// Returns the writer, positioned at the end of the stream.
+    pub(crate) fn finish(mut self) -> Result<W, Failure<TranscodeAction>> {
+        if !self.buffer.is_empty() {
+            self.write_frame()?;
+        }
+        if self.frame_number > 1 {
+            // The final frame of a fixed block size stream may be shorter
+            self.info
+                .set_block_sizes(self.config.block_size, self.config.block_size)
+                .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        }
+        let md5 = self.md5.clone().finalize();
+        self.info.set_md5_digest(&md5.into());
+        self.info.set_total_samples(self.samples);
+        let end = self
+            .writer
+            .stream_position()
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.writer
+            .seek(SeekFrom::Start(0))
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        // STREAMINFO has a fixed size so it's rewritten in place
+        self.write_stream_info()?;
+        self.writer
+            .seek(SeekFrom::Start(end))
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.writer
+            .flush()
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        Ok(self.writer)
+    }
+
+    fn write_stream_info(&mut self) -> Result<(), Failure<TranscodeAction>> {
+        let mut sink = ByteSink::new();
+        Stream::with_stream_info(self.info.clone())
+            .write(&mut sink)
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.writer
+            .write_all(sink.as_slice())
+            .map_err(|error| encode_error(self.path.as_deref(), error))
+    }
+
+    fn write_frame(&mut self) -> Result<(), Failure<TranscodeAction>> {
+        let frames = self.buffer.len().div_ceil(self.channels);
+        let mut framebuf = FrameBuf::with_size(self.channels, frames)
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        framebuf
+            .fill_interleaved(&self.buffer)
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        let frame = encode_fixed_size_frame(&self.config, &framebuf, self.frame_number, &self.info)
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.info.update_frame_info(&frame);
+        let mut sink = ByteSink::new();
+        frame
+            .write(&mut sink)
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.writer
+            .write_all(sink.as_slice())
+            .map_err(|error| encode_error(self.path.as_deref(), error))?;
+        self.frame_number += 1;
+        self.samples += frames;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+fn encode_error(path: Option<&Path>, error: impl Debug) -> Failure<TranscodeAction> {
+    let failure =
+        Failure::from_action(TranscodeAction::EncodeFlac).with("error", format!("{error:?}"));
+    match path {
+        Some(path) => failure.with_path(path),
+        None => failure,
+    }
+}
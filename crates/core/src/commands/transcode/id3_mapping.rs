@@ -0,0 +1,247 @@
+use crate::prelude::*;
+use lofty::TextEncoding;
+use lofty::id3::v2::{ExtendedTextFrame, Frame, FrameId, Id3v2Tag, TextInformationFrame};
+use lofty::tag::{ItemKey, TagType};
+use std::borrow::Cow;
+
+/// Frame ID of user defined text frames.
+const USER_TEXT: &str = "TXXX";
+
+/// Separator of multi-value text frames in `ID3v2.4`.
+const MULTI_VALUE_SEPARATOR: char = '\0';
+
+/// Separator multi-value text frames are joined with for `ID3v2.3`.
+const ID3V23_SEPARATOR: &str = "/";
+
+/// Frame ID of the lead artist.
+const ARTIST: &str = "TPE1";
+
+/// Frame ID of the genre.
+const GENRE: &str = "TCON";
+
+/// Mapping of a Vorbis comment key to an `ID3v2` text frame.
+///
+/// Declared as `KEY=FRAME`, or `KEY=TXXX:DESCRIPTION` for a user defined text frame.
+///
+/// For example `LABEL=TPUB` or `CATALOGNUMBER=TXXX:CATALOG NUMBER`.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Id3Mapping {
+    /// Uppercase Vorbis comment key.
+    pub key: String,
+    /// ID of the text frame.
+    pub frame: String,
+    /// Description of a `TXXX` frame.
+    pub description: Option<String>,
+}
+
+impl Id3Mapping {
+    /// Get the key of the mapped frame, such as `TPUB` or `TXXX:CATALOG NUMBER`.
+    #[must_use]
+    pub(crate) fn get_frame_key(&self) -> String {
+        match &self.description {
+            Some(description) => format!("{}:{description}", self.frame),
+            None => self.frame.clone(),
+        }
+    }
+
+    /// Replace the frames of the key in `tags` with a frame of `values`.
+    ///
+    /// - The frame lofty converts the key to is removed so the value is not duplicated
+    /// - Multiple values are null separated, matching lofty's conversion
+    /// - If there are no values the mapped frame is removed
+    fn apply(&self, tags: &mut Id3v2Tag, values: &[String]) {
+        if let Some(id) = ItemKey::from_key(TagType::VorbisComments, &self.key)
+            .and_then(|item| item.map_key(TagType::Id3v2))
+        {
+            // lofty maps keys without a text frame to a TXXX frame described by the ID
+            if is_text_frame_id(id) {
+                remove_frame(tags, id, None);
+            } else {
+                remove_frame(tags, USER_TEXT, Some(id));
+            }
+        }
+        remove_frame(tags, &self.frame, self.description.as_deref());
+        if values.is_empty() {
+            return;
+        }
+        let value = values.join(&MULTI_VALUE_SEPARATOR.to_string());
+        let frame = match &self.description {
+            Some(description) => Frame::UserText(ExtendedTextFrame::new(
+                TextEncoding::UTF8,
+                description.clone(),
+                value,
+            )),
+            None => Frame::Text(TextInformationFrame::new(
+                FrameId::Valid(Cow::Owned(self.frame.clone())),
+                TextEncoding::UTF8,
+                value,
+            )),
+        };
+        tags.insert(frame);
+    }
+}
+
+impl FromStr for Id3Mapping {
+    type Err = Id3MappingError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (key, frame) = value
+            .split_once('=')
+            .ok_or_else(|| Id3MappingError::InvalidMapping(value.to_owned()))?;
+        let key = key.trim().to_uppercase();
+        if key.is_empty() {
+            return Err(Id3MappingError::InvalidMapping(value.to_owned()));
+        }
+        let (frame, description) = match frame.split_once(':') {
+            Some((frame, description)) => (frame.trim(), Some(description.trim().to_owned())),
+            None => (frame.trim(), None),
+        };
+        if !is_text_frame_id(frame) {
+            return Err(Id3MappingError::InvalidFrame(frame.to_owned()));
+        }
+        let description = match (frame == USER_TEXT, description) {
+            (true, Some(description)) if !description.is_empty() => Some(description),
+            (true, _) => return Err(Id3MappingError::MissingDescription(key)),
+            (false, Some(_)) => return Err(Id3MappingError::UnexpectedDescription(key)),
+            (false, None) => None,
+        };
+        Ok(Self {
+            key,
+            frame: frame.to_owned(),
+            description,
+        })
+    }
+}
+
+impl TryFrom<String> for Id3Mapping {
+    type Error = Id3MappingError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Id3Mapping> for String {
+    fn from(value: Id3Mapping) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for Id3Mapping {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}={}", self.key, self.get_frame_key())
+    }
+}
+
+/// Errors parsing an [`Id3Mapping`].
+#[derive(Clone, Debug, Eq, PartialEq, ThisError)]
+pub enum Id3MappingError {
+    #[error("expected KEY=FRAME: {0}")]
+    InvalidMapping(String),
+    #[error("not an ID3v2 text frame: {0}")]
+    InvalidFrame(String),
+    #[error("TXXX mapping of {0} requires a description")]
+    MissingDescription(String),
+    #[error("only TXXX mappings have a description: {0}")]
+    UnexpectedDescription(String),
+}
+
+/// Get the `ID3v2` tags to write to MP3 output of a FLAC.
+///
+/// - Starts from the tags lofty converts the Vorbis comments to, see [`FlacFile::id3_tags`]
+/// - Each of `id3_mapping` replaces the frame of its key with all the Vorbis values of the key
+/// - Multiple artists and genres are joined with `id3_artist_separator` and
+///   `id3_genre_separator`
+/// - For `ID3v2.3` any other multiple values are joined with `/` and frames introduced in
+///   `ID3v2.4` are removed
+///
+/// Values of a track of a FLAC image are those of the track.
+pub(crate) fn map_id3_tags(
+    flac: &FlacFile,
+    options: &Id3Options,
+) -> Result<Id3v2Tag, Failure<TranscodeAction>> {
+    let mut tags = flac.id3_tags()?.clone();
+    if !options.id3_mapping.is_empty() {
+        let comments =
+            get_all_vorbis_comments(&flac.path).map_err(Failure::wrap(TranscodeAction::GetTags))?;
+        let track_comments = flac
+            .image_track
+            .as_ref()
+            .map(ImageTrack::get_comments)
+            .unwrap_or_default();
+        for mapping in &options.id3_mapping {
+            let track_values: Vec<String> = track_comments
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case(&mapping.key))
+                .map(|(_, value)| value.clone())
+                .collect();
+            let values = if track_values.is_empty() {
+                comments
+                    .iter()
+                    .filter(|(key, _)| key.eq_ignore_ascii_case(&mapping.key))
+                    .map(|(_, value)| value.clone())
+                    .collect()
+            } else {
+                track_values
+            };
+            mapping.apply(&mut tags, &values);
+        }
+    }
+    let mut output = Id3v2Tag::new();
+    for frame in tags {
+        if options.id3_version == Id3Version::V23 && ID3V24_ONLY_FRAMES.contains(&frame.id_str()) {
+            continue;
+        }
+        output.insert(join_values(frame, options));
+    }
+    Ok(output)
+}
+
+/// Join the null separated values of a text frame according to the join rules of `options`.
+fn join_values(frame: Frame<'static>, options: &Id3Options) -> Frame<'static> {
+    let default = match options.id3_version {
+        Id3Version::V24 => None,
+        Id3Version::V23 => Some(ID3V23_SEPARATOR),
+    };
+    let separator = match frame.id_str() {
+        ARTIST => options.id3_artist_separator.as_deref().or(default),
+        GENRE => options.id3_genre_separator.as_deref().or(default),
+        _ => default,
+    };
+    let Some(separator) = separator else {
+        return frame;
+    };
+    match frame {
+        Frame::Text(mut text) => {
+            text.value = Cow::Owned(text.value.replace(MULTI_VALUE_SEPARATOR, separator));
+            Frame::Text(text)
+        }
+        Frame::UserText(mut text) => {
+            text.content = Cow::Owned(text.content.replace(MULTI_VALUE_SEPARATOR, separator));
+            Frame::UserText(text)
+        }
+        frame => frame,
+    }
+}
+
+/// Remove the frames of a text frame ID, or the `TXXX` frame of a description.
+fn remove_frame(tags: &mut Id3v2Tag, id: &str, description: Option<&str>) {
+    match description {
+        Some(description) => {
+            let _ = tags.remove_user_text(description);
+        }
+        None => {
+            let _ = tags.remove(&FrameId::Valid(Cow::Borrowed(id))).count();
+        }
+    }
+}
+
+/// Check if `id` is the ID of an `ID3v2` text frame.
+fn is_text_frame_id(id: &str) -> bool {
+    id.len() == 4
+        && id.starts_with('T')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
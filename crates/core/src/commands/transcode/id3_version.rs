@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+use lofty::config::WriteOptions;
+
+/// Frames introduced in `ID3v2.4` that are discarded when writing `ID3v2.3`.
+pub(crate) const ID3V24_ONLY_FRAMES: &[&str] = &[
+    "ASPI", "EQU2", "RVA2", "SEEK", "SIGN", "TDEN", "TDRL", "TDTG", "TMOO", "TPRO", "TSOA", "TSOP",
+    "TSOT", "TSST",
+];
+
+/// Version of the `ID3v2` tags written to MP3 output.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum Id3Version {
+    /// `ID3v2.4` with null separated multi-value text frames.
+    #[default]
+    #[value(name = "v2.4")]
+    #[serde(rename = "v2.4")]
+    V24,
+    /// `ID3v2.3` for players that can't read `ID3v2.4`.
+    ///
+    /// Multi-value text frames are joined as `ID3v2.3` has no separator.
+    #[value(name = "v2.3")]
+    #[serde(rename = "v2.3")]
+    V23,
+}
+
+impl Id3Version {
+    /// Get the lofty [`WriteOptions`] to save tags of the version.
+    #[must_use]
+    pub(crate) fn get_write_options(self) -> WriteOptions {
+        WriteOptions::default().use_id3v23(self == Id3Version::V23)
+    }
+}
@@ -2,28 +2,64 @@
 
 pub(crate) use additional_job::*;
 pub(crate) use additional_job_factory::*;
+pub(crate) use artwork::*;
+pub(crate) use artwork_policy::*;
 pub(crate) use command_info::*;
 pub(crate) use decode::*;
+pub(crate) use deemphasize::*;
+pub(crate) use downmix::*;
 pub(crate) use encode::*;
+pub(crate) use flac_decoder::*;
+pub(crate) use flac_encoder::*;
+pub(crate) use id3_mapping::*;
+pub(crate) use id3_version::*;
 pub(crate) use include::*;
+pub(crate) use native_deemphasis::*;
+pub(crate) use native_downmix::*;
+pub(crate) use native_resample::*;
+pub(crate) use pcm_pipeline::*;
+pub(crate) use pre_emphasis_policy::*;
+pub(crate) use reencode::*;
+pub(crate) use replay_gain::*;
 pub(crate) use resample::*;
+pub(crate) use resample_backend::*;
 pub(crate) use resize::*;
+pub(crate) use split::*;
 pub(crate) use streaminfo_helpers::*;
 pub(crate) use transcode_action::*;
 pub(crate) use transcode_command::*;
 pub(crate) use transcode_job::*;
 pub(crate) use transcode_job_factory::*;
 pub(crate) use transcode_status::*;
+pub(crate) use transcode_validator::*;
 pub(crate) use variant::*;
+pub(crate) use wav_decoder::*;
 
 mod additional_job;
 mod additional_job_factory;
+mod artwork;
+mod artwork_policy;
 mod command_info;
 mod decode;
+mod deemphasize;
+mod downmix;
 mod encode;
+mod flac_decoder;
+mod flac_encoder;
+mod id3_mapping;
+mod id3_version;
 mod include;
+mod native_deemphasis;
+mod native_downmix;
+mod native_resample;
+mod pcm_pipeline;
+mod pre_emphasis_policy;
+mod reencode;
+mod replay_gain;
 mod resample;
+mod resample_backend;
 mod resize;
+mod split;
 mod streaminfo_helpers;
 #[cfg(test)]
 mod tests;
@@ -32,4 +68,6 @@ mod transcode_command;
 mod transcode_job;
 mod transcode_job_factory;
 mod transcode_status;
+mod transcode_validator;
 mod variant;
+mod wav_decoder;
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// De-emphasize a FLAC in process and write the output as a 16-bit FLAC.
+///
+/// - Refer to [`PcmPipeline`] for details of the de-emphasis
+/// - Vorbis comments are copied from the input, matching the behavior of `SoX`
+pub(crate) fn deemphasize_flac_to_flac(
+    input: &Path,
+    range: Option<SampleRange>,
+    output: &Path,
+    repeatable: bool,
+) -> Result<(), Failure<TranscodeAction>> {
+    let pipeline = PcmPipeline {
+        path: input.to_path_buf(),
+        range,
+        resample_rate: None,
+        deemphasize: true,
+        downmix: false,
+        repeatable,
+    };
+    write_pipeline_to_flac(&pipeline, output)?;
+    copy_vorbis_comments(input, output).map_err(Failure::wrap(TranscodeAction::WriteTags))?;
+    Ok(())
+}
+
+/// Get a string representation of an in process de-emphasis for display.
+#[must_use]
+pub(crate) fn display_native_deemphasis(repeatable: bool) -> String {
+    let mode = if repeatable { ", repeatable" } else { "" };
+    format!("deemph (in-process{mode}) dither")
+}
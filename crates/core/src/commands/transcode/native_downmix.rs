@@ -0,0 +1,5 @@
+/// Get a string representation of an in process downmix for display.
+#[must_use]
+pub(crate) fn display_native_downmix(channels: u32) -> String {
+    format!("downmix (in-process, ITU-R BS.775) {channels} to 2 channels")
+}
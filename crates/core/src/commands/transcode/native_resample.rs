@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+/// Resample a FLAC in process and write the output as a 16-bit FLAC.
+///
+/// - Refer to [`PcmPipeline`] for details of the resampling
+/// - Vorbis comments are copied from the input, matching the behavior of `SoX`
+pub(crate) fn resample_flac_to_flac(
+    input: &Path,
+    range: Option<SampleRange>,
+    output: &Path,
+    resample_rate: u32,
+    repeatable: bool,
+) -> Result<(), Failure<TranscodeAction>> {
+    let pipeline = PcmPipeline {
+        path: input.to_path_buf(),
+        range,
+        resample_rate: Some(resample_rate),
+        deemphasize: false,
+        downmix: false,
+        repeatable,
+    };
+    write_pipeline_to_flac(&pipeline, output)?;
+    copy_vorbis_comments(input, output).map_err(Failure::wrap(TranscodeAction::WriteTags))?;
+    Ok(())
+}
+
+/// Run both passes of a [`PcmPipeline`] and encode the output to a 16-bit FLAC.
+pub(crate) fn write_pipeline_to_flac(
+    pipeline: &PcmPipeline,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    let measurement = pipeline.measure()?;
+    let mut encoder = FlacEncoder::create(output, measurement.channels, measurement.sample_rate)?;
+    let mut result = Ok(());
+    pipeline.quantize(&measurement, |samples| {
+        result = encoder.write(samples);
+        result.is_ok()
+    })?;
+    result?;
+    encoder.finish()?;
+    Ok(())
+}
+
+/// Get the integer factor to decimate `sample_rate` to `resample_rate`.
+///
+/// Returns `None` if `sample_rate` is not a multiple of `resample_rate`.
+#[must_use]
+pub(crate) fn get_decimation_factor(sample_rate: u32, resample_rate: u32) -> Option<u32> {
+    if resample_rate == 0 || !sample_rate.is_multiple_of(resample_rate) {
+        return None;
+    }
+    sample_rate.checked_div(resample_rate)
+}
+
+/// Get a string representation of an in process resample for display.
+#[must_use]
+pub(crate) fn display_native_resample(resample_rate: u32, repeatable: bool) -> String {
+    let mode = if repeatable { ", repeatable" } else { "" };
+    format!("resample (in-process{mode}) {resample_rate} dither")
+}
@@ -0,0 +1,191 @@
+use crate::prelude::*;
+
+/// Bit depth of the output.
+const OUTPUT_BITS_PER_SAMPLE: u32 = 16;
+
+/// Largest peak before quantizing, leaving room for ±1 LSB of [`TpdfDither`] noise so dithered
+/// output is never clamped.
+const MAX_PEAK: f64 = MAX_SAMPLE - 1.0;
+
+/// Number of interleaved samples passed per chunk of quantized output.
+const CHUNK_SAMPLES: usize = 8192;
+
+/// Decode, process and dither a FLAC to 16-bit in process.
+///
+/// Equivalent to `sox [-R] input -G -b 16 output [deemph] [remix] [rate -v -L {rate}] dither`:
+/// - If `deemphasize` each channel is filtered by a [`DeemphasisFilter`]
+/// - If `downmix` each frame is mixed to stereo by a [`Downmixer`]
+/// - If there is a `resample_rate` each channel is filtered and decimated by a linear phase
+///   [`Decimator`]
+/// - Gain is reduced if required to prevent clipping, equivalent to `-G`
+/// - Samples are quantized with [`TpdfDither`] which is seeded with a fixed value if `repeatable`
+///
+/// The FLAC is decoded twice rather than holding the output in memory. [`PcmPipeline::measure`]
+/// finds the peak that determines the gain, then [`PcmPipeline::quantize`] streams the output.
+pub(crate) struct PcmPipeline {
+    /// Path to the input file
+    pub path: PathBuf,
+    /// Samples of the input to process, or `None` for the whole file
+    pub range: Option<SampleRange>,
+    /// Rate to resample to, or `None` to keep the sample rate of the input
+    pub resample_rate: Option<u32>,
+    /// Undo the CD pre-emphasis of the input
+    pub deemphasize: bool,
+    /// Mix a multichannel input to stereo
+    pub downmix: bool,
+    /// Seed the dither with a fixed value so output is deterministic
+    pub repeatable: bool,
+}
+
+/// Format, length and gain of the output of a [`PcmPipeline`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PcmMeasurement {
+    /// Number of interleaved channels.
+    pub channels: u32,
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Number of samples per channel.
+    pub frames: u64,
+    /// Gain applied before quantizing to prevent clipping.
+    pub gain: f64,
+}
+
+impl PcmPipeline {
+    /// Decode and process the FLAC without output to measure the gain.
+    pub(crate) fn measure(&self) -> Result<PcmMeasurement, Failure<TranscodeAction>> {
+        let mut peak = 0.0_f64;
+        let mut samples: u64 = 0;
+        let (channels, sample_rate) = self.process(|sample| {
+            peak = peak.max(sample.abs());
+            samples += 1;
+            true
+        })?;
+        let gain = if peak > MAX_PEAK {
+            trace!(
+                "Reducing gain by {:.2} dB to prevent clipping",
+                20.0 * (peak / MAX_PEAK).log10()
+            );
+            MAX_PEAK / peak
+        } else {
+            1.0
+        };
+        Ok(PcmMeasurement {
+            channels,
+            sample_rate,
+            frames: samples.checked_div(u64::from(channels)).unwrap_or_default(),
+            gain,
+        })
+    }
+
+    /// Decode and process the FLAC again, passing chunks of the quantized interleaved samples
+    /// to `f` until it returns `false`.
+    pub(crate) fn quantize(
+        &self,
+        measurement: &PcmMeasurement,
+        mut f: impl FnMut(&[i16]) -> bool,
+    ) -> Result<(), Failure<TranscodeAction>> {
+        let mut dither = TpdfDither::new(self.repeatable);
+        let mut chunk = Vec::with_capacity(CHUNK_SAMPLES);
+        let mut running = true;
+        self.process(|sample| {
+            chunk.push(dither.quantize(sample * measurement.gain));
+            if chunk.len() == CHUNK_SAMPLES {
+                running = f(&chunk);
+                chunk.clear();
+            }
+            running
+        })?;
+        if running && !chunk.is_empty() {
+            f(&chunk);
+        }
+        Ok(())
+    }
+
+    /// Decode and process the FLAC, passing each interleaved sample scaled so 1.0 is one 16-bit
+    /// LSB to `f` until it returns `false`.
+    ///
+    /// Returns the number of channels and sample rate of the output.
+    fn process(
+        &self,
+        mut f: impl FnMut(f64) -> bool,
+    ) -> Result<(u32, u32), Failure<TranscodeAction>> {
+        let mut decoder = FlacDecoder::open(&self.path)?.with_range(self.range);
+        let info = decoder.stream_info();
+        let sample_rate = self.resample_rate.unwrap_or(info.sample_rate);
+        let factor = get_decimation_factor(info.sample_rate, sample_rate).ok_or_else(|| {
+            Failure::new(
+                TranscodeAction::Resample,
+                TranscodeError::UnsupportedSampleRate(info.sample_rate),
+            )
+            .with_path(&self.path)
+        })?;
+        let shift = i32::try_from(OUTPUT_BITS_PER_SAMPLE).expect("bit depth should fit in i32")
+            - i32::try_from(info.bits_per_sample).expect("bit depth should fit in i32");
+        let scale = 2_f64.powi(shift);
+        let downmixer = if self.downmix {
+            let downmixer = Downmixer::new(info.channels).ok_or_else(|| {
+                Failure::new(
+                    TranscodeAction::Downmix,
+                    TranscodeError::UnsupportedChannels(info.channels),
+                )
+                .with_path(&self.path)
+            })?;
+            Some(downmixer)
+        } else {
+            None
+        };
+        let channels = if downmixer.is_some() {
+            2
+        } else {
+            info.channels
+        };
+        let mut filters: Vec<Option<DeemphasisFilter>> = (0..info.channels)
+            .map(|_| {
+                self.deemphasize
+                    .then(|| DeemphasisFilter::new(info.sample_rate))
+            })
+            .collect();
+        let mut decimators: Vec<Decimator> =
+            (0..channels).map(|_| Decimator::new(factor)).collect();
+        let mut frame = Vec::with_capacity(usize::try_from(info.channels).unwrap_or_default());
+        let mut running = true;
+        decoder.for_each_block(|block, indexes| {
+            for index in indexes {
+                frame.clear();
+                for (channel, filter) in (0..).zip(filters.iter_mut()) {
+                    let sample = f64::from(block.sample(channel, index)) * scale;
+                    frame.push(match filter {
+                        Some(filter) => filter.process(sample),
+                        None => sample,
+                    });
+                }
+                if let Some(downmixer) = &downmixer {
+                    let (left, right) = downmixer.process(&frame);
+                    frame.clear();
+                    frame.extend([left, right]);
+                }
+                for (sample, decimator) in frame.iter().zip(decimators.iter_mut()) {
+                    if let Some(output) = decimator.push(*sample)
+                        && !f(output)
+                    {
+                        running = false;
+                        return false;
+                    }
+                }
+            }
+            true
+        })?;
+        if running {
+            let remaining: Vec<Vec<f64>> = decimators.iter_mut().map(Decimator::finish).collect();
+            let frames = remaining.first().map(Vec::len).unwrap_or_default();
+            'frames: for index in 0..frames {
+                for channel in &remaining {
+                    if !f(channel.get(index).copied().unwrap_or_default()) {
+                        break 'frames;
+                    }
+                }
+            }
+        }
+        Ok((channels, sample_rate))
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+
+/// How to handle CD sources flagged with pre-emphasis.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PreEmphasisPolicy {
+    /// Apply the 50/15 µs de-emphasis filter before encoding MP3 targets.
+    #[default]
+    Deemphasize,
+    /// Report the source as unsuitable for transcoding.
+    Block,
+}
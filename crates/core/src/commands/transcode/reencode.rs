@@ -0,0 +1,170 @@
+use crate::prelude::*;
+use claxon::metadata::StreamInfo;
+use md5::{Digest, Md5};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Total size in bytes of embedded pictures above which a FLAC is re-encoded.
+pub(crate) const MAX_PICTURE_BYTES: u64 = 1024 * 1024;
+
+/// Total size in bytes of padding above which a FLAC is re-encoded.
+pub(crate) const MAX_PADDING_BYTES: u64 = 64 * 1024;
+
+/// Information needed to losslessly re-encode a 16-bit FLAC rather than copy it.
+pub(crate) struct Reencode {
+    /// Path to the input file
+    pub input: PathBuf,
+    /// Path to the output file
+    pub output: PathBuf,
+    /// Why the FLAC is re-encoded
+    pub reason: ReencodeReason,
+}
+
+/// Reason a FLAC target is re-encoded instead of copied.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ReencodeReason {
+    /// Re-encoding every FLAC target was requested with `reencode_flac`.
+    Requested,
+    /// The source has no MD5 signature of its decoded audio.
+    MissingMd5,
+    /// The source was encoded by an outdated release of the reference encoder.
+    OutdatedEncoder,
+    /// The source has embedded pictures larger than [`MAX_PICTURE_BYTES`].
+    Pictures,
+    /// The source has padding larger than [`MAX_PADDING_BYTES`].
+    Padding,
+}
+
+impl Display for ReencodeReason {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let reason = match self {
+            Self::Requested => "requested",
+            Self::MissingMd5 => "missing MD5 signature",
+            Self::OutdatedEncoder => "outdated encoder",
+            Self::Pictures => "large embedded pictures",
+            Self::Padding => "large padding",
+        };
+        formatter.write_str(reason)
+    }
+}
+
+impl ReencodeReason {
+    /// Is the FLAC re-encoded only to reduce the size of its audio?
+    ///
+    /// Other reasons fix the metadata of the FLAC so the re-encode is kept regardless of size.
+    #[must_use]
+    pub(crate) fn is_compression(self) -> bool {
+        matches!(self, Self::Requested | Self::OutdatedEncoder)
+    }
+}
+
+impl Reencode {
+    /// Get a string representation of the re-encode step.
+    ///
+    /// The re-encode is in process so it is described by the decoder, encoder and paths.
+    #[must_use]
+    pub(crate) fn display(&self) -> String {
+        format!(
+            "claxon (in-process) {} | flacenc (in-process) {}",
+            self.input.to_string_lossy(),
+            self.output.to_string_lossy()
+        )
+    }
+}
+
+/// Get the reason a 16-bit FLAC that needs no other conversion should be re-encoded.
+///
+/// Returns `None` if the FLAC can be copied as is.
+pub(crate) fn get_reencode_reason(
+    path: &Path,
+    info: &StreamInfo,
+    requested: bool,
+) -> Result<Option<ReencodeReason>, Failure<TranscodeAction>> {
+    if requested {
+        return Ok(Some(ReencodeReason::Requested));
+    }
+    if info.md5sum == [0_u8; 16] {
+        return Ok(Some(ReencodeReason::MissingMd5));
+    }
+    let metadata = FlacMetadata::read(path)
+        .map_err(Failure::wrap_with_path(TranscodeAction::ReadFlac, path))?;
+    let reason = if metadata.is_outdated_encoder() {
+        Some(ReencodeReason::OutdatedEncoder)
+    } else if metadata.picture_bytes > MAX_PICTURE_BYTES {
+        Some(ReencodeReason::Pictures)
+    } else if metadata.padding_bytes > MAX_PADDING_BYTES {
+        Some(ReencodeReason::Padding)
+    } else {
+        None
+    };
+    Ok(reason)
+}
+
+/// Decode a 16-bit FLAC and losslessly re-encode it in process.
+///
+/// - Decoded and encoded a block at a time so the audio is never held in memory
+/// - Encoded with [`FlacEncoder::with_best_compression`]
+/// - The MD5 of the decoded audio must match the signature of the input, if it has one
+/// - The output is decoded again and must match the decoded input
+/// - Vorbis comments are copied from the input, matching [`resample_flac_to_flac`]
+/// - Pictures, padding and any other metadata blocks are not copied
+pub(crate) fn reencode_flac(input: &Path, output: &Path) -> Result<(), Failure<TranscodeAction>> {
+    let mut decoder = FlacDecoder::open(input)?;
+    let info = decoder.stream_info();
+    let mut encoder =
+        FlacEncoder::create(output, info.channels, info.sample_rate)?.with_best_compression();
+    let mut md5 = Md5::new();
+    let mut result = Ok(());
+    decoder.for_each_chunk(|samples| {
+        update_md5(&mut md5, samples);
+        result = encoder.write(samples);
+        result.is_ok()
+    })?;
+    result?;
+    encoder.finish()?;
+    let digest: [u8; 16] = md5.finalize().into();
+    if info.md5sum != [0_u8; 16] && info.md5sum != digest {
+        return Err(
+            Failure::new(TranscodeAction::Reencode, TranscodeError::Md5Mismatch).with_path(input),
+        );
+    }
+    if get_md5(output)? != digest {
+        return Err(Failure::new(
+            TranscodeAction::Reencode,
+            TranscodeError::InvalidOutput(OutputMismatch::Audio),
+        )
+        .with_path(output));
+    }
+    copy_vorbis_comments(input, output).map_err(Failure::wrap(TranscodeAction::WriteTags))?;
+    Ok(())
+}
+
+/// Check if the audio frames of a re-encoded `output` are smaller than those of `input`.
+///
+/// Metadata blocks are excluded so pictures and padding don't affect the comparison.
+pub(crate) fn is_reencode_smaller(
+    input: &Path,
+    output: &Path,
+) -> Result<bool, Failure<TranscodeAction>> {
+    let input = FlacMetadata::read(input)
+        .map_err(Failure::wrap_with_path(TranscodeAction::ReadFlac, input))?;
+    let output = FlacMetadata::read(output)
+        .map_err(Failure::wrap_with_path(TranscodeAction::ReadFlac, output))?;
+    Ok(output.audio_bytes < input.audio_bytes)
+}
+
+/// Decode a 16-bit FLAC and get the MD5 of its audio as it's calculated for the FLAC
+/// `STREAMINFO` signature.
+fn get_md5(path: &Path) -> Result<[u8; 16], Failure<TranscodeAction>> {
+    let mut md5 = Md5::new();
+    FlacDecoder::open(path)?.for_each_chunk(|samples| {
+        update_md5(&mut md5, samples);
+        true
+    })?;
+    Ok(md5.finalize().into())
+}
+
+fn update_md5(md5: &mut Md5, samples: &[i16]) {
+    for sample in samples {
+        md5.update(sample.to_le_bytes());
+    }
+}
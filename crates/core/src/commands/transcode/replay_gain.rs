@@ -0,0 +1,167 @@
+use crate::prelude::*;
+use ebur128::{EbuR128, Error as EbuR128Error, Mode};
+use futures::stream;
+use futures::{StreamExt, TryStreamExt};
+
+/// Reference loudness of `ReplayGain` 2.0 in LUFS.
+const REFERENCE_LOUDNESS: f64 = -18.0;
+
+/// Vorbis comment name of the track gain.
+const TRACK_GAIN: &str = "REPLAYGAIN_TRACK_GAIN";
+
+/// Vorbis comment name of the track peak.
+const TRACK_PEAK: &str = "REPLAYGAIN_TRACK_PEAK";
+
+/// Vorbis comment name of the album gain.
+const ALBUM_GAIN: &str = "REPLAYGAIN_ALBUM_GAIN";
+
+/// Vorbis comment name of the album peak.
+const ALBUM_PEAK: &str = "REPLAYGAIN_ALBUM_PEAK";
+
+/// `ReplayGain` 2.0 track and album gain and peak of a single track.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ReplayGain {
+    /// Gain in dB to bring the track to the reference loudness.
+    pub track_gain: f64,
+    /// Sample peak of the track relative to full scale.
+    pub track_peak: f64,
+    /// Gain in dB to bring the album to the reference loudness.
+    pub album_gain: f64,
+    /// Sample peak of the album relative to full scale.
+    pub album_peak: f64,
+}
+
+impl ReplayGain {
+    /// Get the `REPLAYGAIN_*` Vorbis comments, skipping any named in `exclude`.
+    ///
+    /// Names are matched case-insensitively as Vorbis comment names are case-insensitive.
+    #[must_use]
+    pub(crate) fn to_comments(&self, exclude: &[String]) -> Vec<(&'static str, String)> {
+        [
+            (TRACK_GAIN, format_gain(self.track_gain)),
+            (TRACK_PEAK, format_peak(self.track_peak)),
+            (ALBUM_GAIN, format_gain(self.album_gain)),
+            (ALBUM_PEAK, format_peak(self.album_peak)),
+        ]
+        .into_iter()
+        .filter(|(name, _)| !exclude.iter().any(|e| e.eq_ignore_ascii_case(name)))
+        .collect()
+    }
+}
+
+/// Analyze the `ReplayGain` of every FLAC in an album.
+///
+/// - Loudness is measured from the decoded source PCM per EBU R128
+/// - Album loudness is gated across all tracks rather than averaged
+/// - Runs up to `cpus` decodes at once on the blocking pool
+///
+/// Returns the [`ReplayGain`] of each FLAC keyed by [`FlacFile::get_track_path`].
+pub(crate) async fn analyze_replay_gain(
+    flacs: &[FlacFile],
+    cpus: usize,
+) -> Result<BTreeMap<PathBuf, ReplayGain>, Failure<TranscodeAction>> {
+    let tracks: Vec<(PathBuf, EbuR128)> = stream::iter(flacs)
+        .map(|flac| {
+            let path = flac.path.clone();
+            let range = flac.get_range();
+            let key = flac.get_track_path();
+            async move {
+                spawn_blocking(move || measure_track(&path, range).map(|meter| (key, meter)))
+                    .await
+                    .expect("replay gain task should not panic")
+            }
+        })
+        .buffered(cpus)
+        .try_collect()
+        .await?;
+    let album_loudness = EbuR128::loudness_global_multiple(tracks.iter().map(|(_, m)| m))
+        .map_err(Failure::wrap(TranscodeAction::AnalyzeReplayGain))?;
+    let mut track_peaks = Vec::with_capacity(tracks.len());
+    for (path, meter) in &tracks {
+        track_peaks.push(get_sample_peak(meter).map_err(Failure::wrap_with_path(
+            TranscodeAction::AnalyzeReplayGain,
+            path,
+        ))?);
+    }
+    let album_peak = track_peaks.iter().copied().fold(0.0, f64::max);
+    let mut gains = BTreeMap::new();
+    for ((path, meter), track_peak) in tracks.into_iter().zip(track_peaks) {
+        let track_loudness = meter.loudness_global().map_err(Failure::wrap_with_path(
+            TranscodeAction::AnalyzeReplayGain,
+            &path,
+        ))?;
+        let gain = ReplayGain {
+            track_gain: get_gain(track_loudness),
+            track_peak,
+            album_gain: get_gain(album_loudness),
+            album_peak,
+        };
+        trace!(
+            "{} track gain {} album gain {} for {}",
+            "Analyzed".bold(),
+            format_gain(gain.track_gain),
+            format_gain(gain.album_gain),
+            path.display()
+        );
+        gains.insert(path, gain);
+    }
+    Ok(gains)
+}
+
+/// Decode a FLAC and measure its integrated loudness and sample peak.
+fn measure_track(
+    path: &Path,
+    range: Option<SampleRange>,
+) -> Result<EbuR128, Failure<TranscodeAction>> {
+    let mut decoder = FlacDecoder::open(path)?.with_range(range);
+    let info = decoder.stream_info();
+    let mut meter =
+        EbuR128::new(info.channels, info.sample_rate, Mode::I | Mode::SAMPLE_PEAK).map_err(
+            Failure::wrap_with_path(TranscodeAction::AnalyzeReplayGain, path),
+        )?;
+    let shift = i32::try_from(info.bits_per_sample).expect("bit depth should fit in i32") - 1;
+    let scale = 2_f64.powi(-shift);
+    let mut result = Ok(());
+    let mut samples: Vec<f64> = Vec::new();
+    decoder.for_each_block(|block, indexes| {
+        samples.clear();
+        for index in indexes {
+            for channel in 0..info.channels {
+                samples.push(f64::from(block.sample(channel, index)) * scale);
+            }
+        }
+        result = meter.add_frames_f64(&samples);
+        result.is_ok()
+    })?;
+    result.map_err(Failure::wrap_with_path(
+        TranscodeAction::AnalyzeReplayGain,
+        path,
+    ))?;
+    Ok(meter)
+}
+
+/// Get the highest sample peak across all channels.
+fn get_sample_peak(meter: &EbuR128) -> Result<f64, EbuR128Error> {
+    (0..meter.channels()).try_fold(0.0, |peak: f64, channel| {
+        Ok(peak.max(meter.sample_peak(channel)?))
+    })
+}
+
+/// Get the gain in dB to bring `loudness` to the [`REFERENCE_LOUDNESS`].
+fn get_gain(loudness: f64) -> f64 {
+    if loudness.is_finite() {
+        REFERENCE_LOUDNESS - loudness
+    } else {
+        0.0
+    }
+}
+
+/// Format a gain as a `ReplayGain` tag value, e.g. `-6.52 dB`.
+fn format_gain(gain: f64) -> String {
+    format!("{gain:.2} dB")
+}
+
+/// Format a peak as a `ReplayGain` tag value, e.g. `0.988831`.
+fn format_peak(peak: f64) -> String {
+    format!("{peak:.6}")
+}
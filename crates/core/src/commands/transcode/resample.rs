@@ -4,21 +4,40 @@ use crate::prelude::*;
 pub(crate) struct Resample {
     /// Path to the input file
     pub input: PathBuf,
+    /// Samples of the input to resample, or `None` for the whole file
+    ///
+    /// A range is only resampled in process so the backend must be [`ResampleBackend::Native`]
+    pub range: Option<SampleRange>,
     /// Path to the output file
     pub output: PathBuf,
     /// Resample rate
     pub resample_rate: u32,
     /// Use repeatable mode for `SoX` (deterministic dithering)
     pub repeatable: bool,
+    /// Backend to resample with
+    pub backend: ResampleBackend,
     /// Factory for creating sox commands
     pub sox: Ref<SoxFactory>,
 }
 
 impl Resample {
+    /// Is the FLAC resampled in process rather than by `SoX`?
+    ///
+    /// True if the backend is [`ResampleBackend::Native`].
+    #[must_use]
+    pub(crate) fn is_in_process(&self) -> bool {
+        self.backend == ResampleBackend::Native
+    }
+
     /// Create a new resample command.
+    ///
+    /// Returns `None` if the FLAC is resampled in process with [`resample_flac_to_flac`].
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_info(self) -> CommandInfo {
+    pub(crate) fn to_info(self) -> Option<CommandInfo> {
+        if self.is_in_process() {
+            return None;
+        }
         let mut info = self.sox.create();
         if self.repeatable {
             info.args.push("-R".to_owned());
@@ -35,6 +54,25 @@ impl Resample {
             self.resample_rate.to_string(),
             "dither".to_owned(),
         ]);
-        info
+        Some(info)
+    }
+
+    /// Get a string representation of the resample step.
+    ///
+    /// In process steps have no CLI command so are described by the decoder, encoder and paths.
+    #[must_use]
+    pub(crate) fn display(self) -> String {
+        if self.is_in_process() {
+            return format!(
+                "claxon (in-process) {}{} | {} | flacenc (in-process) {}",
+                self.input.to_string_lossy(),
+                display_range(self.range),
+                display_native_resample(self.resample_rate, self.repeatable),
+                self.output.to_string_lossy()
+            );
+        }
+        self.to_info()
+            .expect("resample should have a command")
+            .display()
     }
 }
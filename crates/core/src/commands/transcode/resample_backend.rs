@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+
+/// Backend used to resample and dither high-resolution sources.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResampleBackend {
+    /// Resample with the `SoX` binary.
+    #[default]
+    Sox,
+    /// Resample in process without `SoX`.
+    Native,
+}
@@ -4,7 +4,7 @@ use fast_image_resize::{FilterType, IntoImageView, ResizeAlg, ResizeOptions, Res
 use image::codecs::jpeg::JpegEncoder;
 use image::codecs::png::PngEncoder;
 use image::{ColorType, DynamicImage, ExtendedColorType, ImageEncoder, ImageFormat, ImageReader};
-use std::io::BufWriter;
+use std::io::{BufWriter, Cursor, Write};
 
 const RESIZE_ALGORITHM: ResizeAlg = ResizeAlg::Interpolation(FilterType::CatmullRom);
 
@@ -46,6 +46,44 @@ impl Resize {
     }
 }
 
+/// Resize encoded image `data`, preserving aspect ratio and format and only shrinking.
+///
+/// - Returns `None` if the image is within `max_pixel_size` or is not a JPG or PNG
+/// - `path` is the file the image is embedded in, for errors only
+pub(crate) fn resize_image_data(
+    data: &[u8],
+    path: &Path,
+    max_pixel_size: u32,
+    quality: u8,
+) -> Result<Option<Vec<u8>>, Failure<ResizeAction>> {
+    let reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(Failure::wrap_with_path(ResizeAction::Open, path))?;
+    let Some(format @ (ImageFormat::Jpeg | ImageFormat::Png)) = reader.format() else {
+        return Ok(None);
+    };
+    let source = reader
+        .decode()
+        .map_err(Failure::wrap_with_path(ResizeAction::Decode, path))?;
+    let source = normalize_for_format(source, format);
+    let color_type = ExtendedColorType::from(source.color());
+    let Some((image, width, height)) = resize_image(&source, max_pixel_size)? else {
+        return Ok(None);
+    };
+    let encoder = Encoder {
+        path,
+        format,
+        buffer: image.buffer(),
+        width,
+        height,
+        color_type,
+        quality,
+    };
+    let mut output = Vec::new();
+    encoder.encode(&mut output)?;
+    Ok(Some(output))
+}
+
 /// Decode an image from a file path.
 fn decode_image(path: &Path) -> Result<DynamicImage, Failure<ResizeAction>> {
     ImageReader::open(path)
@@ -124,7 +162,11 @@ impl Encoder<'_> {
     fn write(&self) -> Result<(), Failure<ResizeAction>> {
         let file = File::create(self.path)
             .map_err(Failure::wrap_with_path(ResizeAction::Write, self.path))?;
-        let writer = BufWriter::new(file);
+        self.encode(BufWriter::new(file))
+    }
+
+    /// Encode the image to `writer`.
+    fn encode(&self, writer: impl Write) -> Result<(), Failure<ResizeAction>> {
         match self.format {
             ImageFormat::Jpeg => {
                 JpegEncoder::new_with_quality(writer, self.quality)
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+/// Information needed to split a track from a FLAC image that does not need re-sampling.
+pub(crate) struct Split {
+    /// Path to the FLAC image
+    pub input: PathBuf,
+    /// Samples of the image in the track
+    pub range: SampleRange,
+    /// Path to the output file
+    pub output: PathBuf,
+}
+
+impl Split {
+    /// Get a string representation of the split step.
+    ///
+    /// The split is in process so it is described by the decoder, encoder and paths.
+    #[must_use]
+    pub(crate) fn display(&self) -> String {
+        format!(
+            "claxon (in-process) {}{} | flacenc (in-process) {}",
+            self.input.to_string_lossy(),
+            display_range(Some(self.range)),
+            self.output.to_string_lossy()
+        )
+    }
+}
+
+/// Split a track from a FLAC image in process and write it as a 16-bit FLAC.
+///
+/// - Decoded and encoded a block at a time so the track is never held in memory
+/// - Vorbis comments are copied from the image, matching [`resample_flac_to_flac`]
+pub(crate) fn split_flac_to_flac(
+    input: &Path,
+    range: SampleRange,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    let mut decoder = FlacDecoder::open(input)?.with_range(Some(range));
+    let info = decoder.stream_info();
+    let mut encoder = FlacEncoder::create(output, info.channels, info.sample_rate)?;
+    let mut result = Ok(());
+    decoder.for_each_chunk(|samples| {
+        result = encoder.write(samples);
+        result.is_ok()
+    })?;
+    result?;
+    encoder.finish()?;
+    copy_vorbis_comments(input, output).map_err(Failure::wrap(TranscodeAction::WriteTags))?;
+    Ok(())
+}
+
+/// Get a string representation of a [`SampleRange`] for display after the input path.
+///
+/// - The samples are not included so the commands of every track of an image are the same
+/// - Returns an empty string if the whole file is decoded
+#[must_use]
+pub(crate) fn display_range(range: Option<SampleRange>) -> String {
+    if range.is_some() {
+        " (cue sheet track)".to_owned()
+    } else {
+        String::new()
+    }
+}
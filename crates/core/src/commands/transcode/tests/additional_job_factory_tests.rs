@@ -157,6 +157,7 @@ fn small_max_file_options() -> FileOptions {
         no_png_to_jpg: false,
         max_pixel_size: FileOptions::DEFAULT_MAX_PIXEL_SIZE,
         jpg_quality: FileOptions::DEFAULT_JPG_QUALITY,
+        artwork: ArtworkPolicy::Keep,
     }
 }
 
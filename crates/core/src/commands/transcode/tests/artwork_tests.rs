@@ -0,0 +1,255 @@
+use crate::testing_prelude::*;
+use image::codecs::jpeg::JpegEncoder;
+use image::{ExtendedColorType, ImageEncoder, ImageReader, Rgb, RgbImage};
+use lofty::config::ParseOptions;
+use lofty::file::AudioFile;
+use lofty::flac::FlacFile as LoftyFlacFile;
+use lofty::ogg::OggPictureStorage;
+use lofty::picture::{MimeType, Picture, PictureType};
+use std::io::Cursor;
+
+#[test]
+fn resize_image_data_downscales_large_jpg() {
+    // Arrange
+    let data = create_jpg(1000, 500);
+
+    // Act
+    let resized = resize_image_data(&data, Path::new("test.flac"), 200, 80)
+        .expect("should resize")
+        .expect("should be downscaled");
+
+    // Assert
+    let image = ImageReader::new(Cursor::new(resized))
+        .with_guessed_format()
+        .expect("should guess format")
+        .decode()
+        .expect("should decode");
+    assert_eq!((image.width(), image.height()), (200, 100));
+}
+
+#[test]
+fn resize_image_data_skips_small_jpg() {
+    // Arrange
+    let data = create_jpg(100, 50);
+
+    // Act
+    let resized = resize_image_data(&data, Path::new("test.flac"), 200, 80).expect("should resize");
+
+    // Assert
+    assert_eq!(resized, None);
+}
+
+#[test]
+fn artwork_get_pictures_by_policy() {
+    // Arrange
+    let dir = TempDirectory::create("artwork_get_pictures_by_policy");
+    let input = write_flac(&dir);
+    let picture = Picture::unchecked(create_jpg(1000, 500))
+        .pic_type(PictureType::CoverFront)
+        .mime_type(MimeType::Jpeg)
+        .build();
+    replace_flac_pictures(&input, vec![picture.clone()]).expect("should write pictures");
+
+    // Act
+    let keep = artwork(ArtworkPolicy::Keep, &input, None).get_pictures();
+    let strip = artwork(ArtworkPolicy::Strip, &input, None).get_pictures();
+    let resize = artwork(ArtworkPolicy::Resize, &input, None).get_pictures();
+
+    // Assert
+    assert_eq!(keep.expect("should keep"), vec![picture.clone()]);
+    assert_eq!(strip.expect("should strip"), Vec::new());
+    let resize = resize.expect("should resize");
+    assert_eq!(resize.len(), 1);
+    let resized = resize.first().expect("should have one picture");
+    assert_eq!(resized.pic_type(), PictureType::CoverFront);
+    assert!(resized.data().len() < picture.data().len());
+}
+
+#[test]
+fn artwork_get_pictures_embeds_cover() {
+    // Arrange
+    let dir = TempDirectory::create("artwork_get_pictures_embeds_cover");
+    let input = write_flac(&dir);
+    let cover = dir.join("Folder.JPG");
+    write(&cover, create_jpg(100, 100)).expect("should write cover");
+    let flac = FlacFile::new(input.clone(), &dir.to_path_buf());
+
+    // Act
+    let found = find_cover(&flac, &dir);
+    let pictures = artwork(ArtworkPolicy::Embed, &input, found.clone()).get_pictures();
+
+    // Assert
+    assert_eq!(found, Some(cover));
+    let pictures = pictures.expect("should embed");
+    assert_eq!(pictures.len(), 1);
+    let picture = pictures.first().expect("should have one picture");
+    assert_eq!(picture.pic_type(), PictureType::CoverFront);
+    assert_eq!(picture.mime_type(), Some(&MimeType::Jpeg));
+}
+
+#[test]
+fn replace_flac_pictures_strips_pictures() {
+    // Arrange
+    let dir = TempDirectory::create("replace_flac_pictures_strips_pictures");
+    let input = write_flac(&dir);
+    let picture = Picture::unchecked(create_jpg(10, 10))
+        .pic_type(PictureType::CoverFront)
+        .mime_type(MimeType::Jpeg)
+        .build();
+    replace_flac_pictures(&input, vec![picture]).expect("should write pictures");
+
+    // Act
+    replace_flac_pictures(&input, Vec::new()).expect("should strip pictures");
+
+    // Assert
+    let mut file = File::open(&input).expect("should open FLAC");
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default()).expect("should read");
+    assert!(flac.pictures().is_empty());
+}
+
+#[test]
+fn insert_vorbis_comments_into_flac_keeps_pictures() {
+    // Arrange
+    let dir = TempDirectory::create("insert_vorbis_comments_into_flac_keeps_pictures");
+    let input = write_flac(&dir);
+    let picture = Picture::unchecked(create_jpg(10, 10))
+        .pic_type(PictureType::CoverFront)
+        .mime_type(MimeType::Jpeg)
+        .build();
+    replace_flac_pictures(&input, vec![picture.clone()]).expect("should write pictures");
+
+    // Act
+    insert_vorbis_comments_into_flac(
+        &input,
+        vec![("REPLAYGAIN_TRACK_GAIN", "-1.00 dB".to_owned())],
+    )
+    .expect("should insert comments");
+
+    // Assert
+    let mut file = File::open(&input).expect("should open FLAC");
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default()).expect("should read");
+    let pictures: Vec<_> = flac
+        .pictures()
+        .iter()
+        .map(|(picture, _)| picture.clone())
+        .collect();
+    assert_eq!(pictures, vec![picture]);
+    let gain = flac
+        .vorbis_comments()
+        .and_then(|vorbis| vorbis.get("REPLAYGAIN_TRACK_GAIN"));
+    assert_eq!(gain, Some("-1.00 dB"));
+}
+
+#[test]
+fn exclude_vorbis_comments_from_flac_keeps_pictures() {
+    // Arrange
+    let dir = TempDirectory::create("exclude_vorbis_comments_from_flac_keeps_pictures");
+    let input = write_flac(&dir);
+    let picture = Picture::unchecked(create_jpg(10, 10))
+        .pic_type(PictureType::CoverFront)
+        .mime_type(MimeType::Jpeg)
+        .build();
+    insert_vorbis_comments_into_flac(&input, vec![("COMMENT", "Ripped".to_owned())])
+        .expect("should insert comments");
+    replace_flac_pictures(&input, vec![picture.clone()]).expect("should write pictures");
+
+    // Act
+    exclude_vorbis_comments_from_flac(&input, &["COMMENT".to_owned()])
+        .expect("should exclude comments");
+
+    // Assert
+    let mut file = File::open(&input).expect("should open FLAC");
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default()).expect("should read");
+    let pictures: Vec<_> = flac
+        .pictures()
+        .iter()
+        .map(|(picture, _)| picture.clone())
+        .collect();
+    assert_eq!(pictures, vec![picture]);
+    let comment = flac
+        .vorbis_comments()
+        .and_then(|vorbis| vorbis.get("COMMENT"));
+    assert_eq!(comment, None);
+}
+
+/// A FLAC re-encoded to drop large pictures must not have them embedded again by `keep`.
+#[tokio::test]
+async fn transcode_job_keep_drops_large_pictures_of_reencode() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_keep_drops_large_pictures_of_reencode");
+    let input = write_flac(&dir);
+    let size = usize::try_from(MAX_PICTURE_BYTES).expect("should fit in usize") + 1;
+    let picture = Picture::unchecked(vec![0; size])
+        .pic_type(PictureType::CoverFront)
+        .mime_type(MimeType::Jpeg)
+        .build();
+    replace_flac_pictures(&input, vec![picture]).expect("should write pictures");
+    let output_dir = dir.join("output");
+    create_dir(&output_dir).expect("should create output directory");
+    let host = HostBuilder::new()
+        .with_options(SharedOptions {
+            output: output_dir,
+            ..SharedOptions::mock()
+        })
+        .expect_build();
+    let factory = host.services.get_required::<TranscodeJobFactory>();
+    let source = Source {
+        directory: dir.to_path_buf(),
+        ..Source::mock()
+    };
+    let flac = FlacFile::new(input, &dir.to_path_buf());
+
+    // Act
+    let job = factory
+        .create_single(0, &flac, &source, TargetFormat::Flac, None)
+        .expect("should create job");
+    let Job::Transcode(job) = job else {
+        unreachable!("should be a transcode job");
+    };
+    let Variant::Reencode(reencode) = &job.variant else {
+        unreachable!("should be a re-encode");
+    };
+    let reason = reencode.reason;
+    let output = reencode.output.clone();
+    let has_artwork = job.artwork.is_some();
+    let result = job.execute().await;
+
+    // Assert
+    assert_eq!(reason, ReencodeReason::Pictures);
+    assert!(!has_artwork);
+    assert!(result.is_ok(), "re-encode should succeed: {result:?}");
+    let mut file = File::open(&output).expect("should open output");
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default()).expect("should read");
+    assert!(flac.pictures().is_empty());
+}
+
+fn artwork(policy: ArtworkPolicy, input: &Path, cover: Option<PathBuf>) -> Artwork {
+    Artwork {
+        policy,
+        input: input.to_path_buf(),
+        cover,
+        max_pixel_size: 200,
+        quality: FileOptions::DEFAULT_JPG_QUALITY,
+    }
+}
+
+/// Encode a gradient JPG of `width` by `height`.
+fn create_jpg(width: u32, height: u32) -> Vec<u8> {
+    let image = RgbImage::from_fn(width, height, |x, y| {
+        let red = u8::try_from(x % 256).expect("should fit in u8");
+        let green = u8::try_from(y % 256).expect("should fit in u8");
+        Rgb([red, green, 128])
+    });
+    let mut data = Vec::new();
+    JpegEncoder::new_with_quality(&mut data, 90)
+        .write_image(image.as_raw(), width, height, ExtendedColorType::Rgb8)
+        .expect("should encode JPG");
+    data
+}
+
+/// Write a short 16-bit stereo FLAC with a title.
+fn write_flac(dir: &Path) -> PathBuf {
+    PcmFlacGenerator::new()
+        .with_vorbis_tag("TITLE", "Track")
+        .generate(dir)
+}
@@ -0,0 +1,246 @@
+use crate::testing_prelude::*;
+use claxon::FlacReader;
+use std::f64::consts::PI;
+
+#[test]
+fn deemphasize_display_sox_mp3() {
+    // Arrange
+    let deemphasize = get_deemphasize(Path::new("input.flac"), ResampleBackend::Sox);
+    let encode = Encode {
+        output: PathBuf::from("output.mp3"),
+        format: TargetFormat::V0,
+    };
+
+    // Act
+    let output = deemphasize.display(encode);
+
+    // Assert
+    assert_eq!(
+        output,
+        "sox -R input.flac -G -b 16 -t wav - deemph dither | lame -S -V 0 --ignore-tag-errors - output.mp3"
+    );
+}
+
+#[test]
+fn deemphasize_display_sox_flac() {
+    // Arrange
+    let deemphasize = get_deemphasize(Path::new("input.flac"), ResampleBackend::Sox);
+    let encode = Encode {
+        output: PathBuf::from("output.flac"),
+        format: TargetFormat::Flac,
+    };
+
+    // Act
+    let output = deemphasize.display(encode);
+
+    // Assert
+    assert_eq!(
+        output,
+        "sox -R input.flac -G -b 16 output.flac deemph dither"
+    );
+}
+
+#[test]
+fn deemphasize_display_native_flac() {
+    // Arrange
+    let deemphasize = get_deemphasize(Path::new("input.flac"), ResampleBackend::Native);
+    let encode = Encode {
+        output: PathBuf::from("output.flac"),
+        format: TargetFormat::Flac,
+    };
+
+    // Act
+    let output = deemphasize.display(encode);
+
+    // Assert
+    assert_eq!(
+        output,
+        "claxon (in-process) input.flac | deemph (in-process, repeatable) dither | flacenc (in-process) output.flac"
+    );
+}
+
+/// Native de-emphasis to FLAC attenuates treble, keeps bass and copies the tags except the
+/// pre-emphasis flag.
+#[tokio::test]
+async fn deemphasize_native_flac_attenuates_treble() {
+    // Arrange
+    let dir = TempDirectory::create("deemphasize_native_flac_attenuates_treble");
+    let input = PcmFlacGenerator::new()
+        .with_filename("input.flac")
+        .with_samples(get_sines())
+        .with_vorbis_tag("TITLE", "Pre-emphasized")
+        .with_vorbis_tag(PRE_EMPHASIS_TAG, "1")
+        .generate(&dir);
+    let output = dir.join("output/output.flac");
+    let job = TranscodeJob {
+        id: "test".to_owned(),
+        variant: Variant::Deemphasize(
+            get_deemphasize(&input, ResampleBackend::Native),
+            Encode {
+                output: output.clone(),
+                format: TargetFormat::Flac,
+            },
+        ),
+        tags: None,
+        id3_version: Id3Version::V24,
+        exclude_vorbis_comments: Vec::new(),
+        replay_gain: None,
+        comments: Vec::new(),
+        artwork: None,
+    };
+
+    // Act
+    let result = job.execute().await;
+
+    // Assert
+    assert!(result.is_ok(), "de-emphasis should succeed: {result:?}");
+    let (bass, treble) = get_peaks(&output);
+    assert!((bass - 8000.0).abs() < 100.0, "bass peak was {bass}");
+    assert!(
+        treble > 2000.0 && treble < 3000.0,
+        "treble should be cut by about 10 dB: {treble}"
+    );
+    let title = get_vorbis_comment(&output, "TITLE").expect("should read tags");
+    assert_eq!(title.as_deref(), Some("Pre-emphasized"));
+    assert!(!has_pre_emphasis_tag(&output));
+}
+
+/// Only MP3 targets are de-emphasized unless `deemphasize_flac` is set.
+#[test]
+fn transcode_job_factory_deemphasizes_mp3_targets() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_factory_deemphasizes_mp3_targets");
+    let flac = write_pre_emphasized(&dir, 44_100);
+    let default_options = TargetOptions::default();
+    let flac_options = TargetOptions {
+        deemphasize_flac: true,
+        ..TargetOptions::default()
+    };
+
+    // Act
+    let default = get_variants(&dir, &flac, Media::CD, default_options);
+    let deemphasize_flac = get_variants(&dir, &flac, Media::CD, flac_options);
+
+    // Assert
+    assert_eq!(default, vec!["include", "deemphasize"]);
+    assert_eq!(deemphasize_flac, vec!["deemphasize", "deemphasize"]);
+}
+
+/// De-emphasis is skipped for sources that are not 44.1 kHz CD audio.
+#[test]
+fn transcode_job_factory_skips_deemphasis_of_non_cd_audio() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_factory_skips_deemphasis_of_non_cd_audio");
+    let cd = write_pre_emphasized(&dir, 44_100);
+    let dat = write_pre_emphasized(&dir, 48_000);
+    let options = TargetOptions {
+        deemphasize_flac: true,
+        ..TargetOptions::default()
+    };
+
+    // Act
+    let web = get_variants(&dir, &cd, Media::WEB, options.clone());
+    let dat = get_variants(&dir, &dat, Media::CD, options);
+
+    // Assert
+    assert_eq!(web, vec!["include", "transcode"]);
+    assert_eq!(dat, vec!["include", "transcode"]);
+}
+
+fn get_deemphasize(input: &Path, backend: ResampleBackend) -> Deemphasize {
+    Deemphasize {
+        input: input.to_path_buf(),
+        range: None,
+        repeatable: true,
+        backend,
+        sox: Ref::new(SoxFactory::new(Ref::new(SoxOptions {
+            sox_path: None,
+            sox_ng: false,
+        }))),
+    }
+}
+
+/// A second of a 100 Hz sine in the left channel and a 16 kHz sine in the right.
+#[expect(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn get_sines() -> Vec<i16> {
+    (0..44_100_u32)
+        .flat_map(|n| {
+            let t = f64::from(n) / 44_100.0;
+            let bass = 8000.0 * (2.0 * PI * 100.0 * t).sin();
+            let treble = 8000.0 * (2.0 * PI * 16_000.0 * t).sin();
+            [bass.round() as i16, treble.round() as i16]
+        })
+        .collect()
+}
+
+/// Peak of the left and right channels of the second half of a FLAC.
+#[expect(clippy::integer_division, reason = "approximate half")]
+fn get_peaks(path: &Path) -> (f64, f64) {
+    let mut reader = FlacReader::open(path).expect("should open FLAC");
+    let samples: Vec<i32> = reader
+        .samples()
+        .map(|sample| sample.expect("should decode sample"))
+        .collect();
+    let half = samples.len() / 2;
+    let peak = |channel: usize| {
+        samples
+            .iter()
+            .skip(half + channel)
+            .step_by(2)
+            .fold(0.0, |peak: f64, sample| peak.max(f64::from(sample.abs())))
+    };
+    (peak(0), peak(1))
+}
+
+/// Write a 16-bit stereo FLAC flagged with pre-emphasis.
+fn write_pre_emphasized(dir: &Path, sample_rate: u32) -> FlacFile {
+    let mut flac = PcmFlacGenerator::new()
+        .with_filename(format!("{sample_rate}.flac"))
+        .with_sample_rate(sample_rate)
+        .with_vorbis_tag(PRE_EMPHASIS_TAG, "1")
+        .generate_flac_file(dir);
+    flac.pre_emphasis = true;
+    flac
+}
+
+/// Create a job for the FLAC and V0 targets and get the name of each variant.
+fn get_variants(
+    dir: &Path,
+    flac: &FlacFile,
+    media: Media,
+    options: TargetOptions,
+) -> Vec<&'static str> {
+    let host = HostBuilder::new()
+        .with_options(SharedOptions {
+            output: dir.join("output"),
+            ..SharedOptions::mock()
+        })
+        .with_options(options)
+        .expect_build();
+    let factory = host.services.get_required::<TranscodeJobFactory>();
+    let mut source = Source {
+        directory: dir.to_path_buf(),
+        ..Source::mock()
+    };
+    source.metadata.media = media;
+    [TargetFormat::Flac, TargetFormat::V0]
+        .into_iter()
+        .map(|format| {
+            let job = factory
+                .create_single(0, flac, &source, format, None)
+                .expect("should create job");
+            let Job::Transcode(job) = job else {
+                unreachable!("should be a transcode job");
+            };
+            match job.variant {
+                Variant::Transcode(..) => "transcode",
+                Variant::Deemphasize(..) => "deemphasize",
+                Variant::Downmix(..) => "downmix",
+                Variant::Resample(_) => "resample",
+                Variant::Include(_) => "include",
+                Variant::Reencode(_) => "reencode",
+                Variant::Split(_) => "split",
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,151 @@
+use crate::testing_prelude::*;
+use std::f64::consts::PI;
+
+#[test]
+fn pcm_pipeline_downmix_5_1_to_stereo() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_downmix_5_1_to_stereo");
+    let pipeline = get_pipeline(write_surround(&dir));
+
+    // Act
+    let measurement = pipeline.measure().expect("should measure");
+    let mut samples = Vec::new();
+    pipeline
+        .quantize(&measurement, |chunk| {
+            samples.extend_from_slice(chunk);
+            true
+        })
+        .expect("should downmix");
+
+    // Assert
+    assert_eq!(measurement.channels, 2);
+    assert_eq!(measurement.sample_rate, 44_100);
+    assert_eq!(measurement.frames, 10_000);
+    assert_eq!(samples.len(), 20_000);
+    for frame in samples.chunks_exact(2) {
+        let [left, right] = frame else {
+            unreachable!("chunk should have two samples");
+        };
+        assert!((i32::from(*left) - 1000).abs() <= 1, "left was {left}");
+        assert!(right.abs() <= 1, "right was {right}");
+    }
+}
+
+#[test]
+fn pcm_pipeline_downmix_stereo_is_unsupported() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_downmix_stereo_is_unsupported");
+    let path = PcmFlacGenerator::new()
+        .with_filename("stereo.flac")
+        .generate(&dir);
+
+    // Act
+    let result = get_pipeline(path).measure();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn pcm_pipeline_downmix_deemphasize_attenuates_treble() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_downmix_deemphasize_attenuates_treble");
+    let path = write_surround_treble(&dir);
+    let pipeline = get_pipeline(path.clone());
+    let deemphasized = PcmPipeline {
+        deemphasize: true,
+        ..get_pipeline(path)
+    };
+
+    // Act
+    let peak = get_left_peak(&pipeline);
+    let deemphasized_peak = get_left_peak(&deemphasized);
+
+    // Assert
+    assert!((7900..=8100).contains(&peak), "peak was {peak}");
+    assert!(
+        deemphasized_peak > 2000 && deemphasized_peak < 3000,
+        "treble should be cut by about 10 dB: {deemphasized_peak}"
+    );
+}
+
+#[test]
+fn downmix_display_deemphasize() {
+    // Arrange
+    let downmix = Downmix {
+        input: PathBuf::from("input.flac"),
+        range: None,
+        channels: 6,
+        resample_rate: None,
+        deemphasize: true,
+        repeatable: true,
+    };
+
+    // Act
+    let output = downmix.display();
+
+    // Assert
+    assert_eq!(
+        output,
+        "claxon (in-process) input.flac | deemph (in-process) | downmix (in-process, ITU-R BS.775) 6 to 2 channels | dither (in-process, repeatable)"
+    );
+}
+
+fn get_pipeline(path: PathBuf) -> PcmPipeline {
+    PcmPipeline {
+        path,
+        range: None,
+        resample_rate: None,
+        deemphasize: false,
+        downmix: true,
+        repeatable: true,
+    }
+}
+
+/// Write a 5.1 FLAC with a constant front left and an LFE that must be discarded.
+fn write_surround(dir: &Path) -> PathBuf {
+    let samples: Vec<i16> = (0..10_000)
+        .flat_map(|_| [1000, 0, 0, 20_000, 0, 0])
+        .collect();
+    PcmFlacGenerator::new()
+        .with_filename("surround.flac")
+        .with_channels(6)
+        .with_samples(samples)
+        .generate(dir)
+}
+
+/// Write a second of 5.1 FLAC with a 16 kHz sine in the front left.
+#[expect(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn write_surround_treble(dir: &Path) -> PathBuf {
+    let samples: Vec<i16> = (0..44_100_u32)
+        .flat_map(|n| {
+            let t = f64::from(n) / 44_100.0;
+            let treble = (8000.0 * (2.0 * PI * 16_000.0 * t).sin()).round() as i16;
+            [treble, 0, 0, 0, 0, 0]
+        })
+        .collect();
+    PcmFlacGenerator::new()
+        .with_filename("treble.flac")
+        .with_channels(6)
+        .with_samples(samples)
+        .generate(dir)
+}
+
+/// Peak of the left channel of the second half of the output of a [`PcmPipeline`].
+fn get_left_peak(pipeline: &PcmPipeline) -> u16 {
+    let measurement = pipeline.measure().expect("should measure");
+    let mut samples = Vec::new();
+    pipeline
+        .quantize(&measurement, |chunk| {
+            samples.extend_from_slice(chunk);
+            true
+        })
+        .expect("should downmix");
+    samples
+        .chunks_exact(2)
+        .skip(samples.len().div_ceil(4))
+        .filter_map(|frame| frame.first())
+        .map(|sample| sample.unsigned_abs())
+        .max()
+        .unwrap_or_default()
+}
@@ -0,0 +1,36 @@
+use crate::testing_prelude::*;
+use claxon::FlacReader;
+use md5::{Digest, Md5};
+
+#[test]
+fn flac_encoder_create_streams_chunks() {
+    // Arrange
+    let dir = TempDirectory::create("flac_encoder_create_streams_chunks");
+    let path = dir.join("output.flac");
+    let samples: Vec<i16> = (0..10_001_i32)
+        .flat_map(|index| {
+            let sample = i16::try_from(index % 2000 - 1000).expect("sample should fit in i16");
+            [sample, -sample]
+        })
+        .collect();
+    let mut md5 = Md5::new();
+    for sample in &samples {
+        md5.update(sample.to_le_bytes());
+    }
+
+    // Act
+    let mut encoder = FlacEncoder::create(&path, 2, 44_100).expect("should create FLAC");
+    for chunk in samples.chunks(3000) {
+        encoder.write(chunk).expect("should write samples");
+    }
+    encoder.finish().expect("should finish FLAC");
+
+    // Assert
+    let info = FlacReader::open(&path)
+        .expect("should open FLAC")
+        .streaminfo();
+    assert_eq!(info.samples, Some(10_001));
+    assert_eq!(info.md5sum, <[u8; 16]>::from(md5.finalize()));
+    let pcm = decode_flac(&path, None).expect("should decode FLAC");
+    assert_eq!(pcm.samples, samples);
+}
@@ -0,0 +1,155 @@
+use crate::testing_prelude::*;
+use lofty::id3::v2::{FrameId, Id3v2Tag};
+use lofty::prelude::TagExt;
+use std::borrow::Cow;
+
+#[test]
+fn id3_mapping_parse() {
+    // Arrange
+    // Act
+    let text: Id3Mapping = "label = TPUB".parse().expect("should parse");
+    let user: Id3Mapping = "CatalogNumber=TXXX:CATALOG NUMBER"
+        .parse()
+        .expect("should parse");
+
+    // Assert
+    assert_eq!(
+        text,
+        Id3Mapping {
+            key: "LABEL".to_owned(),
+            frame: "TPUB".to_owned(),
+            description: None,
+        }
+    );
+    assert_eq!(user.to_string(), "CATALOGNUMBER=TXXX:CATALOG NUMBER");
+    assert_eq!(user.get_frame_key(), "TXXX:CATALOG NUMBER");
+}
+
+#[test]
+fn id3_mapping_parse_errors() {
+    // Arrange
+    // Act
+    // Assert
+    assert_eq!(
+        "LABEL".parse::<Id3Mapping>(),
+        Err(Id3MappingError::InvalidMapping("LABEL".to_owned()))
+    );
+    assert_eq!(
+        "LABEL=APIC".parse::<Id3Mapping>(),
+        Err(Id3MappingError::InvalidFrame("APIC".to_owned()))
+    );
+    assert_eq!(
+        "LABEL=TXXX".parse::<Id3Mapping>(),
+        Err(Id3MappingError::MissingDescription("LABEL".to_owned()))
+    );
+    assert_eq!(
+        "LABEL=TPUB:PUBLISHER".parse::<Id3Mapping>(),
+        Err(Id3MappingError::UnexpectedDescription("LABEL".to_owned()))
+    );
+}
+
+#[test]
+fn map_id3_tags_applies_mapping() {
+    // Arrange
+    let dir = TempDirectory::create("map_id3_tags_applies_mapping");
+    let flac = write_flac(&dir);
+    let options = Id3Options {
+        id3_mapping: vec![
+            "CATALOGNUMBER=TXXX:CATALOG NUMBER"
+                .parse()
+                .expect("should parse"),
+            "MOOD=TXXX:MOOD".parse().expect("should parse"),
+            "MEDIA=TMED".parse().expect("should parse"),
+        ],
+        ..Id3Options::default()
+    };
+
+    // Act
+    let tags = map_id3_tags(&flac, &options).expect("should map tags");
+
+    // Assert
+    assert_eq!(tags.get_user_text("CATALOG NUMBER"), Some("CAT-1"));
+    assert_eq!(tags.get_user_text("CATALOGNUMBER"), None);
+    assert_eq!(tags.get_user_text("MOOD"), Some("Calm\0Dark"));
+    assert_eq!(tags.get_text(&frame_id("TMOO")), None);
+    assert_eq!(tags.get_text(&frame_id("TMED")), None);
+    assert_eq!(tags.get_text(&frame_id("TPE1")), Some("Artist A\0Artist B"));
+    assert_eq!(tags.get_text(&frame_id("TSOP")), Some("A, Artist"));
+}
+
+#[test]
+fn map_id3_tags_joins_artist_and_genre() {
+    // Arrange
+    let dir = TempDirectory::create("map_id3_tags_joins_artist_and_genre");
+    let flac = write_flac(&dir);
+    let options = Id3Options {
+        id3_artist_separator: Some("; ".to_owned()),
+        id3_genre_separator: Some(", ".to_owned()),
+        ..Id3Options::default()
+    };
+
+    // Act
+    let tags = map_id3_tags(&flac, &options).expect("should map tags");
+
+    // Assert
+    assert_eq!(tags.get_text(&frame_id("TPE1")), Some("Artist A; Artist B"));
+    assert_eq!(tags.get_text(&frame_id("TCON")), Some("Rock, Jazz"));
+}
+
+#[test]
+fn map_id3_tags_id3v23() {
+    // Arrange
+    let dir = TempDirectory::create("map_id3_tags_id3v23");
+    let flac = write_flac(&dir);
+    let options = Id3Options {
+        id3_mapping: vec!["MOOD=TXXX:MOOD".parse().expect("should parse")],
+        id3_genre_separator: Some(", ".to_owned()),
+        id3_version: Id3Version::V23,
+        ..Id3Options::default()
+    };
+
+    // Act
+    let tags = map_id3_tags(&flac, &options).expect("should map tags");
+    let mut bytes = Vec::new();
+    tags.dump_to(&mut bytes, options.id3_version.get_write_options())
+        .expect("should write tags");
+
+    // Assert
+    assert_eq!(tags.get_text(&frame_id("TPE1")), Some("Artist A/Artist B"));
+    assert_eq!(tags.get_text(&frame_id("TCON")), Some("Rock, Jazz"));
+    assert_eq!(tags.get_user_text("MOOD"), Some("Calm/Dark"));
+    assert_eq!(tags.get_text(&frame_id("TSOP")), None);
+    assert_eq!(bytes.get(..4), Some(b"ID3\x03".as_slice()));
+}
+
+#[test]
+fn get_id3v2_frame_key_of_user_text() {
+    // Arrange
+    let mut tags = Id3v2Tag::new();
+    let _ = tags.insert_user_text("CATALOGNUMBER".to_owned(), "CAT-1".to_owned());
+
+    // Act
+    let keys: Vec<String> = (&tags).into_iter().map(get_id3v2_frame_key).collect();
+
+    // Assert
+    assert_eq!(keys, vec!["TXXX:CATALOGNUMBER".to_owned()]);
+}
+
+fn frame_id(id: &'static str) -> FrameId<'static> {
+    FrameId::Valid(Cow::Borrowed(id))
+}
+
+/// Write a short FLAC with multiple artists, genres and moods.
+fn write_flac(dir: &TempDirectory) -> FlacFile {
+    PcmFlacGenerator::new()
+        .with_vorbis_tag("TITLE", "Track")
+        .with_vorbis_tag("ARTIST", "Artist A")
+        .with_vorbis_tag("ARTIST", "Artist B")
+        .with_vorbis_tag("ARTISTSORT", "A, Artist")
+        .with_vorbis_tag("GENRE", "Rock")
+        .with_vorbis_tag("GENRE", "Jazz")
+        .with_vorbis_tag("MOOD", "Calm")
+        .with_vorbis_tag("MOOD", "Dark")
+        .with_vorbis_tag("CATALOGNUMBER", "CAT-1")
+        .generate_flac_file(dir)
+}
@@ -1,5 +1,16 @@
 mod additional_job_factory_tests;
 mod additional_job_tests;
+mod artwork_tests;
+mod deemphasize_tests;
+mod downmix_tests;
+mod flac_encoder_tests;
+mod id3_mapping_tests;
+mod pcm_pipeline_tests;
+mod reencode_tests;
 mod rename_tracks_tests;
+mod replay_gain_tests;
+mod split_tests;
 mod torrent_file_tests;
 mod transcode_command_tests;
+mod transcode_validator_tests;
+mod wav_decoder_tests;
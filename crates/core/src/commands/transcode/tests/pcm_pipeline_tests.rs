@@ -0,0 +1,165 @@
+use crate::testing_prelude::*;
+use std::f64::consts::PI;
+
+#[test]
+fn pcm_pipeline_quantize_matches_measurement() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_quantize_matches_measurement");
+    let path = write_source(&dir, 88_200, sine(88_200, 10_001));
+    let pipeline = PcmPipeline {
+        path,
+        range: None,
+        resample_rate: Some(44_100),
+        deemphasize: false,
+        downmix: false,
+        repeatable: true,
+    };
+
+    // Act
+    let measurement = pipeline.measure().expect("should measure");
+    let mut samples = Vec::new();
+    pipeline
+        .quantize(&measurement, |chunk| {
+            samples.extend_from_slice(chunk);
+            true
+        })
+        .expect("should quantize");
+
+    // Assert
+    assert_eq!(measurement.channels, 2);
+    assert_eq!(measurement.sample_rate, 44_100);
+    assert_eq!(measurement.frames, 5001);
+    assert_eq!(
+        u64::try_from(samples.len()).expect("length should fit in u64"),
+        measurement.frames * 2
+    );
+}
+
+#[test]
+fn pcm_pipeline_quantize_stops_early() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_quantize_stops_early");
+    let path = write_source(&dir, 88_200, sine(88_200, 20_000));
+    let pipeline = PcmPipeline {
+        path,
+        range: None,
+        resample_rate: Some(44_100),
+        deemphasize: false,
+        downmix: false,
+        repeatable: true,
+    };
+    let measurement = pipeline.measure().expect("should measure");
+
+    // Act
+    let mut chunks = 0;
+    pipeline
+        .quantize(&measurement, |_| {
+            chunks += 1;
+            false
+        })
+        .expect("should quantize");
+
+    // Assert
+    assert_eq!(chunks, 1);
+}
+
+#[test]
+fn pcm_pipeline_deemphasize_full_scale_is_not_clipped() {
+    // Arrange
+    let dir = TempDirectory::create("pcm_pipeline_deemphasize_full_scale_is_not_clipped");
+    let samples = (0..44_100_u32)
+        .flat_map(|index| {
+            let sample = if index % 4 < 2 { i16::MAX } else { -i16::MAX };
+            [sample, sample]
+        })
+        .collect();
+    let path = write_source(&dir, 44_100, samples);
+    let pipeline = PcmPipeline {
+        path,
+        range: None,
+        resample_rate: None,
+        deemphasize: true,
+        downmix: false,
+        repeatable: true,
+    };
+
+    // Act
+    let measurement = pipeline.measure().expect("should measure");
+    let mut peak = 0;
+    pipeline
+        .quantize(&measurement, |chunk| {
+            peak = chunk
+                .iter()
+                .map(|sample| sample.unsigned_abs())
+                .fold(peak, u16::max);
+            true
+        })
+        .expect("should quantize");
+
+    // Assert
+    assert_eq!(measurement.sample_rate, 44_100);
+    assert_eq!(measurement.frames, 44_100);
+    assert!(measurement.gain <= 1.0, "gain was {}", measurement.gain);
+    assert!(peak < i16::MAX.unsigned_abs(), "output was clipped");
+}
+
+#[test]
+fn resample_flac_to_flac_full_scale_square_reduces_gain() {
+    // Arrange
+    let dir = TempDirectory::create("resample_flac_to_flac_full_scale_square_reduces_gain");
+    let samples = (0..88_200_u32)
+        .flat_map(|index| {
+            let sample = if index % 40 < 20 { i16::MAX } else { -i16::MAX };
+            [sample, sample]
+        })
+        .collect();
+    let input = write_source(&dir, 88_200, samples);
+    let output = dir.join("output.flac");
+    let pipeline = PcmPipeline {
+        path: input.clone(),
+        range: None,
+        resample_rate: Some(44_100),
+        deemphasize: false,
+        downmix: false,
+        repeatable: true,
+    };
+
+    // Act
+    let measurement = pipeline.measure().expect("should measure");
+    resample_flac_to_flac(&input, None, &output, 44_100, true).expect("should resample");
+
+    // Assert
+    let pcm = decode_flac(&output, None).expect("should decode output");
+    let peak = pcm
+        .samples
+        .iter()
+        .map(|sample| sample.unsigned_abs())
+        .max()
+        .unwrap_or_default();
+    assert_eq!(pcm.channels, 2);
+    assert_eq!(pcm.sample_rate, 44_100);
+    assert_eq!(pcm.samples.len(), 88_200);
+    assert!(measurement.gain < 1.0, "gain was {}", measurement.gain);
+    assert!(peak > 32_000, "peak was {peak}");
+}
+
+/// Interleaved samples of a 16-bit stereo 1 kHz sine at half scale.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn sine(sample_rate: u32, frames: u32) -> Vec<i16> {
+    (0..frames)
+        .flat_map(|index| {
+            let phase = 2.0 * PI * 1000.0 * f64::from(index) / f64::from(sample_rate);
+            let sample = (phase.sin() * 16_000.0) as i16;
+            [sample, sample]
+        })
+        .collect()
+}
+
+/// Write interleaved 16-bit stereo samples to a FLAC.
+fn write_source(dir: &TempDirectory, sample_rate: u32, samples: Vec<i16>) -> PathBuf {
+    PcmFlacGenerator::new()
+        .with_filename("source.flac")
+        .with_sample_rate(sample_rate)
+        .with_samples(samples)
+        .generate(dir)
+}
@@ -0,0 +1,154 @@
+use crate::testing_prelude::*;
+use claxon::FlacReader;
+
+/// Offset of the MD5 signature in a FLAC with `STREAMINFO` as the first block.
+const MD5_OFFSET: usize = 26;
+
+#[test]
+fn reencode_flac_is_lossless() {
+    // Arrange
+    let dir = TempDirectory::create("reencode_flac_is_lossless");
+    let (input, samples) = write_source(&dir);
+    insert_vorbis_comments_into_flac(&input, vec![("TITLE", "Track".to_owned())])
+        .expect("should write tags");
+    let output = dir.join("output.flac");
+
+    // Act
+    let result = reencode_flac(&input, &output);
+
+    // Assert
+    assert!(result.is_ok(), "re-encode should succeed: {result:?}");
+    let pcm = decode_flac(&output, None).expect("should decode output");
+    assert_eq!(pcm.samples, samples);
+    let reader = FlacReader::open(&output).expect("should open output");
+    assert_eq!(reader.get_tag("TITLE").next(), Some("Track"));
+}
+
+#[test]
+fn reencode_flac_rejects_md5_mismatch() {
+    // Arrange
+    let dir = TempDirectory::create("reencode_flac_rejects_md5_mismatch");
+    let (input, _) = write_source(&dir);
+    set_md5(&input, [1; 16]);
+    let output = dir.join("output.flac");
+
+    // Act
+    let result = reencode_flac(&input, &output);
+
+    // Assert
+    let error = result.expect_err("should reject mismatched MD5");
+    assert_eq!(error.action(), &TranscodeAction::Reencode);
+}
+
+#[test]
+fn get_reencode_reason_missing_md5() {
+    // Arrange
+    let dir = TempDirectory::create("get_reencode_reason_missing_md5");
+    let (input, _) = write_source(&dir);
+    set_md5(&input, [0; 16]);
+    let info = FlacReader::open(&input)
+        .expect("should open FLAC")
+        .streaminfo();
+
+    // Act
+    let reason = get_reencode_reason(&input, &info, false).expect("should get reason");
+
+    // Assert
+    assert_eq!(reason, Some(ReencodeReason::MissingMd5));
+}
+
+#[test]
+fn get_reencode_reason_none() {
+    // Arrange
+    let dir = TempDirectory::create("get_reencode_reason_none");
+    let (input, _) = write_source(&dir);
+    let info = FlacReader::open(&input)
+        .expect("should open FLAC")
+        .streaminfo();
+
+    // Act
+    let reason = get_reencode_reason(&input, &info, false).expect("should get reason");
+    let requested = get_reencode_reason(&input, &info, true).expect("should get reason");
+
+    // Assert
+    assert_eq!(reason, None);
+    assert_eq!(requested, Some(ReencodeReason::Requested));
+}
+
+/// Re-encodes only for compression are replaced with a copy of the input if not smaller.
+#[tokio::test]
+async fn transcode_job_reencode_copies_if_not_smaller() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_reencode_copies_if_not_smaller");
+    let input = PcmFlacGenerator::new()
+        .with_filename("silence.flac")
+        .generate(&dir);
+    let missing_md5 = PcmFlacGenerator::new()
+        .with_filename("missing_md5.flac")
+        .generate(&dir);
+    set_md5(&missing_md5, [0; 16]);
+    let requested = dir.join("requested.flac");
+    let fixed = dir.join("fixed.flac");
+
+    // Act
+    let requested_result = get_job(&input, &requested, ReencodeReason::Requested)
+        .execute()
+        .await;
+    let fixed_result = get_job(&missing_md5, &fixed, ReencodeReason::MissingMd5)
+        .execute()
+        .await;
+
+    // Assert
+    assert!(requested_result.is_ok(), "{requested_result:?}");
+    assert!(fixed_result.is_ok(), "{fixed_result:?}");
+    assert_eq!(
+        read(&requested).expect("should read output"),
+        read(&input).expect("should read input")
+    );
+    let info = FlacReader::open(&fixed)
+        .expect("should open output")
+        .streaminfo();
+    assert_ne!(info.md5sum, [0; 16], "re-encode should be kept");
+}
+
+fn get_job(input: &Path, output: &Path, reason: ReencodeReason) -> TranscodeJob {
+    TranscodeJob {
+        id: "test".to_owned(),
+        variant: Variant::Reencode(Reencode {
+            input: input.to_path_buf(),
+            output: output.to_path_buf(),
+            reason,
+        }),
+        tags: None,
+        id3_version: Id3Version::V24,
+        exclude_vorbis_comments: Vec::new(),
+        replay_gain: None,
+        comments: Vec::new(),
+        artwork: None,
+    }
+}
+
+/// Write a 16-bit stereo FLAC and return its path and samples.
+fn write_source(dir: &Path) -> (PathBuf, Vec<i16>) {
+    let samples: Vec<i16> = (0..10_000_i32)
+        .flat_map(|index| {
+            let sample = i16::try_from(index % 20_000 - 10_000).expect("sample should fit in i16");
+            [sample, -sample]
+        })
+        .collect();
+    let path = PcmFlacGenerator::new()
+        .with_filename("source.flac")
+        .with_samples(samples.clone())
+        .generate(dir);
+    (path, samples)
+}
+
+/// Overwrite the MD5 signature in the `STREAMINFO` block of a FLAC.
+fn set_md5(path: &Path, md5: [u8; 16]) {
+    let mut bytes = read(path).expect("should read FLAC");
+    bytes
+        .get_mut(MD5_OFFSET..MD5_OFFSET + 16)
+        .expect("FLAC should have STREAMINFO")
+        .copy_from_slice(&md5);
+    write(path, bytes).expect("should write FLAC");
+}
@@ -94,6 +94,7 @@ async fn rename_tracks_helper(config: AlbumConfig) -> Vec<FileSnapshot> {
             max_file_size: FileOptions::DEFAULT_MAX_FILE_SIZE,
             max_pixel_size: FileOptions::DEFAULT_MAX_PIXEL_SIZE,
             jpg_quality: FileOptions::DEFAULT_JPG_QUALITY,
+            artwork: ArtworkPolicy::Keep,
         })
         .expect_build();
 
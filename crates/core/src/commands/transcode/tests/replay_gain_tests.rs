@@ -0,0 +1,81 @@
+use crate::testing_prelude::*;
+use std::f64::consts::TAU;
+
+/// Sample rate of the generated test tones.
+const SAMPLE_RATE: u32 = 44100;
+
+/// Frequency in Hz of the EBU R128 reference tone.
+const FREQUENCY: f64 = 997.0;
+
+/// Write a three second stereo sine tone with a peak of `amplitude` to a FLAC.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn write_tone(dir: &Path, name: &str, amplitude: f64) -> FlacFile {
+    let frames = SAMPLE_RATE * 3;
+    let samples: Vec<i16> = (0..frames)
+        .flat_map(|index| {
+            let phase = TAU * FREQUENCY * f64::from(index) / f64::from(SAMPLE_RATE);
+            let sample = (phase.sin() * amplitude * 32767.0).round() as i16;
+            [sample, sample]
+        })
+        .collect();
+    PcmFlacGenerator::new()
+        .with_filename(name)
+        .with_sample_rate(SAMPLE_RATE)
+        .with_samples(samples)
+        .generate_flac_file(dir)
+}
+
+#[tokio::test]
+async fn analyze_replay_gain_track_and_album() {
+    // Arrange
+    let dir = TempDirectory::create("analyze_replay_gain_track_and_album");
+    let flacs = [
+        write_tone(&dir, "loud.flac", 0.1),
+        write_tone(&dir, "quiet.flac", 0.05),
+    ];
+
+    // Act
+    let gains = analyze_replay_gain(&flacs, 1)
+        .await
+        .expect("should analyze");
+
+    // Assert
+    let loud = gains
+        .get(&dir.join("loud.flac"))
+        .expect("should have loud gain");
+    let quiet = gains
+        .get(&dir.join("quiet.flac"))
+        .expect("should have quiet gain");
+    // A stereo 997 Hz sine with a peak of -20 dBFS measures -20 LUFS
+    assert!((loud.track_gain - 2.0).abs() < 0.1, "{loud:?}");
+    assert!((quiet.track_gain - 8.02).abs() < 0.1, "{quiet:?}");
+    assert!((loud.track_peak - 0.1).abs() < 0.001, "{loud:?}");
+    assert!((loud.album_gain - quiet.album_gain).abs() < f64::EPSILON);
+    assert!((loud.album_peak - loud.track_peak).abs() < f64::EPSILON);
+    assert!(loud.album_gain > loud.track_gain && loud.album_gain < quiet.track_gain);
+}
+
+#[test]
+fn replay_gain_to_comments_excludes_names() {
+    // Arrange
+    let gain = ReplayGain {
+        track_gain: -6.521,
+        track_peak: 0.988_831_2,
+        album_gain: 1.5,
+        album_peak: 1.0,
+    };
+    let exclude = vec!["replaygain_album_peak".to_owned()];
+
+    // Act
+    let comments = gain.to_comments(&exclude);
+
+    // Assert
+    assert_eq!(
+        comments,
+        vec![
+            ("REPLAYGAIN_TRACK_GAIN", "-6.52 dB".to_owned()),
+            ("REPLAYGAIN_TRACK_PEAK", "0.988831".to_owned()),
+            ("REPLAYGAIN_ALBUM_GAIN", "1.50 dB".to_owned()),
+        ]
+    );
+}
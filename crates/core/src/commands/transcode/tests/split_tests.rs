@@ -0,0 +1,157 @@
+use crate::testing_prelude::*;
+use claxon::FlacReader;
+
+#[test]
+fn decode_flac_range_is_sample_accurate() {
+    // Arrange
+    let dir = TempDirectory::create("decode_flac_range_is_sample_accurate");
+    let (path, samples) = write_image(&dir);
+    let range = SampleRange {
+        start: 5000,
+        end: Some(12_000),
+    };
+
+    // Act
+    let pcm = decode_flac(&path, Some(range)).expect("should decode");
+
+    // Assert
+    assert_eq!(pcm.samples.len(), 14_000);
+    assert_eq!(Some(pcm.samples.as_slice()), samples.get(10_000..24_000));
+}
+
+#[tokio::test]
+async fn transcode_job_split_writes_track() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_split_writes_track");
+    let (input, samples) = write_image(&dir);
+    insert_vorbis_comments_into_flac(
+        &input,
+        vec![
+            ("TITLE", "Album".to_owned()),
+            ("CUESHEET", "FILE \"Album.wav\" WAVE".to_owned()),
+        ],
+    )
+    .expect("should write tags");
+    let output = dir.join("output/02 Second.flac");
+    let range = SampleRange {
+        start: 12_000,
+        end: None,
+    };
+    let job = TranscodeJob {
+        id: "test".to_owned(),
+        variant: Variant::Split(Split {
+            input,
+            range,
+            output: output.clone(),
+        }),
+        tags: None,
+        id3_version: Id3Version::V24,
+        exclude_vorbis_comments: vec![CUESHEET_TAG.to_owned()],
+        replay_gain: None,
+        comments: vec![
+            ("TRACKNUMBER", "2".to_owned()),
+            ("TITLE", "Second".to_owned()),
+        ],
+        artwork: None,
+    };
+
+    // Act
+    let result = job.execute().await;
+
+    // Assert
+    assert!(result.is_ok(), "split should succeed: {result:?}");
+    let mut reader = FlacReader::open(&output).expect("should open output");
+    let decoded: Vec<i16> = reader
+        .samples()
+        .map(|sample| {
+            i16::try_from(sample.expect("should decode sample")).expect("should be 16-bit")
+        })
+        .collect();
+    assert_eq!(Some(decoded.as_slice()), samples.get(24_000..));
+    let title = get_vorbis_comment(&output, "TITLE").expect("should read tags");
+    assert_eq!(title.as_deref(), Some("Second"));
+    let track = get_vorbis_comment(&output, "TRACKNUMBER").expect("should read tags");
+    assert_eq!(track.as_deref(), Some("2"));
+    let cuesheet = get_vorbis_comment(&output, CUESHEET_TAG).expect("should read tags");
+    assert_eq!(cuesheet, None);
+}
+
+/// `SoX` can't process a range so tracks of an image are resampled with the native backend.
+#[test]
+fn transcode_job_factory_resamples_image_tracks_natively() {
+    // Arrange
+    let dir = TempDirectory::create("transcode_job_factory_resamples_image_tracks_natively");
+    let whole = PcmFlacGenerator::new()
+        .with_filename("Whole.flac")
+        .with_sample_rate(96_000)
+        .generate_flac_file(&dir);
+    let mut track = PcmFlacGenerator::new()
+        .with_filename("Album.flac")
+        .with_sample_rate(96_000)
+        .generate_flac_file(&dir);
+    track.image_track = Some(ImageTrack {
+        number: 1,
+        total: 1,
+        range: SampleRange {
+            start: 0,
+            end: Some(500),
+        },
+        title: None,
+        performer: None,
+        album: None,
+        pre_emphasis: false,
+    });
+    let host = HostBuilder::new()
+        .with_options(SharedOptions {
+            output: dir.join("output"),
+            ..SharedOptions::mock()
+        })
+        .with_options(TargetOptions {
+            resample_backend: ResampleBackend::Sox,
+            ..TargetOptions::default()
+        })
+        .expect_build();
+    let factory = host.services.get_required::<TranscodeJobFactory>();
+    let source = Source {
+        directory: dir.to_path_buf(),
+        ..Source::mock()
+    };
+
+    // Act
+    let backends: Vec<ResampleBackend> = [&whole, &track]
+        .into_iter()
+        .map(|flac| {
+            let job = factory
+                .create_single(0, flac, &source, TargetFormat::Flac, None)
+                .expect("should create job");
+            let Job::Transcode(job) = job else {
+                unreachable!("should be a transcode job");
+            };
+            let Variant::Resample(resample) = job.variant else {
+                unreachable!("should be a resample");
+            };
+            resample.backend
+        })
+        .collect();
+
+    // Assert
+    assert_eq!(
+        backends,
+        vec![ResampleBackend::Sox, ResampleBackend::Native]
+    );
+}
+
+/// Encode a 16-bit 44.1 kHz stereo FLAC image where each sample is unique.
+fn write_image(dir: &Path) -> (PathBuf, Vec<i16>) {
+    let samples: Vec<i16> = (0..20_000_i32)
+        .flat_map(|index| {
+            let sample = i16::try_from(index % 30_000).expect("sample should fit in i16");
+            [sample, -sample]
+        })
+        .collect();
+    let path = PcmFlacGenerator::new()
+        .with_filename("Album.flac")
+        .with_samples(samples.clone())
+        .generate(dir);
+    (path, samples)
+}
@@ -3,42 +3,64 @@ use std::fs::OpenOptions;
 
 #[tokio::test]
 async fn transcode_command_flac16_441() {
-    let snapshot = transcode_command_helper(SampleFormat::FLAC16_441).await;
+    let snapshot = transcode_command_helper(SampleFormat::FLAC16_441, ResampleBackend::Sox).await;
     let snapshot = normalize_snapshots!(snapshot);
     assert_yaml_snapshot!(snapshot);
 }
 
 #[tokio::test]
 async fn transcode_command_flac16_48() {
-    let snapshot = transcode_command_helper(SampleFormat::FLAC16_48).await;
+    let snapshot = transcode_command_helper(SampleFormat::FLAC16_48, ResampleBackend::Sox).await;
     let snapshot = normalize_snapshots!(snapshot);
     assert_yaml_snapshot!(snapshot);
 }
 
 #[tokio::test]
 async fn transcode_command_flac24_441() {
-    let snapshot = transcode_command_helper(SampleFormat::FLAC24_441).await;
+    let snapshot = transcode_command_helper(SampleFormat::FLAC24_441, ResampleBackend::Sox).await;
     let snapshot = normalize_snapshots!(snapshot);
     assert_yaml_snapshot!(snapshot);
 }
 
 #[tokio::test]
 async fn transcode_command_flac24_48() {
-    let snapshot = transcode_command_helper(SampleFormat::FLAC24_48).await;
+    let snapshot = transcode_command_helper(SampleFormat::FLAC24_48, ResampleBackend::Sox).await;
     let snapshot = normalize_snapshots!(snapshot);
     assert_yaml_snapshot!(snapshot);
 }
 
 #[tokio::test]
 async fn transcode_command_flac24_96() {
-    let snapshot = transcode_command_helper(SampleFormat::FLAC24_96).await;
+    let snapshot = transcode_command_helper(SampleFormat::FLAC24_96, ResampleBackend::Sox).await;
     let snapshot = normalize_snapshots!(snapshot);
     assert_yaml_snapshot!(snapshot);
 }
 
-/// A non-zero `flac` decode exit must fail the transcode rather than produce a partial output.
+#[tokio::test]
+async fn transcode_command_flac24_441_native() {
+    let snapshot =
+        transcode_command_helper(SampleFormat::FLAC24_441, ResampleBackend::Native).await;
+    let snapshot = normalize_snapshots!(snapshot, "transcode_command_flac24_441");
+    assert_yaml_snapshot!("transcode_command_flac24_441", snapshot);
+}
+
+#[tokio::test]
+async fn transcode_command_flac24_48_native() {
+    let snapshot = transcode_command_helper(SampleFormat::FLAC24_48, ResampleBackend::Native).await;
+    let snapshot = normalize_snapshots!(snapshot, "transcode_command_flac24_48");
+    assert_yaml_snapshot!("transcode_command_flac24_48", snapshot);
+}
+
+#[tokio::test]
+async fn transcode_command_flac24_96_native() {
+    let snapshot = transcode_command_helper(SampleFormat::FLAC24_96, ResampleBackend::Native).await;
+    let snapshot = normalize_snapshots!(snapshot, "transcode_command_flac24_96");
+    assert_yaml_snapshot!("transcode_command_flac24_96", snapshot);
+}
+
+/// A decode error must fail the transcode rather than produce a partial output.
 ///
-/// Truncating a source FLAC drops trailing frames so `flac` exits with `LOST_SYNC`, yet `lame`
+/// Truncating a source FLAC drops trailing frames so `claxon` fails mid-frame, yet `lame`
 /// still encodes the partial stream and exits zero. The command must surface the decode failure
 /// so the release is not marked transcoded.
 #[tokio::test]
@@ -119,7 +141,14 @@ fn truncate_to_half(path: &Path) {
         .expect("should truncate file");
 }
 
-async fn transcode_command_helper(format: SampleFormat) -> Vec<FileSnapshot> {
+/// Transcode a sample album with the given [`ResampleBackend`].
+///
+/// Snapshots of the `native` backend are compared against the `sox` snapshots with the
+/// platform dependent fields patched, so only structural differences cause failures.
+async fn transcode_command_helper(
+    format: SampleFormat,
+    backend: ResampleBackend,
+) -> Vec<FileSnapshot> {
     // Arrange
     init_logger();
     let test_dir = TestDirectory::new();
@@ -128,6 +157,10 @@ async fn transcode_command_helper(format: SampleFormat) -> Vec<FileSnapshot> {
         .with_mock_api(album)
         .with_test_options(&test_dir)
         .await
+        .with_options(TargetOptions {
+            resample_backend: backend,
+            ..TargetOptions::default()
+        })
         .expect_build();
     let provider = host.services.get_required::<SourceProvider>();
     let transcoder = host.services.get_required::<TranscodeCommand>();
@@ -0,0 +1,180 @@
+use crate::testing_prelude::*;
+use lofty::config::ParseOptions;
+use lofty::file::AudioFile;
+use lofty::id3::v2::Id3v2Tag;
+use lofty::mpeg::MpegFile;
+use lofty::tag::TagType;
+use std::fs::{File, OpenOptions};
+
+/// Header of an MPEG-1 Layer III frame at 128 kbps, 44.1 kHz and stereo.
+const MPEG1_128K: [u8; 4] = [0xFF, 0xFB, 0x90, 0x64];
+
+/// Length in bytes of a frame of [`MPEG1_128K`].
+const MPEG1_128K_LENGTH: usize = 417;
+
+#[tokio::test]
+async fn transcode_validator_accepts_transcodes() {
+    // Arrange
+    let test_dir = TestDirectory::new();
+    let (host, source) = transcode_helper(SampleFormat::FLAC24_96, &test_dir).await;
+    let validator = host.services.get_required::<TranscodeValidator>();
+    let flacs = Collector::get_flacs(&source.directory);
+
+    // Act
+    let result = validator.execute(&source, &flacs, &source.targets);
+
+    // Assert
+    assert!(result.is_ok(), "validation should pass: {result:?}");
+}
+
+#[tokio::test]
+async fn transcode_validator_truncated_mp3() {
+    // Arrange
+    let test_dir = TestDirectory::new();
+    let (host, source) = transcode_helper(SampleFormat::default(), &test_dir).await;
+    let validator = host.services.get_required::<TranscodeValidator>();
+    let flacs = Collector::get_flacs(&source.directory);
+    let output = get_first_output(&host, &source, &flacs, TargetFormat::V0);
+    let length = metadata(&output).expect("should read metadata").len();
+    OpenOptions::new()
+        .write(true)
+        .open(&output)
+        .expect("should open file")
+        .set_len(length.div_ceil(2))
+        .expect("should truncate file");
+
+    // Act
+    let result = validator.execute(&source, &flacs, &source.targets);
+
+    // Assert
+    assert_eq!(
+        get_mismatch(result),
+        Some(OutputMismatch::Duration),
+        "truncated output should fail validation"
+    );
+}
+
+#[tokio::test]
+async fn transcode_validator_missing_tags() {
+    // Arrange
+    let test_dir = TestDirectory::new();
+    let (host, source) = transcode_helper(SampleFormat::default(), &test_dir).await;
+    let validator = host.services.get_required::<TranscodeValidator>();
+    let flacs = Collector::get_flacs(&source.directory);
+    let output = get_first_output(&host, &source, &flacs, TargetFormat::_320);
+    TagType::Id3v2
+        .remove_from_path(&output)
+        .expect("should remove tags");
+
+    // Act
+    let result = validator.execute(&source, &flacs, &source.targets);
+
+    // Assert
+    assert_eq!(
+        get_mismatch(result),
+        Some(OutputMismatch::Tags),
+        "output without tags should fail validation"
+    );
+}
+
+/// lofty upgrades `ID3v2.3` frames such as `TYER` to their `ID3v2.4` equivalents when reading,
+/// so the frames of `ID3v2.3` output must still match the tags they were written from.
+#[test]
+fn get_missing_id3v2_frames_of_id3v23_output() {
+    // Arrange
+    let dir = TempDirectory::create("get_missing_id3v2_frames_of_id3v23_output");
+    let flac = PcmFlacGenerator::new()
+        .with_vorbis_tag("TITLE", "Track")
+        .with_vorbis_tag("ARTIST", "Artist A")
+        .with_vorbis_tag("ARTIST", "Artist B")
+        .with_vorbis_tag("ARTISTSORT", "A, Artist")
+        .with_vorbis_tag("ALBUM", "Album")
+        .with_vorbis_tag("DATE", "2020-05-01")
+        .with_vorbis_tag("ORIGINALDATE", "1999")
+        .with_vorbis_tag("GENRE", "Rock")
+        .with_vorbis_tag("GENRE", "Jazz")
+        .with_vorbis_tag("TRACKNUMBER", "1")
+        .with_vorbis_tag("TRACKTOTAL", "10")
+        .with_vorbis_tag("DISCNUMBER", "1")
+        .with_vorbis_tag("CATALOGNUMBER", "CAT-1")
+        .generate_flac_file(&dir);
+    let options = Id3Options {
+        id3_mapping: vec![
+            "CATALOGNUMBER=TXXX:CATALOG NUMBER"
+                .parse()
+                .expect("should parse"),
+        ],
+        id3_version: Id3Version::V23,
+        ..Id3Options::default()
+    };
+    let expected = map_id3_tags(&flac, &options).expect("should map tags");
+    let output = dir.join("track.mp3");
+    write_mpeg_frames(&output);
+    save_id3v2_deterministic(expected.clone(), &output, options.id3_version)
+        .expect("should save tags");
+
+    // Act
+    let mut file = File::open(&output).expect("should open MP3");
+    let mpeg = MpegFile::read_from(&mut file, ParseOptions::default()).expect("should read MP3");
+    let empty = Id3v2Tag::new();
+    let missing = get_missing_id3v2_frames(&expected, mpeg.id3v2().unwrap_or(&empty));
+
+    // Assert
+    let bytes = read(&output).expect("should read MP3");
+    assert_eq!(bytes.get(..4), Some(b"ID3\x03".as_slice()));
+    assert_eq!(missing, Vec::<String>::new());
+}
+
+/// Transcode a sample album and return the host and [`Source`].
+async fn transcode_helper(format: SampleFormat, test_dir: &TestDirectory) -> (Host, Source) {
+    init_logger();
+    let album = AlbumProvider::get(format).await;
+    let host = HostBuilder::new()
+        .with_mock_api(album)
+        .with_test_options(test_dir)
+        .await
+        .expect_build();
+    let provider = host.services.get_required::<SourceProvider>();
+    let transcoder = host.services.get_required::<TranscodeCommand>();
+    let source = provider
+        .get(AlbumConfig::TORRENT_ID)
+        .await
+        .expect("should not fail")
+        .expect("should find source");
+    transcoder
+        .execute(&source)
+        .await
+        .expect("transcode should succeed");
+    (host, source)
+}
+
+/// Path of the transcoded output of the first FLAC.
+fn get_first_output(
+    host: &Host,
+    source: &Source,
+    flacs: &[FlacFile],
+    format: TargetFormat,
+) -> PathBuf {
+    let paths = host.services.get_required::<PathManager>();
+    let flac = flacs.first().expect("should be at least one FLAC");
+    paths.get_transcode_path(source, format, flac)
+}
+
+/// Get the [`OutputMismatch`] of a validation failure.
+fn get_mismatch(result: Result<(), Failure<TranscodeAction>>) -> Option<OutputMismatch> {
+    let error = result.err()?;
+    match error.source()?.downcast_ref::<TranscodeError>()? {
+        TranscodeError::InvalidOutput(mismatch) => Some(*mismatch),
+        _ => None,
+    }
+}
+
+/// Write an MP3 of silent [`MPEG1_128K`] frames.
+fn write_mpeg_frames(path: &Path) {
+    let mut frame = vec![0; MPEG1_128K_LENGTH];
+    frame
+        .get_mut(..4)
+        .expect("frame should fit header")
+        .copy_from_slice(&MPEG1_128K);
+    write(path, frame.repeat(10)).expect("should write MP3");
+}
@@ -0,0 +1,75 @@
+use crate::testing_prelude::*;
+use claxon::FlacReader;
+use tokio::sync::mpsc::channel;
+
+/// Read a little endian `u32` from the header at `offset`.
+fn read_u32(header: &[u8], offset: usize) -> u32 {
+    let bytes = header
+        .get(offset..offset + 4)
+        .expect("header should contain offset");
+    u32::from_le_bytes(bytes.try_into().expect("should be 4 bytes"))
+}
+
+#[test]
+fn wav_header_stereo_16_bit() {
+    // Act
+    let header = wav_header(2, 44100, Some(44100));
+
+    // Assert
+    assert_eq!(header.len(), 44);
+    assert_eq!(header.get(0..4), Some(b"RIFF".as_slice()));
+    assert_eq!(header.get(8..16), Some(b"WAVEfmt ".as_slice()));
+    assert_eq!(header.get(36..40), Some(b"data".as_slice()));
+    assert_eq!(read_u32(&header, 4), 44100 * 4 + 36);
+    assert_eq!(read_u32(&header, 24), 44100);
+    assert_eq!(read_u32(&header, 28), 44100 * 4);
+    assert_eq!(read_u32(&header, 40), 44100 * 4);
+}
+
+#[test]
+fn wav_header_unknown_samples_uses_maximum_size() {
+    // Act
+    let header = wav_header(2, 44100, None);
+
+    // Assert
+    assert_eq!(read_u32(&header, 4), u32::MAX - 8);
+    assert_eq!(read_u32(&header, 40), u32::MAX - 44);
+}
+
+/// In process decode produces a header and exactly one 16-bit sample per channel per frame.
+#[tokio::test]
+async fn decode_flac_to_wav_matches_stream_info() {
+    // Arrange
+    init_logger();
+    let album = AlbumProvider::get(SampleFormat::FLAC16_441).await;
+    let track = album.tracks.first().expect("album should have a track");
+    let path = SAMPLE_SOURCES_DIR
+        .join(album.dir_name())
+        .join(album.track_filename(track));
+    let info = FlacReader::open(&path)
+        .expect("should open FLAC")
+        .streaminfo();
+    let (sender, mut receiver) = channel::<Vec<u8>>(1024);
+
+    // Act
+    let result = spawn_blocking(move || decode_flac_to_wav(&path, None, &sender))
+        .await
+        .expect("decode task should not panic");
+    let mut output = Vec::new();
+    while let Some(chunk) = receiver.recv().await {
+        output.extend(chunk);
+    }
+
+    // Assert
+    assert!(result.is_ok(), "decode should succeed");
+    let samples = info.samples.expect("should have samples");
+    let expected = 44 + samples * u64::from(info.channels) * 2;
+    assert_eq!(
+        u64::try_from(output.len()).expect("length should fit in u64"),
+        expected
+    );
+    assert_eq!(
+        output.get(0..44),
+        Some(wav_header(info.channels, info.sample_rate, info.samples).as_slice())
+    );
+}
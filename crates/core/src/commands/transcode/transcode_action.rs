@@ -23,8 +23,18 @@ pub enum TranscodeAction {
     WaitDecode,
     #[error("wait for encode")]
     WaitEncode,
+    #[error("write decoded audio to encoder")]
+    WriteDecode,
     #[error("resample")]
     Resample,
+    #[error("de-emphasize")]
+    Deemphasize,
+    #[error("downmix to stereo")]
+    Downmix,
+    #[error("re-encode FLAC")]
+    Reencode,
+    #[error("encode FLAC")]
+    EncodeFlac,
     #[error("write tags")]
     WriteTags,
     #[error("read FLAC")]
@@ -41,10 +51,18 @@ pub enum TranscodeAction {
     CreateTorrent,
     #[error("resize image")]
     ResizeImage,
+    #[error("read folder cover")]
+    ReadCover,
+    #[error("write embedded pictures")]
+    WritePictures,
     #[error("execute transcode runner")]
     ExecuteRunner,
     #[error("exclude vorbis comments from resampled output")]
     ExcludeVorbisComments,
+    #[error("validate transcoded output")]
+    Validate,
+    #[error("analyze replay gain")]
+    AnalyzeReplayGain,
 }
 
 /// Errors that can occur during transcoding.
@@ -54,4 +72,31 @@ pub enum TranscodeError {
     NoTranscodes,
     #[error("unsupported sample rate: {0}")]
     UnsupportedSampleRate(u32),
+    #[error("unsupported bit depth for in process decode: {0}")]
+    UnsupportedBitDepth(u32),
+    #[error("unsupported channel count for downmix: {0}")]
+    UnsupportedChannels(u32),
+    #[error("decoded audio does not match the MD5 signature of the source")]
+    Md5Mismatch,
+    #[error("transcoded output failed validation: {0}")]
+    InvalidOutput(OutputMismatch),
+}
+
+/// Properties of a transcoded output that can differ from what was expected.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ThisError)]
+pub enum OutputMismatch {
+    #[error("duration does not match source")]
+    Duration,
+    #[error("sample rate does not match target")]
+    SampleRate,
+    #[error("bit depth does not match target")]
+    BitDepth,
+    #[error("bitrate mode does not match target format")]
+    BitrateMode,
+    #[error("LAME tag is missing, corrupt, or has the wrong preset")]
+    LameTag,
+    #[error("ID3 tags are missing or altered")]
+    Tags,
+    #[error("decoded audio does not match source")]
+    Audio,
 }
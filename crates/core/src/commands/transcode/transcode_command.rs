@@ -7,8 +7,11 @@ pub(crate) struct TranscodeCommand {
     source_provider: Ref<SourceProvider>,
     copy_options: Ref<CopyOptions>,
     file_options: Ref<FileOptions>,
+    target_options: Ref<TargetOptions>,
+    runner_options: Ref<RunnerOptions>,
     paths: Ref<PathManager>,
     transcode_job_factory: Ref<TranscodeJobFactory>,
+    transcode_validator: Ref<TranscodeValidator>,
     additional_job_factory: Ref<AdditionalJobFactory>,
     runner: Ref<JobRunner>,
 }
@@ -55,7 +58,9 @@ impl TranscodeCommand {
         if targets.is_empty() {
             return Ok(TranscodeSuccess { formats });
         }
-        self.execute_transcode(source, &targets).await?;
+        let flacs = self.get_flacs(source);
+        self.execute_transcode(source, &flacs, &targets).await?;
+        self.transcode_validator.execute(source, &flacs, &targets)?;
         self.execute_additional(source, &targets).await?;
         self.execute_torrent(source, &targets)
             .await
@@ -86,18 +91,20 @@ impl TranscodeCommand {
         out
     }
 
+    fn get_flacs(&self, source: &Source) -> Vec<FlacFile> {
+        if self.file_options.rename_tracks {
+            Collector::get_flacs_with_context(&source.directory)
+        } else {
+            Collector::get_tracks(&source.directory)
+        }
+    }
+
     async fn execute_transcode(
         &self,
         source: &Source,
+        flacs: &[FlacFile],
         targets: &BTreeSet<TargetFormat>,
     ) -> Result<(), Failure<TranscodeAction>> {
-        let rename_tracks = self.file_options.rename_tracks;
-        let flacs = if rename_tracks {
-            Collector::get_flacs_with_context(&source.directory)
-        } else {
-            Collector::get_flacs(&source.directory)
-        };
-
         info!(
             "{} to {} for {} FLACs in {}",
             "Transcoding".bold(),
@@ -105,8 +112,16 @@ impl TranscodeCommand {
             flacs.len().to_string().gray(),
             source
         );
+        let replay_gain = if self.target_options.replay_gain {
+            debug!("{} replay gain {}", "Analyzing".bold(), source);
+            analyze_replay_gain(flacs, self.runner_options.get_cpus()).await?
+        } else {
+            BTreeMap::new()
+        };
         for target in targets {
-            let jobs = self.transcode_job_factory.create(&flacs, source, *target)?;
+            let jobs = self
+                .transcode_job_factory
+                .create(flacs, source, *target, &replay_gain)?;
             self.runner.add(jobs);
         }
         self.runner
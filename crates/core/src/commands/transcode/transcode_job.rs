@@ -1,7 +1,13 @@
 use crate::prelude::*;
-use lofty::id3::v2::Id3v2Tag;
+use lofty::TextEncoding;
+use lofty::id3::v2::{ExtendedTextFrame, Frame, Id3v2Tag};
 use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
 use tokio::join;
+use tokio::sync::mpsc::{Sender, channel};
+
+/// Number of decoded FLAC frames to buffer ahead of the encoder.
+const DECODE_CHANNEL_CAPACITY: usize = 16;
 
 /// Job to transcode a single FLAC file to a target format.
 pub(crate) struct TranscodeJob {
@@ -11,8 +17,17 @@ pub(crate) struct TranscodeJob {
     pub variant: Variant,
     /// ID3 tags to write to MP3 output.
     pub tags: Option<Id3v2Tag>,
+    /// Version of the ID3 tags written to MP3 output.
+    pub id3_version: Id3Version,
     /// Vorbis comment tag names to exclude from output.
     pub exclude_vorbis_comments: Vec<String>,
+    /// [`ReplayGain`] to write to output.
+    pub replay_gain: Option<ReplayGain>,
+    /// Vorbis comments to write to FLAC output, such as the track number and title of a track
+    /// of a FLAC image.
+    pub comments: Vec<(&'static str, String)>,
+    /// [`Artwork`] to embed in output, or `None` to leave pictures unchanged.
+    pub artwork: Option<Artwork>,
 }
 
 impl TranscodeJob {
@@ -21,12 +36,29 @@ impl TranscodeJob {
     /// Tags named in [`exclude_vorbis_comments`](TranscodeJob::exclude_vorbis_comments) are
     /// stripped from the output via two paths:
     /// - **MP3 transcode**: excluded from the in-memory [`Id3v2Tag`] before writing
-    /// - **FLAC resample**: excluded from the on-disk Vorbis comments after `SoX` writes the file
+    /// - **FLAC resample**: excluded from the on-disk Vorbis comments after the file is written
+    ///
+    /// De-emphasized FLAC output also excludes the [`PRE_EMPHASIS_TAG`] so it is not flagged
+    /// as pre-emphasized.
+    ///
+    /// If there is a [`ReplayGain`] it's written as `TXXX` frames for MP3 and Vorbis comments
+    /// for FLAC, excluding any named in
+    /// [`exclude_vorbis_comments`](TranscodeJob::exclude_vorbis_comments).
+    ///
+    /// [`comments`](TranscodeJob::comments) are written to FLAC output, replacing any copied
+    /// from the input.
+    ///
+    /// If there is an [`Artwork`] the embedded pictures of the output are replaced last, after
+    /// the Vorbis comments of a FLAC are written.
     pub(crate) async fn execute(self) -> Result<(), Failure<TranscodeAction>> {
         let output_path = match &self.variant {
-            Variant::Transcode(_, encode) => encode.output.clone(),
+            Variant::Transcode(_, encode)
+            | Variant::Deemphasize(_, encode)
+            | Variant::Downmix(_, encode) => encode.output.clone(),
             Variant::Resample(resample) => resample.output.clone(),
             Variant::Include(include) => include.output.clone(),
+            Variant::Reencode(reencode) => reencode.output.clone(),
+            Variant::Split(split) => split.output.clone(),
         };
         let output_dir = output_path
             .parent()
@@ -35,6 +67,19 @@ impl TranscodeJob {
             TranscodeAction::CreateOutputDirectory,
             output_dir,
         ))?;
+        let replay_gain = self
+            .replay_gain
+            .map(|gain| gain.to_comments(&self.exclude_vorbis_comments))
+            .unwrap_or_default();
+        let mut comments = self.comments;
+        let is_flac = match &self.variant {
+            Variant::Transcode(..) | Variant::Downmix(..) => false,
+            Variant::Deemphasize(_, encode) => encode.format == TargetFormat::Flac,
+            Variant::Resample(_)
+            | Variant::Reencode(_)
+            | Variant::Include(_)
+            | Variant::Split(_) => true,
+        };
         match self.variant {
             Variant::Transcode(decode, encode) => execute_transcode(decode, encode).await?,
             Variant::Resample(resample) => {
@@ -42,23 +87,161 @@ impl TranscodeJob {
                 exclude_vorbis_comments_from_flac(&output_path, &self.exclude_vorbis_comments)
                     .map_err(Failure::wrap(TranscodeAction::ExcludeVorbisComments))?;
             }
+            Variant::Deemphasize(deemphasize, encode) => {
+                execute_deemphasize(deemphasize, encode).await?;
+                if is_flac {
+                    let mut keys = self.exclude_vorbis_comments.clone();
+                    keys.push(PRE_EMPHASIS_TAG.to_owned());
+                    exclude_vorbis_comments_from_flac(&output_path, &keys)
+                        .map_err(Failure::wrap(TranscodeAction::ExcludeVorbisComments))?;
+                }
+            }
+            Variant::Downmix(downmix, encode) => execute_downmix(downmix, encode).await?,
             Variant::Include(include) => execute_include(include).await?,
+            Variant::Reencode(reencode) => {
+                execute_reencode(reencode).await?;
+                exclude_vorbis_comments_from_flac(&output_path, &self.exclude_vorbis_comments)
+                    .map_err(Failure::wrap(TranscodeAction::ExcludeVorbisComments))?;
+            }
+            Variant::Split(split) => {
+                execute_split(split).await?;
+                exclude_vorbis_comments_from_flac(&output_path, &self.exclude_vorbis_comments)
+                    .map_err(Failure::wrap(TranscodeAction::ExcludeVorbisComments))?;
+            }
         }
-        if let Some(mut tags) = self.tags {
+        if is_flac {
+            comments.extend(replay_gain);
+            insert_vorbis_comments_into_flac(&output_path, comments)
+                .map_err(Failure::wrap(TranscodeAction::WriteTags))?;
+            if let Some(artwork) = self.artwork {
+                replace_flac_pictures(&output_path, artwork.get_pictures()?)
+                    .map_err(Failure::wrap(TranscodeAction::WritePictures))?;
+            }
+        } else if let Some(mut tags) = self.tags {
+            if let Some(artwork) = self.artwork {
+                replace_id3v2_pictures(&mut tags, artwork.get_pictures()?);
+            }
             exclude_tags(&mut tags, &vorbis_keys(&self.exclude_vorbis_comments));
-            save_id3v2_deterministic(tags, &output_path).map_err(Failure::wrap_with_path(
-                TranscodeAction::WriteTags,
-                &output_path,
-            ))?;
+            for (description, content) in replay_gain {
+                tags.insert(Frame::UserText(ExtendedTextFrame::new(
+                    TextEncoding::UTF8,
+                    description.to_owned(),
+                    content,
+                )));
+            }
+            save_id3v2_deterministic(tags, &output_path, self.id3_version).map_err(
+                Failure::wrap_with_path(TranscodeAction::WriteTags, &output_path),
+            )?;
         }
         Ok(())
     }
 }
 
-/// Pipe decode output directly to encode input.
+/// Decode the FLAC and pipe the WAV output directly to encode input.
+///
+/// If a resample is required with the [`ResampleBackend::Sox`] backend then `SoX` decodes and
+/// resamples in a child process, otherwise the FLAC is decoded in process with `claxon`.
 async fn execute_transcode(decode: Decode, encode: Encode) -> Result<(), Failure<TranscodeAction>> {
-    let decode_input = decode.input.clone();
-    let decode_info = decode.to_info();
+    let input = decode.input.clone();
+    if decode.is_in_process() {
+        let range = decode.range;
+        let resample_rate = decode.resample_rate;
+        let repeatable = decode.repeatable;
+        let description = decode.display();
+        execute_in_process_transcode(description, encode, move |sender| match resample_rate {
+            Some(rate) => resample_flac_to_wav(&input, range, rate, repeatable, sender),
+            None => decode_flac_to_wav(&input, range, sender),
+        })
+        .await
+    } else {
+        let decode_info = decode.to_info().expect("decode should have a command");
+        execute_piped_transcode(decode_info, &input, encode).await
+    }
+}
+
+/// De-emphasize the FLAC then encode to the target format.
+///
+/// - FLAC output is written directly by `SoX`, or encoded in process with `flacenc`
+/// - MP3 output is piped to the encoder as with [`execute_transcode`]
+async fn execute_deemphasize(
+    deemphasize: Deemphasize,
+    encode: Encode,
+) -> Result<(), Failure<TranscodeAction>> {
+    let input = deemphasize.input.clone();
+    let range = deemphasize.range;
+    let repeatable = deemphasize.repeatable;
+    if encode.format == TargetFormat::Flac {
+        let output = encode.output.clone();
+        if deemphasize.is_in_process() {
+            trace!("Executing de-emphasis: {}", deemphasize.display(encode));
+            return spawn_blocking(move || {
+                deemphasize_flac_to_flac(&input, range, &output, repeatable)
+            })
+            .await
+            .expect("de-emphasis task should not panic");
+        }
+        let info = deemphasize
+            .to_info(Some(&output))
+            .expect("deemphasize should have a command");
+        trace!("Executing de-emphasis: {info}");
+        info.to_command()
+            .run()
+            .await
+            .map_err(Failure::wrap_with_path(
+                TranscodeAction::Deemphasize,
+                &output,
+            ))?;
+        return Ok(());
+    }
+    if deemphasize.is_in_process() {
+        let description = format!(
+            "claxon (in-process) {}{} | {}",
+            input.display(),
+            display_range(range),
+            display_native_deemphasis(repeatable)
+        );
+        execute_in_process_transcode(description, encode, move |sender| {
+            deemphasize_flac_to_wav(&input, range, repeatable, sender)
+        })
+        .await
+    } else {
+        let info = deemphasize
+            .to_info(None)
+            .expect("deemphasize should have a command");
+        execute_piped_transcode(info, &input, encode).await
+    }
+}
+
+/// Downmix the FLAC to stereo in process and stream the WAV output to encode input.
+async fn execute_downmix(downmix: Downmix, encode: Encode) -> Result<(), Failure<TranscodeAction>> {
+    let description = downmix.display();
+    let Downmix {
+        input,
+        range,
+        resample_rate,
+        deemphasize,
+        repeatable,
+        ..
+    } = downmix;
+    execute_in_process_transcode(description, encode, move |sender| {
+        downmix_flac_to_wav(
+            &input,
+            range,
+            resample_rate,
+            deemphasize,
+            repeatable,
+            sender,
+        )
+    })
+    .await
+}
+
+/// Pipe decode process output directly to encode input.
+async fn execute_piped_transcode(
+    decode_info: CommandInfo,
+    decode_input: &Path,
+    encode: Encode,
+) -> Result<(), Failure<TranscodeAction>> {
     let encode_info = encode.to_info();
     trace!("Executing transcode: {decode_info} | {encode_info}");
     let decode_program = decode_info.program.clone();
@@ -94,7 +277,7 @@ async fn execute_transcode(decode: Decode, encode: Encode) -> Result<(), Failure
         return Err(Failure::from_action(TranscodeAction::Decode)
             .with("program", &decode_program)
             .with("exit", decode_exit.to_string())
-            .with_path(&decode_input));
+            .with_path(decode_input));
     }
     require_success(encode_output, &encode_program).map_err(Failure::wrap_with_path(
         TranscodeAction::Transcode,
@@ -103,9 +286,60 @@ async fn execute_transcode(decode: Decode, encode: Encode) -> Result<(), Failure
     Ok(())
 }
 
+/// Decode in process with `claxon` and stream the WAV output to encode input.
+///
+/// - `decode` runs on the blocking pool and sends chunks over a bounded channel
+/// - A decode failure takes precedence over an encode failure as `lame` will happily
+///   encode a partial stream
+async fn execute_in_process_transcode(
+    description: String,
+    encode: Encode,
+    decode: impl FnOnce(&Sender<Vec<u8>>) -> Result<(), Failure<TranscodeAction>> + Send + 'static,
+) -> Result<(), Failure<TranscodeAction>> {
+    let encode_info = encode.to_info();
+    trace!("Executing transcode: {description} | {encode_info}");
+    let encode_program = encode_info.program.clone();
+    let mut encode_command = encode_info
+        .to_command()
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(Failure::wrap_with(TranscodeAction::SpawnEncode, |f| {
+            f.with("program", &encode_program)
+        }))?;
+    let mut stdin = encode_command
+        .stdin
+        .take()
+        .expect("should be able to take stdin");
+    let (sender, mut receiver) = channel::<Vec<u8>>(DECODE_CHANNEL_CAPACITY);
+    let decode_task = spawn_blocking(move || decode(&sender));
+    let write_task = async move {
+        while let Some(chunk) = receiver.recv().await {
+            stdin.write_all(&chunk).await?;
+        }
+        stdin.shutdown().await
+    };
+    let (decode_result, write_result, encode_output) =
+        join!(decode_task, write_task, encode_command.wait_with_output());
+    decode_result.expect("decode task should not panic")?;
+    let encode_output = encode_output.map_err(Failure::wrap(TranscodeAction::WaitEncode))?;
+    require_success(encode_output, &encode_program).map_err(Failure::wrap_with_path(
+        TranscodeAction::Transcode,
+        &encode_program,
+    ))?;
+    write_result.map_err(Failure::wrap_with(TranscodeAction::WriteDecode, |f| {
+        f.with("program", &encode_program)
+    }))?;
+    Ok(())
+}
+
 async fn execute_resample(resample: Resample) -> Result<(), Failure<TranscodeAction>> {
+    if resample.is_in_process() {
+        return execute_native_resample(resample).await;
+    }
     let output = resample.output.clone();
-    let info = resample.to_info();
+    let info = resample.to_info().expect("resample should have a command");
     trace!("Executing resample: {info}");
     info.to_command()
         .run()
@@ -114,6 +348,61 @@ async fn execute_resample(resample: Resample) -> Result<(), Failure<TranscodeAct
     Ok(())
 }
 
+async fn execute_native_resample(resample: Resample) -> Result<(), Failure<TranscodeAction>> {
+    let input = resample.input.clone();
+    let range = resample.range;
+    let output = resample.output.clone();
+    let resample_rate = resample.resample_rate;
+    let repeatable = resample.repeatable;
+    trace!("Executing resample: {}", resample.display());
+    spawn_blocking(move || resample_flac_to_flac(&input, range, &output, resample_rate, repeatable))
+        .await
+        .expect("resample task should not panic")
+}
+
+/// Re-encode the FLAC, or copy it if the re-encode is only for compression and is not smaller.
+async fn execute_reencode(reencode: Reencode) -> Result<(), Failure<TranscodeAction>> {
+    trace!(
+        "Executing re-encode ({}): {}",
+        reencode.reason,
+        reencode.display()
+    );
+    let Reencode {
+        input,
+        output,
+        reason,
+    } = reencode;
+    let is_smaller = spawn_blocking({
+        let input = input.clone();
+        let output = output.clone();
+        move || {
+            reencode_flac(&input, &output)?;
+            is_reencode_smaller(&input, &output)
+        }
+    })
+    .await
+    .expect("re-encode task should not panic")?;
+    if reason.is_compression() && !is_smaller {
+        tokio_copy(&input, &output)
+            .await
+            .map_err(Failure::wrap_with_path(TranscodeAction::CopyFlac, &output))?;
+        trace!(
+            "{} {} to {} as re-encoding did not reduce its size",
+            "Copied".bold(),
+            input.display(),
+            output.display()
+        );
+    }
+    Ok(())
+}
+
+async fn execute_split(split: Split) -> Result<(), Failure<TranscodeAction>> {
+    trace!("Executing split: {}", split.display());
+    spawn_blocking(move || split_flac_to_flac(&split.input, split.range, &split.output))
+        .await
+        .expect("split task should not panic")
+}
+
 async fn execute_include(include: Include) -> Result<(), Failure<TranscodeAction>> {
     let verb = if include.hard_link {
         tokio_hard_link(&include.input, &include.output)
@@ -1,36 +1,56 @@
 use crate::prelude::*;
+use claxon::metadata::StreamInfo;
+
+/// Sample rate of CD audio, the only rate the de-emphasis filter is defined for.
+const CD_SAMPLE_RATE: u32 = 44_100;
 
 /// Factory for creating [`TranscodeJob`] instances.
 #[injectable]
 pub(crate) struct TranscodeJobFactory {
     paths: Ref<PathManager>,
     copy_options: Ref<CopyOptions>,
+    file_options: Ref<FileOptions>,
     target_options: Ref<TargetOptions>,
+    id3_options: Ref<Id3Options>,
     sox: Ref<SoxFactory>,
 }
 
 impl TranscodeJobFactory {
     /// Create a [`TranscodeJob`] for each [`FlacFile`] in the [`Vec<FlacFile>`].
+    ///
+    /// `replay_gain` is keyed by [`FlacFile::get_track_path`].
     pub(crate) fn create(
         &self,
         flacs: &[FlacFile],
         source: &Source,
         format: TargetFormat,
+        replay_gain: &BTreeMap<PathBuf, ReplayGain>,
     ) -> Result<Vec<Job>, Failure<TranscodeAction>> {
         let mut jobs = Vec::new();
         for (index, flac) in flacs.iter().enumerate() {
-            jobs.push(self.create_single(index, flac, source, format)?);
+            let gain = replay_gain.get(&flac.get_track_path()).cloned();
+            jobs.push(self.create_single(index, flac, source, format, gain)?);
         }
         Ok(jobs)
     }
 
     /// Create a single [`TranscodeJob`] from a `flac_file`.
+    ///
+    /// If there is a `replay_gain` then FLAC output is copied rather than hard linked as the
+    /// tags of the output are modified.
+    ///
+    /// A track of a FLAC image is split in process from the image so it is never copied, and
+    /// the tags of the track replace those of the image.
+    ///
+    /// A 16-bit FLAC is re-encoded rather than copied if there is a [`ReencodeReason`].
+    #[expect(clippy::too_many_lines, reason = "flat selection of variants")]
     pub(crate) fn create_single(
         &self,
         index: usize,
         flac: &FlacFile,
         source: &Source,
         format: TargetFormat,
+        replay_gain: Option<ReplayGain>,
     ) -> Result<Job, Failure<TranscodeAction>> {
         let info = flac.get_stream_info().map_err(Failure::wrap_with_path(
             TranscodeAction::ReadFlac,
@@ -38,60 +58,258 @@ impl TranscodeJobFactory {
         ))?;
         let id = format!("Transcode {:<4}{index:>3}", format.to_string());
         let output_path = self.paths.get_transcode_path(source, format, flac);
+        let range = flac.get_range();
         let repeatable = !self.target_options.sox_random_dither;
-        let variant = match format {
-            TargetFormat::Flac => {
-                if is_resample_required(&info) {
-                    Variant::Resample(Resample {
-                        input: flac.path.clone(),
-                        output: output_path.clone(),
-                        resample_rate: get_resample_rate(&info).map_err(
-                            Failure::wrap_with_path(TranscodeAction::GetSampleRate, &flac.path),
-                        )?,
-                        repeatable,
-                        sox: self.sox.clone(),
-                    })
-                } else {
-                    Variant::Include(Include {
-                        input: flac.path.clone(),
-                        output: output_path.clone(),
-                        hard_link: self.copy_options.hard_link,
-                    })
+        let backend = self.get_backend(flac, range, &info);
+        let variant = if self.is_downmix_required(format, &info) {
+            Variant::Downmix(
+                Downmix {
+                    input: flac.path.clone(),
+                    range,
+                    channels: info.channels,
+                    resample_rate: get_encode_resample_rate(flac, &info)?,
+                    deemphasize: self.is_deemphasis_required(flac, source, format, &info),
+                    repeatable,
+                },
+                Encode {
+                    format,
+                    output: output_path.clone(),
+                },
+            )
+        } else if self.is_deemphasis_required(flac, source, format, &info) {
+            Variant::Deemphasize(
+                Deemphasize {
+                    input: flac.path.clone(),
+                    range,
+                    repeatable,
+                    backend,
+                    sox: self.sox.clone(),
+                },
+                Encode {
+                    format,
+                    output: output_path.clone(),
+                },
+            )
+        } else {
+            match format {
+                TargetFormat::Flac => {
+                    if is_resample_required(&info) {
+                        Variant::Resample(Resample {
+                            input: flac.path.clone(),
+                            range,
+                            output: output_path.clone(),
+                            resample_rate: get_resample_rate(&info).map_err(
+                                Failure::wrap_with_path(TranscodeAction::GetSampleRate, &flac.path),
+                            )?,
+                            repeatable,
+                            backend,
+                            sox: self.sox.clone(),
+                        })
+                    } else if let Some(range) = range {
+                        Variant::Split(Split {
+                            input: flac.path.clone(),
+                            range,
+                            output: output_path.clone(),
+                        })
+                    } else if let Some(reason) = self.get_reencode_reason(flac, &info)? {
+                        Variant::Reencode(Reencode {
+                            input: flac.path.clone(),
+                            output: output_path.clone(),
+                            reason,
+                        })
+                    } else {
+                        Variant::Include(Include {
+                            input: flac.path.clone(),
+                            output: output_path.clone(),
+                            hard_link: self.copy_options.hard_link
+                                && replay_gain.is_none()
+                                && self.file_options.artwork == ArtworkPolicy::Keep,
+                        })
+                    }
                 }
-            }
-            TargetFormat::_320 | TargetFormat::V0 => {
-                let resample_rate = is_resample_required(&info)
-                    .then(|| get_resample_rate(&info))
-                    .transpose()
-                    .map_err(Failure::wrap_with_path(
-                        TranscodeAction::GetSampleRate,
-                        &flac.path,
-                    ))?;
-                Variant::Transcode(
+                TargetFormat::_320 | TargetFormat::V0 => Variant::Transcode(
                     Decode {
                         input: flac.path.clone(),
-                        resample_rate,
+                        range,
+                        resample_rate: get_encode_resample_rate(flac, &info)?,
                         repeatable,
+                        backend,
                         sox: self.sox.clone(),
                     },
                     Encode {
                         format,
                         output: output_path.clone(),
                     },
-                )
+                ),
             }
         };
         let tags = if matches!(format, TargetFormat::_320 | TargetFormat::V0) {
-            Some(flac.id3_tags()?.clone())
+            Some(map_id3_tags(flac, &self.id3_options)?)
         } else {
             None
         };
-        let exclude_vorbis_comments = self.target_options.exclude_vorbis_comments.clone();
+        let mut exclude_vorbis_comments = self.target_options.exclude_vorbis_comments.clone();
+        let comments = match &flac.image_track {
+            Some(track) => {
+                exclude_vorbis_comments.push(CUESHEET_TAG.to_owned());
+                track.get_comments()
+            }
+            None => Vec::new(),
+        };
+        let artwork = self.get_artwork(flac, source, format, &variant);
         Ok(Job::Transcode(TranscodeJob {
             id,
             variant,
             tags,
+            id3_version: self.id3_options.id3_version,
             exclude_vorbis_comments,
+            replay_gain,
+            comments,
+            artwork,
         }))
     }
+
+    /// Get the [`Artwork`] to apply to the output, or `None` if the pictures are unchanged.
+    ///
+    /// With the `keep` policy the pictures are left unchanged if the output already has them or
+    /// should not have them:
+    /// - MP3 tags already include the pictures of the source
+    /// - An included FLAC is a copy or hard link of the source
+    /// - A FLAC re-encoded for [`ReencodeReason::Pictures`] is re-encoded to drop them
+    ///
+    /// FLAC output written by any other variant is given the pictures of the source as they are
+    /// not otherwise copied.
+    fn get_artwork(
+        &self,
+        flac: &FlacFile,
+        source: &Source,
+        format: TargetFormat,
+        variant: &Variant,
+    ) -> Option<Artwork> {
+        let policy = self.file_options.artwork;
+        if policy == ArtworkPolicy::Keep {
+            let is_unchanged =
+                format != TargetFormat::Flac || matches!(variant, Variant::Include(_));
+            let is_dropped = matches!(
+                variant,
+                Variant::Reencode(reencode) if reencode.reason == ReencodeReason::Pictures
+            );
+            if is_dropped {
+                debug!(
+                    "{} embedded pictures of {} as they exceed {MAX_PICTURE_BYTES} bytes",
+                    "Dropping".bold(),
+                    flac.path.display()
+                );
+            }
+            if is_unchanged || is_dropped {
+                return None;
+            }
+        }
+        Some(Artwork {
+            policy,
+            input: flac.path.clone(),
+            cover: if policy == ArtworkPolicy::Embed {
+                find_cover(flac, &source.directory)
+            } else {
+                None
+            },
+            max_pixel_size: self.file_options.max_pixel_size,
+            quality: self.file_options.jpg_quality,
+        })
+    }
+
+    /// Get the reason a 16-bit FLAC target should be re-encoded rather than copied.
+    ///
+    /// Sources of other bit depths are always copied.
+    fn get_reencode_reason(
+        &self,
+        flac: &FlacFile,
+        info: &StreamInfo,
+    ) -> Result<Option<ReencodeReason>, Failure<TranscodeAction>> {
+        if info.bits_per_sample != 16 {
+            return Ok(None);
+        }
+        get_reencode_reason(&flac.path, info, self.target_options.reencode_flac)
+    }
+
+    /// Check if the FLAC has more than two channels and must be downmixed before encoding.
+    ///
+    /// - Only if `downmix` is set, otherwise the source is blocked by verify
+    /// - FLAC output keeps every channel so only MP3 targets are downmixed
+    /// - A pre-emphasized multichannel source is de-emphasized before it's downmixed
+    fn is_downmix_required(&self, format: TargetFormat, info: &StreamInfo) -> bool {
+        self.target_options.downmix && format != TargetFormat::Flac && info.channels > 2
+    }
+
+    /// Get the backend to resample and de-emphasize with.
+    ///
+    /// `SoX` can only process a whole file so a track of a FLAC image is processed with the
+    /// [`ResampleBackend::Native`] backend regardless of `resample_backend`.
+    fn get_backend(
+        &self,
+        flac: &FlacFile,
+        range: Option<SampleRange>,
+        info: &StreamInfo,
+    ) -> ResampleBackend {
+        let backend = self.target_options.resample_backend;
+        if backend != ResampleBackend::Sox || range.is_none() {
+            return backend;
+        }
+        if is_resample_required(info) || flac.pre_emphasis {
+            debug!(
+                "Using the native backend for {} as SoX can't process a track of a FLAC image",
+                flac.get_track_path().display()
+            );
+        }
+        ResampleBackend::Native
+    }
+
+    /// Check if the FLAC is pre-emphasized and must be de-emphasized before encoding.
+    ///
+    /// - Only if `pre_emphasis` is `deemphasize`, otherwise the source is blocked by verify
+    /// - FLAC targets are only de-emphasized if `deemphasize_flac` is set, otherwise the source
+    ///   is copied with its pre-emphasis flag
+    /// - De-emphasis only applies to 16-bit 44.1 kHz CD audio so it is skipped for any other
+    ///   source
+    fn is_deemphasis_required(
+        &self,
+        flac: &FlacFile,
+        source: &Source,
+        format: TargetFormat,
+        info: &StreamInfo,
+    ) -> bool {
+        if self.target_options.pre_emphasis != PreEmphasisPolicy::Deemphasize || !flac.pre_emphasis
+        {
+            return false;
+        }
+        if format == TargetFormat::Flac && !self.target_options.deemphasize_flac {
+            return false;
+        }
+        if source.metadata.media != Media::CD
+            || info.sample_rate != CD_SAMPLE_RATE
+            || info.bits_per_sample != 16
+        {
+            warn!(
+                "Skipping de-emphasis of {} as it is not 16-bit 44.1 kHz CD audio",
+                flac.path.display()
+            );
+            return false;
+        }
+        trace!("{} {}", "De-emphasizing".bold(), flac.path.display());
+        true
+    }
+}
+
+/// Get the sample rate to resample to before encoding, or `None` if no resample is required.
+fn get_encode_resample_rate(
+    flac: &FlacFile,
+    info: &StreamInfo,
+) -> Result<Option<u32>, Failure<TranscodeAction>> {
+    is_resample_required(info)
+        .then(|| get_resample_rate(info))
+        .transpose()
+        .map_err(Failure::wrap_with_path(
+            TranscodeAction::GetSampleRate,
+            &flac.path,
+        ))
 }
@@ -0,0 +1,229 @@
+use crate::prelude::*;
+use claxon::FlacReader;
+use claxon::metadata::StreamInfo;
+use lofty::config::ParseOptions;
+use lofty::file::AudioFile;
+use lofty::id3::v2::Id3v2Tag;
+use lofty::mpeg::MpegFile;
+
+/// Maximum difference between the duration of the source and the transcoded output.
+///
+/// `lame` pads the stream with encoder delay and a final partial frame, and counts the Xing
+/// frame, which adds up to 80 ms at 44.1 kHz.
+const DURATION_TOLERANCE: Duration = Duration::from_millis(150);
+
+/// Validate transcoded outputs against their source.
+#[injectable]
+pub(crate) struct TranscodeValidator {
+    paths: Ref<PathManager>,
+    target_options: Ref<TargetOptions>,
+    id3_options: Ref<Id3Options>,
+    file_options: Ref<FileOptions>,
+}
+
+impl TranscodeValidator {
+    /// Re-open the output of every FLAC for each target and validate it.
+    ///
+    /// Catches bad encodes that exit successfully:
+    /// - Duration must match the source within [`DURATION_TOLERANCE`]
+    /// - Sample rate and bit depth must match the resample decision of [`get_resample_rate`]
+    /// - MP3 bitrate mode must match the [`TargetFormat`]
+    /// - MP3 LAME tag must have a valid CRC and the preset of the [`TargetFormat`]
+    /// - MP3 `ID3v2` tags written by [`save_id3v2_deterministic`] must be present
+    ///
+    /// Returns the first mismatch as [`TranscodeError::InvalidOutput`].
+    pub(crate) fn execute(
+        &self,
+        source: &Source,
+        flacs: &[FlacFile],
+        targets: &BTreeSet<TargetFormat>,
+    ) -> Result<(), Failure<TranscodeAction>> {
+        debug!("{} transcodes {}", "Validating".bold(), source);
+        for target in targets {
+            for flac in flacs {
+                let output = self.paths.get_transcode_path(source, *target, flac);
+                self.validate(flac, *target, &output)?;
+            }
+        }
+        debug!("{} transcodes {}", "Validated".bold(), source);
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        flac: &FlacFile,
+        target: TargetFormat,
+        output: &Path,
+    ) -> Result<(), Failure<TranscodeAction>> {
+        trace!("{} {}", "Validating".bold(), output.display());
+        let info = flac.get_stream_info().map_err(Failure::wrap_with_path(
+            TranscodeAction::ReadFlac,
+            &flac.path,
+        ))?;
+        let expected = ExpectedOutput::new(&info).map_err(Failure::wrap_with_path(
+            TranscodeAction::GetSampleRate,
+            &flac.path,
+        ))?;
+        match target {
+            TargetFormat::Flac => validate_flac(&expected, output),
+            TargetFormat::_320 | TargetFormat::V0 => {
+                let mut tags = map_id3_tags(flac, &self.id3_options)?;
+                exclude_tags(
+                    &mut tags,
+                    &vorbis_keys(&self.target_options.exclude_vorbis_comments),
+                );
+                if self.file_options.artwork != ArtworkPolicy::Keep {
+                    replace_id3v2_pictures(&mut tags, Vec::new());
+                }
+                validate_mp3(&expected, target, &tags, output)
+            }
+        }
+    }
+}
+
+/// Properties a transcode of a source is expected to have.
+struct ExpectedOutput {
+    duration: Option<Duration>,
+    sample_rate: u32,
+    bit_depth: u32,
+}
+
+impl ExpectedOutput {
+    fn new(info: &StreamInfo) -> Result<Self, TranscodeError> {
+        let (sample_rate, bit_depth) = if is_resample_required(info) {
+            (get_resample_rate(info)?, 16)
+        } else {
+            (info.sample_rate, info.bits_per_sample)
+        };
+        Ok(Self {
+            duration: get_exact_duration(info),
+            sample_rate,
+            bit_depth,
+        })
+    }
+}
+
+fn validate_flac(expected: &ExpectedOutput, output: &Path) -> Result<(), Failure<TranscodeAction>> {
+    let info = FlacReader::open(output)
+        .map_err(Failure::wrap_with_path(TranscodeAction::Validate, output))?
+        .streaminfo();
+    check_duration(expected, get_exact_duration(&info), output)?;
+    check_equal(
+        OutputMismatch::SampleRate,
+        expected.sample_rate,
+        info.sample_rate,
+        output,
+    )?;
+    check_equal(
+        OutputMismatch::BitDepth,
+        expected.bit_depth,
+        info.bits_per_sample,
+        output,
+    )
+}
+
+fn validate_mp3(
+    expected: &ExpectedOutput,
+    target: TargetFormat,
+    tags: &Id3v2Tag,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    let mut file =
+        File::open(output).map_err(Failure::wrap_with_path(TranscodeAction::Validate, output))?;
+    let mpeg = MpegFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TranscodeAction::Validate, output))?;
+    let properties = mpeg.properties();
+    check_duration(expected, Some(properties.duration()), output)?;
+    check_equal(
+        OutputMismatch::SampleRate,
+        expected.sample_rate,
+        properties.sample_rate(),
+        output,
+    )?;
+    let expected_mode = match target {
+        TargetFormat::V0 => BitrateMode::Variable,
+        TargetFormat::_320 | TargetFormat::Flac => BitrateMode::Constant,
+    };
+    let header = XingHeader::read(output)
+        .map_err(Failure::wrap_with_path(TranscodeAction::Validate, output))?;
+    let actual_mode = header
+        .as_ref()
+        .map_or_else(|| "unknown".to_owned(), |header| header.mode.to_string());
+    check_equal(
+        OutputMismatch::BitrateMode,
+        expected_mode.to_string(),
+        actual_mode,
+        output,
+    )?;
+    check_lame_tag(target, header.and_then(|header| header.lame), output)?;
+    let empty = Id3v2Tag::new();
+    let missing = get_missing_id3v2_frames(tags, mpeg.id3v2().unwrap_or(&empty));
+    if !missing.is_empty() {
+        return Err(mismatch(OutputMismatch::Tags, output).with("frames", missing.join(", ")));
+    }
+    Ok(())
+}
+
+fn check_lame_tag(
+    target: TargetFormat,
+    lame: Option<LameTag>,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    let Some(lame) = lame else {
+        return Err(mismatch(OutputMismatch::LameTag, output).with("reason", "missing"));
+    };
+    if !lame.is_tag_crc_valid {
+        return Err(mismatch(OutputMismatch::LameTag, output).with("reason", "invalid CRC"));
+    }
+    let Some(expected) = LameTag::get_expected_preset(target) else {
+        return Ok(());
+    };
+    check_equal(OutputMismatch::LameTag, expected, lame.preset, output)
+}
+
+fn check_duration(
+    expected: &ExpectedOutput,
+    actual: Option<Duration>,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    let (Some(expected), Some(actual)) = (expected.duration, actual) else {
+        return Ok(());
+    };
+    if expected.abs_diff(actual) > DURATION_TOLERANCE {
+        return Err(mismatch(OutputMismatch::Duration, output)
+            .with("expected", format!("{expected:?}"))
+            .with("actual", format!("{actual:?}")));
+    }
+    Ok(())
+}
+
+fn check_equal<T: Display + PartialEq>(
+    kind: OutputMismatch,
+    expected: T,
+    actual: T,
+    output: &Path,
+) -> Result<(), Failure<TranscodeAction>> {
+    if expected != actual {
+        return Err(mismatch(kind, output)
+            .with("expected", expected.to_string())
+            .with("actual", actual.to_string()));
+    }
+    Ok(())
+}
+
+fn mismatch(kind: OutputMismatch, output: &Path) -> Failure<TranscodeAction> {
+    Failure::new(
+        TranscodeAction::Validate,
+        TranscodeError::InvalidOutput(kind),
+    )
+    .with_path(output)
+}
+
+/// Get the duration of a FLAC from the number of samples without rounding.
+fn get_exact_duration(info: &StreamInfo) -> Option<Duration> {
+    let samples = info.samples?;
+    let nanos = (u128::from(samples) * 1_000_000_000).checked_div(u128::from(info.sample_rate))?;
+    Some(Duration::from_nanos(
+        u64::try_from(nanos).expect("duration should fit in u64"),
+    ))
+}
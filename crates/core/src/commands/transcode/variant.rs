@@ -6,6 +6,14 @@ pub enum Variant {
     Transcode(Decode, Encode),
     /// Resample high-resolution FLAC to 16-bit.
     Resample(Resample),
+    /// De-emphasize a pre-emphasized FLAC then encode to the target format.
+    Deemphasize(Deemphasize, Encode),
+    /// Downmix a multichannel FLAC to stereo then encode to MP3.
+    Downmix(Downmix, Encode),
+    /// Losslessly re-encode a 16-bit FLAC that needs no other conversion.
+    Reencode(Reencode),
     /// Copy or hard-link FLAC that needs no conversion.
     Include(Include),
+    /// Split a track from a FLAC image that needs no other conversion.
+    Split(Split),
 }
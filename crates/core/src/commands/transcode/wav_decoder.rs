@@ -0,0 +1,165 @@
+use crate::prelude::*;
+use tokio::sync::mpsc::Sender;
+
+/// Bit depth of the decoded PCM stream.
+const WAV_BITS_PER_SAMPLE: u32 = 16;
+
+/// Size in bytes of a canonical PCM WAV header.
+const WAV_HEADER_SIZE: u32 = 44;
+
+/// Decode a FLAC to 16-bit PCM WAV in process with `claxon`.
+///
+/// - Sends the WAV header followed by one chunk of interleaved samples per FLAC frame
+/// - Stops early without error if the receiver is dropped, as the encoder failure is reported
+///   by the caller
+/// - Decode errors are reported with the number of samples per channel decoded before the failure
+/// - Sources deeper than 16-bit must be resampled instead
+pub(crate) fn decode_flac_to_wav(
+    path: &Path,
+    range: Option<SampleRange>,
+    sender: &Sender<Vec<u8>>,
+) -> Result<(), Failure<TranscodeAction>> {
+    let mut decoder = FlacDecoder::open(path)?.with_range(range);
+    let info = decoder.stream_info();
+    if info.bits_per_sample > WAV_BITS_PER_SAMPLE {
+        return Err(Failure::new(
+            TranscodeAction::Decode,
+            TranscodeError::UnsupportedBitDepth(info.bits_per_sample),
+        )
+        .with_path(path));
+    }
+    let header = wav_header(info.channels, info.sample_rate, info.samples);
+    if sender.blocking_send(header).is_err() {
+        return Ok(());
+    }
+    let shift = WAV_BITS_PER_SAMPLE - info.bits_per_sample;
+    decoder.for_each_block(|block, indexes| {
+        let length = usize::try_from(block.len()).expect("block length should fit in usize");
+        let mut chunk = Vec::with_capacity(length * 2);
+        for index in indexes {
+            for channel in 0..block.channels() {
+                let sample = i16::try_from(block.sample(channel, index) << shift)
+                    .expect("sample should fit in 16 bits");
+                chunk.extend_from_slice(&sample.to_le_bytes());
+            }
+        }
+        sender.blocking_send(chunk).is_ok()
+    })
+}
+
+/// Decode, resample and dither a FLAC to 16-bit PCM WAV in process.
+///
+/// Refer to [`PcmPipeline`] for details of the resampling.
+pub(crate) fn resample_flac_to_wav(
+    path: &Path,
+    range: Option<SampleRange>,
+    resample_rate: u32,
+    repeatable: bool,
+    sender: &Sender<Vec<u8>>,
+) -> Result<(), Failure<TranscodeAction>> {
+    let pipeline = PcmPipeline {
+        path: path.to_path_buf(),
+        range,
+        resample_rate: Some(resample_rate),
+        deemphasize: false,
+        downmix: false,
+        repeatable,
+    };
+    send_pipeline(&pipeline, sender)
+}
+
+/// Decode, de-emphasize and dither a FLAC to 16-bit PCM WAV in process.
+///
+/// Refer to [`PcmPipeline`] for details of the de-emphasis.
+pub(crate) fn deemphasize_flac_to_wav(
+    path: &Path,
+    range: Option<SampleRange>,
+    repeatable: bool,
+    sender: &Sender<Vec<u8>>,
+) -> Result<(), Failure<TranscodeAction>> {
+    let pipeline = PcmPipeline {
+        path: path.to_path_buf(),
+        range,
+        resample_rate: None,
+        deemphasize: true,
+        downmix: false,
+        repeatable,
+    };
+    send_pipeline(&pipeline, sender)
+}
+
+/// Decode, downmix and dither a multichannel FLAC to 16-bit stereo PCM WAV in process.
+///
+/// Refer to [`PcmPipeline`] for details of the de-emphasis and downmix.
+pub(crate) fn downmix_flac_to_wav(
+    path: &Path,
+    range: Option<SampleRange>,
+    resample_rate: Option<u32>,
+    deemphasize: bool,
+    repeatable: bool,
+    sender: &Sender<Vec<u8>>,
+) -> Result<(), Failure<TranscodeAction>> {
+    let pipeline = PcmPipeline {
+        path: path.to_path_buf(),
+        range,
+        resample_rate,
+        deemphasize,
+        downmix: true,
+        repeatable,
+    };
+    send_pipeline(&pipeline, sender)
+}
+
+/// Run both passes of a [`PcmPipeline`] and send a WAV header followed by chunks of the output,
+/// stopping early if the receiver is dropped.
+fn send_pipeline(
+    pipeline: &PcmPipeline,
+    sender: &Sender<Vec<u8>>,
+) -> Result<(), Failure<TranscodeAction>> {
+    let measurement = pipeline.measure()?;
+    let header = wav_header(
+        measurement.channels,
+        measurement.sample_rate,
+        Some(measurement.frames),
+    );
+    if sender.blocking_send(header).is_err() {
+        return Ok(());
+    }
+    pipeline.quantize(&measurement, |samples| {
+        let chunk = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        sender.blocking_send(chunk).is_ok()
+    })
+}
+
+/// Create a canonical 44 byte PCM WAV header for 16-bit samples.
+///
+/// If the total number of `samples` per channel is unknown, or the data exceeds the 4 GiB limit
+/// of WAV, the sizes are set to their maximum so the encoder reads until the end of the stream.
+#[must_use]
+pub(crate) fn wav_header(channels: u32, sample_rate: u32, samples: Option<u64>) -> Vec<u8> {
+    let channels = u16::try_from(channels).expect("FLAC should have at most 8 channels");
+    let block_align = channels * 2;
+    let byte_rate = sample_rate * u32::from(block_align);
+    let data_size = samples
+        .and_then(|samples| u32::try_from(samples * u64::from(block_align)).ok())
+        .filter(|size| *size <= u32::MAX - WAV_HEADER_SIZE)
+        .unwrap_or(u32::MAX - WAV_HEADER_SIZE);
+    let mut header = Vec::new();
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&(data_size + WAV_HEADER_SIZE - 8).to_le_bytes());
+    header.extend_from_slice(b"WAVE");
+    header.extend_from_slice(b"fmt ");
+    header.extend_from_slice(&16_u32.to_le_bytes());
+    header.extend_from_slice(&1_u16.to_le_bytes());
+    header.extend_from_slice(&channels.to_le_bytes());
+    header.extend_from_slice(&sample_rate.to_le_bytes());
+    header.extend_from_slice(&byte_rate.to_le_bytes());
+    header.extend_from_slice(&block_align.to_le_bytes());
+    header.extend_from_slice(&16_u16.to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data_size.to_le_bytes());
+    header
+}
@@ -251,7 +251,7 @@ impl UploadCommand {
 
     /// Collect unique transcode commands for a source and target format.
     pub(crate) fn get_commands(&self, source: &Source, target: TargetFormat) -> HashSet<String> {
-        let flacs = Collector::get_flacs(&source.directory);
+        let flacs = Collector::get_tracks(&source.directory);
         flacs
             .into_iter()
             .filter_map(|flac| {
@@ -272,7 +272,7 @@ impl UploadCommand {
     ) -> Result<Option<String>, Failure<UploadAction>> {
         let job = self
             .transcode_job_factory
-            .create_single(0, &flac, source, target)
+            .create_single(0, &flac, source, target, None)
             .map_err(Failure::wrap(UploadAction::GetTranscodeCommand))?;
         let Job::Transcode(job) = job else {
             unreachable!("TranscodeJobFactory::create_single always returns Job::Transcode")
@@ -280,30 +280,53 @@ impl UploadCommand {
         let command = match job.variant {
             Variant::Transcode(mut decode, mut encode) => {
                 decode.input = PathBuf::from("input.flac");
-                let extension = encode
-                    .output
-                    .extension()
-                    .expect("output should have an extension")
-                    .to_string_lossy();
-                encode.output = PathBuf::from(format!("output.{extension}"));
+                encode.output = get_display_output(&encode.output);
                 Some(format!(
                     "{} | {}",
-                    decode.to_info().display(),
+                    decode.display(),
                     encode.to_info().display()
                 ))
             }
             Variant::Resample(mut resample) => {
                 resample.input = PathBuf::from("input.flac");
-                let extension = resample
-                    .output
-                    .extension()
-                    .expect("output should have an extension")
-                    .to_string_lossy();
-                resample.output = PathBuf::from(format!("output.{extension}"));
-                Some(resample.to_info().display())
+                resample.output = get_display_output(&resample.output);
+                Some(resample.display())
+            }
+            Variant::Deemphasize(mut deemphasize, mut encode) => {
+                deemphasize.input = PathBuf::from("input.flac");
+                encode.output = get_display_output(&encode.output);
+                Some(deemphasize.display(encode))
+            }
+            Variant::Downmix(mut downmix, mut encode) => {
+                downmix.input = PathBuf::from("input.flac");
+                encode.output = get_display_output(&encode.output);
+                Some(format!(
+                    "{} | {}",
+                    downmix.display(),
+                    encode.to_info().display()
+                ))
+            }
+            Variant::Reencode(mut reencode) => {
+                reencode.input = PathBuf::from("input.flac");
+                reencode.output = PathBuf::from("output.flac");
+                Some(reencode.display())
+            }
+            Variant::Split(mut split) => {
+                split.input = PathBuf::from("input.flac");
+                split.output = PathBuf::from("output.flac");
+                Some(split.display())
             }
             Variant::Include(_) => None,
         };
         Ok(command)
     }
 }
+
+/// Get a generic output path with the same extension as `output` for display.
+fn get_display_output(output: &Path) -> PathBuf {
+    let extension = output
+        .extension()
+        .expect("output should have an extension")
+        .to_string_lossy();
+    PathBuf::from(format!("output.{extension}"))
+}
@@ -0,0 +1,232 @@
+use crate::prelude::*;
+use lofty::prelude::Accessor;
+use lofty::tag::{ItemKey, Tag};
+
+/// Verify the tags and stream info of a [`Source`]'s FLAC files are consistent across the album.
+#[injectable]
+pub(crate) struct AlbumVerifier {
+    verify_options: Ref<VerifyOptions>,
+}
+
+impl AlbumVerifier {
+    /// Compare every FLAC of the source and return a [`SourceIssue`] for each inconsistency.
+    ///
+    /// - Skips entirely when `no_album_check` is set
+    /// - FLACs without Vorbis comments are skipped as they are reported by [`TagVerifier`]
+    pub(crate) fn execute(&self, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+        if self.verify_options.no_album_check {
+            debug!("{} album check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        trace!(
+            "{} album consistency of {} FLACs",
+            "Checking".bold(),
+            flacs.len()
+        );
+        check_album(flacs)
+    }
+}
+
+/// Tags of a FLAC that must be consistent across the album.
+struct AlbumTrack<'a> {
+    flac: &'a FlacFile,
+    album: Option<String>,
+    album_artist: Option<String>,
+    date: Option<String>,
+    disc: Option<u32>,
+    disc_total: Option<u32>,
+    track: Option<u32>,
+    track_total: Option<u32>,
+}
+
+impl<'a> AlbumTrack<'a> {
+    fn new(flac: &'a FlacFile, tags: &Tag) -> Self {
+        let mut tags = tags.clone();
+        fix_track_numbering(&mut tags);
+        let disc = tags.get_string(ItemKey::DiscNumber).and_then(parse_number);
+        Self {
+            flac,
+            album: get_text(&tags, ItemKey::AlbumTitle),
+            album_artist: get_text(&tags, ItemKey::AlbumArtist),
+            date: get_text(&tags, ItemKey::RecordingDate),
+            disc: tags.disk().or(disc.map(|(disc, _)| disc)),
+            disc_total: tags.disk_total().or(disc.and_then(|(_, total)| total)),
+            track: tags.track(),
+            track_total: tags.track_total(),
+        }
+    }
+
+    /// Disc number, or if there is no tag the position of the FLAC's directory in `dirs`.
+    ///
+    /// A multi-disc source in `CD1` and `CD2` directories without disc tags is grouped by
+    /// directory, and a single directory is disc 1.
+    fn get_disc(&self, dirs: &BTreeSet<&Path>) -> u32 {
+        self.disc.unwrap_or_else(|| {
+            let index = dirs
+                .iter()
+                .position(|dir| *dir == self.flac.sub_dir)
+                .unwrap_or_default();
+            u32::try_from(index + 1).unwrap_or(1)
+        })
+    }
+}
+
+/// Check the FLACs of a source are consistent with each other.
+///
+/// - Returns [`SourceIssue::InconsistentTags`] if album, album artist or date differ
+/// - FLACs are grouped by disc number, or by directory if they have no disc tag
+/// - Returns [`SourceIssue::DuplicateTrackNumber`] for each track number on more than one FLAC
+///   of a disc
+/// - Returns [`SourceIssue::MissingTrackNumbers`] for each disc with gaps in its track numbers
+/// - Returns [`SourceIssue::TrackTotal`] and [`SourceIssue::DiscTotal`] if the totals differ
+///   from the number of FLACs and discs
+/// - Returns [`SourceIssue::MissingDiscs`] if disc numbers below the highest have no FLACs
+/// - Returns [`SourceIssue::MixedFormat`] if bit depth or sample rate differ
+pub(crate) fn check_album(flacs: &[FlacFile]) -> Vec<SourceIssue> {
+    let tracks: Vec<AlbumTrack> = flacs
+        .iter()
+        .filter_map(|flac| Some(AlbumTrack::new(flac, flac.vorbis_tags().ok()?)))
+        .collect();
+    let mut issues = Vec::new();
+    issues.extend(check_consistent("album", tracks.iter().map(|x| &x.album)));
+    issues.extend(check_consistent(
+        "album artist",
+        tracks.iter().map(|x| &x.album_artist),
+    ));
+    issues.extend(check_consistent("date", tracks.iter().map(|x| &x.date)));
+    let dirs: BTreeSet<&Path> = tracks
+        .iter()
+        .map(|track| track.flac.sub_dir.as_path())
+        .collect();
+    let mut discs: BTreeMap<u32, Vec<&AlbumTrack>> = BTreeMap::new();
+    for track in &tracks {
+        discs.entry(track.get_disc(&dirs)).or_default().push(track);
+    }
+    for (disc, tracks) in &discs {
+        issues.append(&mut check_track_numbers(*disc, tracks));
+    }
+    issues.extend(check_disc_total(&tracks, discs.len()));
+    issues.extend(check_discs(&discs));
+    issues.append(&mut check_stream_info(flacs));
+    issues
+}
+
+/// Check a tag has the same value on every FLAC that has it.
+fn check_consistent<'a>(
+    tag: &str,
+    values: impl Iterator<Item = &'a Option<String>>,
+) -> Option<SourceIssue> {
+    let values: BTreeSet<&String> = values.flatten().collect();
+    (values.len() > 1).then(|| SourceIssue::InconsistentTags {
+        tag: tag.to_owned(),
+        values: values.into_iter().cloned().collect(),
+    })
+}
+
+/// Check the track numbers of a disc are unique, without gaps, and match the track total.
+fn check_track_numbers(disc: u32, tracks: &[&AlbumTrack]) -> Vec<SourceIssue> {
+    let mut issues = Vec::new();
+    let mut numbers: BTreeMap<u32, Vec<PathBuf>> = BTreeMap::new();
+    for track in tracks {
+        if let Some(number) = track.track {
+            numbers
+                .entry(number)
+                .or_default()
+                .push(track.flac.path.clone());
+        }
+    }
+    for (track, paths) in &numbers {
+        if paths.len() > 1 {
+            issues.push(SourceIssue::DuplicateTrackNumber {
+                disc,
+                track: *track,
+                paths: paths.clone(),
+            });
+        }
+    }
+    if let Some(max) = numbers.keys().max() {
+        let missing: Vec<u32> = (1..*max)
+            .filter(|number| !numbers.contains_key(number))
+            .collect();
+        if !missing.is_empty() {
+            issues.push(SourceIssue::MissingTrackNumbers {
+                disc,
+                tracks: missing,
+            });
+        }
+    }
+    let totals: BTreeSet<u32> = tracks
+        .iter()
+        .filter_map(|track| track.track_total)
+        .collect();
+    for total in totals {
+        if usize::try_from(total).ok() != Some(tracks.len()) {
+            issues.push(SourceIssue::TrackTotal {
+                disc,
+                total,
+                actual: tracks.len(),
+            });
+        }
+    }
+    issues
+}
+
+/// Check the disc total matches the number of discs with FLACs.
+fn check_disc_total(tracks: &[AlbumTrack], actual: usize) -> Vec<SourceIssue> {
+    let totals: BTreeSet<u32> = tracks.iter().filter_map(|track| track.disc_total).collect();
+    totals
+        .into_iter()
+        .filter(|total| usize::try_from(*total).ok() != Some(actual))
+        .map(|total| SourceIssue::DiscTotal { total, actual })
+        .collect()
+}
+
+/// Check every disc below the highest disc number has FLACs.
+fn check_discs(discs: &BTreeMap<u32, Vec<&AlbumTrack>>) -> Option<SourceIssue> {
+    let disc_count = discs.keys().max().copied().unwrap_or_default();
+    let missing: Vec<u32> = (1..=disc_count)
+        .filter(|disc| !discs.contains_key(disc))
+        .collect();
+    (!missing.is_empty()).then_some(SourceIssue::MissingDiscs {
+        discs: missing,
+        disc_count,
+    })
+}
+
+/// Check every FLAC has the same bit depth and sample rate.
+fn check_stream_info(flacs: &[FlacFile]) -> Vec<SourceIssue> {
+    let infos: Vec<_> = flacs
+        .iter()
+        .filter_map(|flac| flac.get_stream_info().ok())
+        .collect();
+    let mut issues = Vec::new();
+    let bit_depths: BTreeSet<u32> = infos.iter().map(|info| info.bits_per_sample).collect();
+    if bit_depths.len() > 1 {
+        issues.push(SourceIssue::MixedFormat {
+            property: "bit depth".to_owned(),
+            values: bit_depths.into_iter().collect(),
+        });
+    }
+    let sample_rates: BTreeSet<u32> = infos.iter().map(|info| info.sample_rate).collect();
+    if sample_rates.len() > 1 {
+        issues.push(SourceIssue::MixedFormat {
+            property: "sample rate".to_owned(),
+            values: sample_rates.into_iter().collect(),
+        });
+    }
+    issues
+}
+
+/// Get the trimmed text of a tag, or `None` if it is empty.
+fn get_text(tags: &Tag, key: ItemKey) -> Option<String> {
+    let value = tags.get_string(key)?.trim();
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+/// Parse a number that may have a total such as `1/2`.
+fn parse_number(value: &str) -> Option<(u32, Option<u32>)> {
+    if let Some((number, total)) = get_numeric_from_slash_total_format(value.trim()) {
+        return Some((number, Some(total)));
+    }
+    Some((value.trim().parse().ok()?, None))
+}
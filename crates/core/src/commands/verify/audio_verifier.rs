@@ -0,0 +1,296 @@
+use crate::prelude::*;
+use claxon::Error as ClaxonError;
+use claxon::FlacReader;
+use futures::StreamExt;
+use futures::stream;
+
+/// Bit depth of CD audio.
+const CD_BIT_DEPTH: u32 = 16;
+
+/// Sample rate of CD audio.
+const CD_SAMPLE_RATE: u32 = 44_100;
+
+/// Verify the decoded audio of FLAC files.
+///
+/// Each FLAC is decoded once and the samples are passed to every enabled analysis rather than
+/// decoding it again for each [`VerifyCheck`].
+#[injectable]
+pub(crate) struct AudioVerifier {
+    verify_options: Ref<VerifyOptions>,
+    runner_options: Ref<RunnerOptions>,
+}
+
+/// Analyses of the decoded audio to run on each FLAC.
+#[derive(Clone, Copy, Debug, Default)]
+#[expect(clippy::struct_excessive_bools, reason = "one flag per check")]
+pub(crate) struct AudioChecks {
+    /// Report decode errors, and checksums that differ from the rip log.
+    pub decode: bool,
+    /// Report hi-res FLACs that were mastered at CD quality.
+    pub hi_res: bool,
+    /// Report FLACs with the lowpass shelf of a lossy encoder.
+    pub lossy_master: bool,
+    /// Report FLACs with an MQA side channel.
+    pub mqa: bool,
+}
+
+/// Issues found in the decoded audio, grouped by [`VerifyCheck`].
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct AudioIssues {
+    /// Issues of [`VerifyCheck::Decode`].
+    pub decode: Vec<SourceIssue>,
+    /// Issues of [`VerifyCheck::HiRes`].
+    pub hi_res: Vec<SourceIssue>,
+    /// Issues of [`VerifyCheck::LossyMaster`].
+    pub lossy_master: Vec<SourceIssue>,
+    /// Issues of [`VerifyCheck::Mqa`].
+    pub mqa: Vec<SourceIssue>,
+}
+
+impl AudioVerifier {
+    /// Decode every FLAC concurrently and return the [`SourceIssue`] of each analysis.
+    ///
+    /// - Each analysis is skipped when its `no_*_check` option is set, and decoding is skipped
+    ///   entirely if every analysis is
+    /// - FLACs of a CD source that match a track of an EAC or XLD log are checked against the
+    ///   CRC32 and `AccurateRip` checksums of the log, unless `no_log_check` is set
+    /// - Runs up to `cpus` decodes at once on the blocking pool
+    /// - Preserves input order via `buffered`, so issues need no sort
+    /// - Cost scales with audio length as every frame is decoded, comparable to `flac --test`
+    /// - Runs roughly 20x slower in debug builds than release due to unoptimized `claxon` decoding
+    pub(crate) async fn execute(&self, source: &Source, flacs: &[FlacFile]) -> AudioIssues {
+        let checks = AudioChecks::from_options(&self.verify_options);
+        if !checks.any() {
+            debug!("{} audio checks due to settings", "Skipped".bold());
+            return AudioIssues::default();
+        }
+        trace!("{} audio of {} FLACs", "Checking".bold(), flacs.len());
+        let cpus = self.runner_options.get_cpus();
+        let start = Instant::now();
+        let mut checksums = self.get_log_checksums(source, flacs);
+        let paths = flacs
+            .iter()
+            .map(|flac| (flac.path.clone(), checksums.remove(&flac.path)));
+        let issues = stream::iter(paths)
+            .map(|(path, checksums)| async move {
+                spawn_blocking(move || check_audio(&path, checksums.as_ref(), checks))
+                    .await
+                    .expect("audio task should not panic")
+            })
+            .buffered(cpus)
+            .fold(AudioIssues::default(), |mut issues, file| async move {
+                issues.extend(file);
+                issues
+            })
+            .await;
+        trace!(
+            "{} audio of {} FLACs in {:.3}s",
+            "Checked".bold(),
+            flacs.len(),
+            start.elapsed().as_secs_f64()
+        );
+        issues
+    }
+
+    /// Read the rip logs of a CD source and match each track to a FLAC.
+    fn get_log_checksums(
+        &self,
+        source: &Source,
+        flacs: &[FlacFile],
+    ) -> HashMap<PathBuf, LogChecksums> {
+        if self.verify_options.no_decode_check
+            || self.verify_options.no_log_check
+            || source.metadata.media != Media::CD
+        {
+            return HashMap::new();
+        }
+        let paths = match RipLog::find(&source.directory) {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Failed to read logs of {source}: {error}");
+                return HashMap::new();
+            }
+        };
+        let mut checksums = HashMap::new();
+        for path in paths {
+            match RipLog::read(&path) {
+                Ok(Some(log)) => checksums.extend(LogChecksums::from_log(&path, &log, flacs)),
+                Ok(None) => {}
+                Err(error) => warn!("Failed to read log {}: {error}", path.display()),
+            }
+        }
+        trace!(
+            "{} {} FLACs to rip log checksums",
+            "Matched".bold(),
+            checksums.len()
+        );
+        checksums
+    }
+}
+
+impl AudioChecks {
+    /// Get the [`AudioChecks`] enabled by [`VerifyOptions`].
+    #[must_use]
+    pub(crate) fn from_options(options: &VerifyOptions) -> Self {
+        Self {
+            decode: !options.no_decode_check,
+            hi_res: !options.no_hi_res_check,
+            lossy_master: !options.no_lossy_master_check,
+            mqa: !options.no_mqa_check,
+        }
+    }
+
+    /// Is any analysis enabled?
+    #[must_use]
+    pub(crate) fn any(self) -> bool {
+        self.decode || self.hi_res || self.lossy_master || self.mqa
+    }
+}
+
+impl AudioIssues {
+    fn extend(&mut self, other: AudioIssues) {
+        self.decode.extend(other.decode);
+        self.hi_res.extend(other.hi_res);
+        self.lossy_master.extend(other.lossy_master);
+        self.mqa.extend(other.mqa);
+    }
+}
+
+/// Decode a single FLAC by path and run each of `checks` on the samples.
+///
+/// - A decode error is reported by the decode check, and the other analyses are discarded as
+///   they are inconclusive
+/// - Checksums are only calculated for 16-bit 44.1 kHz stereo FLACs
+/// - Decoding stops early once the remaining analyses are complete
+pub(crate) fn check_audio(
+    path: &Path,
+    checksums: Option<&LogChecksums>,
+    checks: AudioChecks,
+) -> AudioIssues {
+    let mut analysis = AudioAnalysis::default();
+    let result = decode(path, checksums, checks, &mut analysis);
+    let mut issues = AudioIssues::default();
+    if let Some(mqa) = analysis.mqa {
+        issues.mqa.extend(mqa.get_issue(path));
+    }
+    match result {
+        Ok(actual) => {
+            if let (Some(checksums), Some(actual)) = (checksums, actual) {
+                issues.decode.extend(checksums.compare(path, &actual));
+            }
+            if let Some(hi_res) = analysis.hi_res {
+                issues.hi_res = hi_res.get_issues(path);
+            }
+            if let Some(lossy_master) = analysis.lossy_master {
+                issues.lossy_master.extend(lossy_master.get_issue(path));
+            }
+        }
+        Err(error) => {
+            trace!("Skipping audio analysis of {}: {error}", path.display());
+            if checks.decode {
+                issues.decode.push(SourceIssue::DecodeError {
+                    path: path.to_path_buf(),
+                    error: format!("{error}"),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Analyses of a single FLAC, or `None` if skipped.
+#[derive(Default)]
+struct AudioAnalysis {
+    hi_res: Option<HiResAnalysis>,
+    lossy_master: Option<LossyMasterAnalysis>,
+    mqa: Option<MqaAnalysis>,
+}
+
+impl AudioAnalysis {
+    /// Are there no analyses left that require more frames?
+    fn is_complete(&self) -> bool {
+        self.hi_res.is_none()
+            && self.lossy_master.is_none()
+            && self.mqa.as_ref().is_none_or(MqaAnalysis::is_complete)
+    }
+}
+
+/// Decode the audio frames of a FLAC file, passing each frame to `analysis`.
+///
+/// - Validates each frame header CRC-8 and footer CRC-16 in process
+/// - Surfaces truncation as a mid-frame decode error
+/// - Reuses a single sample buffer across frames
+/// - Calculates [`RipChecksums`] if `checksums` is set and the FLAC is CD audio
+fn decode(
+    path: &Path,
+    checksums: Option<&LogChecksums>,
+    checks: AudioChecks,
+    analysis: &mut AudioAnalysis,
+) -> Result<Option<RipChecksums>, ClaxonError> {
+    trace!("Decoding FLAC {}", path.display());
+    let mut reader = FlacReader::open(path)?;
+    let info = reader.streaminfo();
+    let is_cd = info.bits_per_sample == CD_BIT_DEPTH
+        && info.sample_rate == CD_SAMPLE_RATE
+        && info.channels == 2;
+    let samples = info.samples.and_then(|samples| u32::try_from(samples).ok());
+    let mut calculator = match (checksums, samples) {
+        (Some(checksums), Some(samples)) if checks.decode && is_cd => Some(
+            RipChecksumCalculator::new(samples, checksums.is_first, checksums.is_last),
+        ),
+        _ => None,
+    };
+    if checks.hi_res && HiResAnalysis::is_hi_res(info.bits_per_sample, info.sample_rate) {
+        analysis.hi_res = Some(HiResAnalysis::new(info.bits_per_sample, info.sample_rate));
+    }
+    if checks.lossy_master {
+        analysis.lossy_master = Some(LossyMasterAnalysis::new(
+            info.bits_per_sample,
+            info.channels,
+            info.sample_rate,
+        ));
+    }
+    if checks.mqa {
+        analysis.mqa = MqaAnalysis::new(path, info.bits_per_sample, info.channels);
+    }
+    let mut frame = Vec::new();
+    let mut buffer = Vec::new();
+    let mut blocks = reader.blocks();
+    if !checks.decode && analysis.is_complete() {
+        return Ok(None);
+    }
+    while let Some(block) = blocks.read_next_or_eof(buffer)? {
+        if let Some(calculator) = &mut calculator {
+            calculator.push((0..block.duration()).map(|index| {
+                (
+                    to_i16(block.sample(0, index)),
+                    to_i16(block.sample(1, index)),
+                )
+            }));
+        }
+        if !analysis.is_complete() {
+            for index in 0..block.duration() {
+                frame.clear();
+                frame.extend((0..block.channels()).map(|channel| block.sample(channel, index)));
+                if let Some(hi_res) = &mut analysis.hi_res {
+                    hi_res.push_frame(&frame);
+                }
+                if let Some(lossy_master) = &mut analysis.lossy_master {
+                    lossy_master.push_frame(&frame);
+                }
+                if let Some(mqa) = &mut analysis.mqa {
+                    mqa.push_frame(&frame);
+                }
+            }
+        }
+        if !checks.decode && analysis.is_complete() {
+            break;
+        }
+        buffer = block.into_buffer();
+    }
+    Ok(calculator.map(RipChecksumCalculator::finish))
+}
+
+fn to_i16(sample: i32) -> i16 {
+    i16::try_from(sample).expect("16-bit sample should fit in i16")
+}
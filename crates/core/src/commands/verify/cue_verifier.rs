@@ -0,0 +1,195 @@
+use crate::prelude::*;
+
+/// Shortest gap before track 1 that is treated as hidden track one audio.
+///
+/// Shorter gaps are usually silence left by the mastering.
+const MIN_HIDDEN_TRACK: CueTime = CueTime {
+    frames: 2 * FRAMES_PER_SECOND,
+};
+
+/// Bit depth of a FLAC image that can be split into tracks.
+const IMAGE_BIT_DEPTH: u32 = 16;
+
+/// Check the cue sheets of a CD source against its FLACs.
+#[injectable]
+pub(crate) struct CueVerifier {
+    verify_options: Ref<VerifyOptions>,
+    target_options: Ref<TargetOptions>,
+}
+
+impl CueVerifier {
+    /// Check every `.cue` file of the source and return a [`SourceIssue`] for each mismatch.
+    ///
+    /// - Skips entirely when `no_cue_check` is set
+    /// - Skips sources that are not CD
+    /// - [`SourceIssue::PreEmphasis`] is only returned if `pre_emphasis` is `block`, in which case
+    ///   FLACs with a [`PRE_EMPHASIS_TAG`] are also reported
+    pub(crate) fn execute(&self, source: &Source, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+        if self.verify_options.no_cue_check {
+            debug!("{} cue check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        if source.metadata.media != Media::CD {
+            trace!("Skipping cue check of {} source", source.metadata.media);
+            return Vec::new();
+        }
+        let paths = match CueSheet::find(&source.directory) {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Failed to read cue sheets of {source}: {error}");
+                return Vec::new();
+            }
+        };
+        trace!("{} {} cue sheets", "Checking".bold(), paths.len());
+        let block = self.target_options.pre_emphasis == PreEmphasisPolicy::Block;
+        let mut issues: Vec<SourceIssue> = paths
+            .iter()
+            .flat_map(|path| check_cue(path, flacs))
+            .filter(|issue| block || !matches!(issue, SourceIssue::PreEmphasis { .. }))
+            .collect();
+        if block {
+            for flac in flacs {
+                if has_pre_emphasis_tag(&flac.path) {
+                    issues.push(SourceIssue::PreEmphasis {
+                        path: flac.path.clone(),
+                        tracks: Vec::new(),
+                    });
+                }
+            }
+        }
+        issues
+    }
+}
+
+/// Check a single cue sheet against the FLACs in its directory.
+///
+/// - Returns [`SourceIssue::CueMissingFile`] for each file without a matching FLAC
+/// - Returns [`SourceIssue::CueTrackCount`] if the number of audio tracks and FLACs differ
+/// - Returns [`SourceIssue::CueIndexBeyondEnd`] for each index past the end of its FLAC
+/// - Returns [`SourceIssue::HiddenTrack`] if the gap before track 1 was not ripped
+/// - Returns [`SourceIssue::ImageBitDepth`] if the FLAC of an image is not 16-bit
+/// - Returns [`SourceIssue::PreEmphasis`] if any track has the `PRE` flag
+///
+/// A FLAC matches a file of the cue sheet if it has the same name, ignoring the extension, as
+/// EAC cue sheets refer to `.wav` files.
+pub(crate) fn check_cue(path: &Path, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+    let sheet = match CueSheet::read(path) {
+        Ok(sheet) => sheet,
+        Err(error) => {
+            warn!("Failed to read cue sheet {}: {error}", path.display());
+            return Vec::new();
+        }
+    };
+    let dir = path.parent().unwrap_or(path);
+    let candidates: Vec<&FlacFile> = flacs
+        .iter()
+        .filter(|flac| flac.path.parent() == Some(dir))
+        .collect();
+    let files: Vec<Option<&FlacFile>> = sheet
+        .files
+        .iter()
+        .map(|name| find_flac(&candidates, name))
+        .collect();
+    let mut issues = Vec::new();
+    for (name, flac) in sheet.files.iter().zip(&files) {
+        if flac.is_none() {
+            issues.push(SourceIssue::CueMissingFile {
+                path: path.to_path_buf(),
+                file: name.clone(),
+            });
+        }
+    }
+    let tracks = sheet.get_audio_tracks().count();
+    let hidden_track = get_hidden_track(&sheet);
+    if !sheet.is_image() {
+        let actual = candidates.len();
+        if actual == tracks {
+            if let Some(length) = hidden_track {
+                issues.push(SourceIssue::HiddenTrack {
+                    path: path.to_path_buf(),
+                    length: length.to_string(),
+                });
+            }
+        } else if !(hidden_track.is_some() && actual == tracks + 1) {
+            issues.push(SourceIssue::CueTrackCount {
+                path: path.to_path_buf(),
+                expected: tracks,
+                actual,
+            });
+        }
+    }
+    if sheet.is_image()
+        && let Some(Some(flac)) = files.first()
+        && let Ok(info) = flac.get_stream_info()
+    {
+        issues.extend(check_image_bit_depth(&flac.path, info.bits_per_sample));
+    }
+    issues.append(&mut check_indexes(path, &sheet, &files));
+    let pre_emphasis: Vec<u32> = sheet
+        .get_audio_tracks()
+        .filter(|track| track.pre_emphasis)
+        .map(|track| track.number)
+        .collect();
+    if !pre_emphasis.is_empty() {
+        issues.push(SourceIssue::PreEmphasis {
+            path: path.to_path_buf(),
+            tracks: pre_emphasis,
+        });
+    }
+    issues
+}
+
+/// Check the FLAC of an image is 16-bit so it can be split into tracks.
+pub(crate) fn check_image_bit_depth(path: &Path, bits_per_sample: u32) -> Option<SourceIssue> {
+    (bits_per_sample != IMAGE_BIT_DEPTH).then(|| SourceIssue::ImageBitDepth {
+        path: path.to_path_buf(),
+        bit_depth: bits_per_sample,
+    })
+}
+
+/// Check each index is within the duration of the FLAC of its file.
+fn check_indexes(path: &Path, sheet: &CueSheet, files: &[Option<&FlacFile>]) -> Vec<SourceIssue> {
+    let durations: Vec<Option<CueTime>> = files
+        .iter()
+        .map(|flac| flac.and_then(get_duration))
+        .collect();
+    let mut issues = Vec::new();
+    for track in sheet.get_audio_tracks() {
+        for index in &track.indexes {
+            let Some(Some(duration)) = durations.get(index.file) else {
+                continue;
+            };
+            if index.time > *duration {
+                issues.push(SourceIssue::CueIndexBeyondEnd {
+                    path: path.to_path_buf(),
+                    track: track.number,
+                    index: index.number,
+                    time: index.time.to_string(),
+                    file: sheet.files.get(index.file).cloned().unwrap_or_default(),
+                    duration: duration.to_string(),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Get the length of the gap before track 1 if it is long enough to be hidden track one audio.
+fn get_hidden_track(sheet: &CueSheet) -> Option<CueTime> {
+    let first = sheet.get_audio_tracks().find(|track| track.number == 1)?;
+    let gap = first.get_gap()?;
+    (gap >= MIN_HIDDEN_TRACK).then_some(gap)
+}
+
+fn find_flac<'a>(candidates: &[&'a FlacFile], name: &str) -> Option<&'a FlacFile> {
+    let stem = get_file_stem(name);
+    candidates
+        .iter()
+        .find(|flac| flac.file_name == stem)
+        .copied()
+}
+
+fn get_duration(flac: &FlacFile) -> Option<CueTime> {
+    let info = flac.get_stream_info().ok()?;
+    Some(CueTime::from_samples(info.samples?, info.sample_rate))
+}
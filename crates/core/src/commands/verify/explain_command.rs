@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+/// Explain every verify check of a FLAC source and how its existing formats were determined.
+#[injectable]
+pub(crate) struct ExplainCommand {
+    source_provider: Ref<SourceProvider>,
+    api: Ref<GazelleClient>,
+    existing_provider: Ref<ExistingFormatProvider>,
+    verify: Ref<VerifyCommand>,
+}
+
+impl ExplainCommand {
+    /// Execute [`ExplainCommand`] from the CLI.
+    ///
+    /// Every check of [`VerifyCommand`] is run, even if an earlier check fails, and logged as a
+    /// table with the status and reason of each. Each torrent of the group is then logged with
+    /// whether it matched the edition of the source exactly, as a less specific edition, or not
+    /// at all.
+    ///
+    /// Returns `true` if the source is verified.
+    pub(crate) async fn execute_cli(&self) -> Result<bool, Failure<VerifyAction>> {
+        let source = match self.source_provider.get_from_options().await {
+            Ok(Ok(source)) => source,
+            Ok(Err(issue)) => {
+                warn!("{} to explain unknown source", "Unable".bold());
+                warn!("{issue}");
+                return Ok(false);
+            }
+            Err(e) => return Err(Failure::new(VerifyAction::GetSource, e)),
+        };
+        let result = self.verify.execute_with(&source, true).await?;
+        let id = source.to_string();
+        info!(
+            "{} checks of {id}\n{}",
+            "Ran".bold(),
+            render_checks(&result.checks)
+        );
+        let group = self
+            .api
+            .get_torrent_group(source.group.id)
+            .await
+            .map_err(Failure::wrap(VerifyAction::GetTorrentGroup))?;
+        let matches = self
+            .existing_provider
+            .explain(&source.torrent, &group.torrents);
+        info!(
+            "{} existing formats of {id}\n{}",
+            "Matched".bold(),
+            render_matches(&group.torrents, &matches)
+        );
+        let targets: Vec<String> = source.targets.iter().map(ToString::to_string).collect();
+        info!("{} {}", "Targets".bold(), targets.join(", "));
+        Ok(result.verified())
+    }
+}
+
+/// Render a table of each group torrent and how it matched the edition of the source.
+///
+/// `matches` must be in the same order as `torrents`, as returned by
+/// [`ExistingFormatProvider::explain`].
+#[must_use]
+pub(crate) fn render_matches(torrents: &[Torrent], matches: &[ExistingFormatMatch]) -> String {
+    let mut builder = TableBuilder::new()
+        .headers(["ID", "Format", "Edition", "Existing", "Reason"])
+        .right_align(vec![true]);
+    for (torrent, matched) in torrents.iter().zip(matches) {
+        let format = matched.format.map_or_else(
+            || format!("{} {}", torrent.format, torrent.encoding),
+            |format| format.to_string(),
+        );
+        let existing = if matched.edition.is_existing() && matched.format.is_some() {
+            "yes"
+        } else {
+            "no"
+        };
+        builder = builder.row([
+            matched.id.to_string(),
+            format,
+            get_edition(torrent),
+            existing.to_owned(),
+            matched.edition.to_string(),
+        ]);
+    }
+    builder.build()
+}
+
+/// Year, title, label, catalogue number and media of a torrent's edition.
+fn get_edition(torrent: &Torrent) -> String {
+    let year = torrent
+        .remaster_year
+        .filter(|year| *year != 0)
+        .map(|year| year.to_string())
+        .unwrap_or_default();
+    let parts = [
+        year,
+        torrent.remaster_title.clone(),
+        torrent.remaster_record_label.clone(),
+        torrent.remaster_catalogue_number.clone(),
+        torrent.media.to_string(),
+    ];
+    let parts: Vec<String> = parts.into_iter().filter(|part| !part.is_empty()).collect();
+    parts.join(" / ")
+}
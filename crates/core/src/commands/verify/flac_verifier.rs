@@ -4,6 +4,9 @@ use crate::prelude::*;
 #[injectable]
 pub(crate) struct FlacVerifier {
     paths: Ref<PathManager>,
+    verify_options: Ref<VerifyOptions>,
+    target_options: Ref<TargetOptions>,
+    id3_options: Ref<Id3Options>,
 }
 
 impl FlacVerifier {
@@ -18,7 +21,8 @@ impl FlacVerifier {
     ) -> Result<Vec<SourceIssue>, Failure<VerifyAction>> {
         trace!("{} {} FLACs", "Checking".bold(), flacs.len());
         let mut issues: Vec<SourceIssue> = Vec::new();
-        issues.extend(check_flac_count(source, flacs.len()));
+        let files: BTreeSet<&PathBuf> = flacs.iter().map(|flac| &flac.path).collect();
+        issues.extend(check_flac_count(source, files.len()));
         issues.extend(check_subdirectory(flacs));
         let max_target = get_max_path_length_target(source);
         let output_dir = self.paths.get_output_dir();
@@ -32,10 +36,20 @@ impl FlacVerifier {
                     .to_path_buf();
                 issues.extend(check_path_length(&path));
             }
-            let tag_issues = TagVerifier::execute(flac, source)
-                .map_err(Failure::wrap(VerifyAction::VerifyTags))?;
+            let tag_issues = TagVerifier::execute(
+                flac,
+                source,
+                &self.verify_options.composer_tags,
+                &self.id3_options,
+            )
+            .map_err(Failure::wrap(VerifyAction::VerifyTags))?;
             issues.extend(tag_issues);
-            issues.extend(StreamVerifier::execute(flac, source));
+            // Tracks of a FLAC image share the stream so only report each issue once
+            for issue in StreamVerifier::execute(flac, source, self.target_options.downmix) {
+                if !issues.contains(&issue) {
+                    issues.push(issue);
+                }
+            }
         }
         Ok(issues)
     }
@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// Highest bit depth of a CD master.
+const CD_BIT_DEPTH: u32 = 16;
+
+/// Lowest sample rate checked for upsampling.
+const MIN_UPSAMPLED_RATE: u32 = 88_200;
+
+/// Band in Hz used as the reference level of audible high frequency content.
+const REFERENCE_BAND: (f64, f64) = (16_000.0, 20_000.0);
+
+/// Lowest frequency in Hz of the band that must have content in a genuine high sample rate master.
+///
+/// Above the Nyquist frequency of both 44.1 and 48 kHz masters.
+const ULTRASONIC_LOW: f64 = 24_500.0;
+
+/// Highest frequency in Hz of the band that must have content in a genuine high sample rate master.
+const ULTRASONIC_HIGH: f64 = 40_000.0;
+
+/// Minimum drop in dB from [`REFERENCE_BAND`] to the ultrasonic band to report upsampling.
+///
+/// Genuine masters typically have analog noise within 50 dB of the reference band, while the
+/// ultrasonic band of an upsampled master is at the quantization noise floor.
+const UPSAMPLED_MIN_DROP_DB: f64 = 60.0;
+
+/// Minimum level in dB of [`REFERENCE_BAND`] for the upsampling check to be conclusive.
+const REFERENCE_MIN_DB: f64 = -140.0;
+
+/// Accumulated analysis of the decoded samples of a hi-res FLAC, to detect 24-bit or high
+/// sample rate FLACs that were mastered at CD quality.
+pub(crate) struct HiResAnalysis {
+    bits_per_sample: u32,
+    sample_rate: u32,
+    used_bits: i32,
+    scale: f64,
+    spectrum: Option<PowerSpectrum>,
+}
+
+impl HiResAnalysis {
+    /// Is a stream 24-bit or a high sample rate, and so should be analyzed?
+    #[must_use]
+    pub(crate) fn is_hi_res(bits_per_sample: u32, sample_rate: u32) -> bool {
+        bits_per_sample > CD_BIT_DEPTH || sample_rate >= MIN_UPSAMPLED_RATE
+    }
+
+    /// Create a [`HiResAnalysis`] for a stream.
+    #[must_use]
+    pub(crate) fn new(bits_per_sample: u32, sample_rate: u32) -> Self {
+        let shift = i32::try_from(bits_per_sample).expect("bit depth should fit in i32") - 1;
+        let spectrum = (sample_rate >= MIN_UPSAMPLED_RATE).then(|| PowerSpectrum::new(sample_rate));
+        Self {
+            bits_per_sample,
+            sample_rate,
+            used_bits: 0,
+            scale: 2_f64.powi(-shift),
+            spectrum,
+        }
+    }
+
+    /// Add an interleaved frame with one sample per channel.
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub(crate) fn push_frame(&mut self, samples: &[i32]) {
+        let mut sum = 0.0;
+        for sample in samples {
+            self.used_bits |= sample;
+            sum += f64::from(*sample);
+        }
+        if let Some(spectrum) = &mut self.spectrum {
+            spectrum.push(sum * self.scale / samples.len().max(1) as f64);
+        }
+    }
+
+    /// Get the number of bits used by the samples, or `None` if every sample is zero.
+    #[must_use]
+    pub(crate) fn get_effective_bit_depth(&self) -> Option<u32> {
+        if self.used_bits == 0 {
+            return None;
+        }
+        Some(
+            self.bits_per_sample
+                .saturating_sub(self.used_bits.trailing_zeros()),
+        )
+    }
+
+    /// Get the drop in dB from the audible high frequencies to the ultrasonic band.
+    ///
+    /// Returns `None` if the sample rate is too low, there is too little audio, or the audible
+    /// high frequencies are too quiet to be conclusive.
+    #[must_use]
+    pub(crate) fn get_ultrasonic_drop(&self) -> Option<f64> {
+        let spectrum = self.spectrum.as_ref()?;
+        if spectrum.windows() == 0 {
+            return None;
+        }
+        let nyquist = f64::from(self.sample_rate) / 2.0;
+        let reference = spectrum.get_band_level(REFERENCE_BAND.0, REFERENCE_BAND.1)?;
+        if reference < REFERENCE_MIN_DB {
+            return None;
+        }
+        let ultrasonic = spectrum.get_band_level(ULTRASONIC_LOW, ULTRASONIC_HIGH.min(nyquist))?;
+        Some(reference - ultrasonic)
+    }
+
+    /// Get a [`SourceIssue`] for each sign the stream is fake.
+    #[must_use]
+    pub(crate) fn get_issues(&self, path: &Path) -> Vec<SourceIssue> {
+        let mut issues = Vec::new();
+        if self.bits_per_sample > CD_BIT_DEPTH
+            && let Some(effective) = self.get_effective_bit_depth()
+            && effective <= CD_BIT_DEPTH
+        {
+            issues.push(SourceIssue::PaddedBitDepth {
+                path: path.to_path_buf(),
+                bit_depth: self.bits_per_sample,
+                effective,
+            });
+        }
+        if let Some(drop) = self.get_ultrasonic_drop() {
+            trace!(
+                "Ultrasonic band is {drop:.1} dB below audible high frequencies: {}",
+                path.display()
+            );
+            if drop >= UPSAMPLED_MIN_DROP_DB {
+                issues.push(SourceIssue::Upsampled {
+                    path: path.to_path_buf(),
+                    rate: self.sample_rate,
+                });
+            }
+        }
+        issues
+    }
+}
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+/// Parse and score the EAC and XLD rip logs of a CD source.
+#[injectable]
+pub(crate) struct LogVerifier {
+    verify_options: Ref<VerifyOptions>,
+}
+
+impl LogVerifier {
+    /// Check every `.log` file of the source and return a [`SourceIssue`] for each bad rip.
+    ///
+    /// - Skips entirely when `no_log_check` is set
+    /// - Skips sources that are not CD
+    /// - Logs that are not from EAC or XLD are ignored
+    pub(crate) fn execute(&self, source: &Source) -> Vec<SourceIssue> {
+        if self.verify_options.no_log_check {
+            debug!("{} log check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        if source.metadata.media != Media::CD {
+            trace!("Skipping log check of {} source", source.metadata.media);
+            return Vec::new();
+        }
+        let paths = match RipLog::find(&source.directory) {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Failed to read logs of {source}: {error}");
+                return Vec::new();
+            }
+        };
+        trace!("{} {} logs", "Checking".bold(), paths.len());
+        paths.iter().flat_map(|path| check_log(path)).collect()
+    }
+}
+
+/// Parse and score a single rip log by path.
+///
+/// - Returns [`SourceIssue::LogScore`] if the score is below [`MAX_LOG_SCORE`]
+/// - Returns [`SourceIssue::CrcMismatch`] for each track with differing test and copy CRCs
+pub(crate) fn check_log(path: &Path) -> Vec<SourceIssue> {
+    let log = match RipLog::read(path) {
+        Ok(Some(log)) => log,
+        Ok(None) => {
+            trace!("Skipping log check of {}: not EAC or XLD", path.display());
+            return Vec::new();
+        }
+        Err(error) => {
+            warn!("Failed to read log {}: {error}", path.display());
+            return Vec::new();
+        }
+    };
+    let mut issues = Vec::new();
+    let score = LogScore::from_log(&log);
+    if score.score < MAX_LOG_SCORE {
+        issues.push(SourceIssue::LogScore {
+            path: path.to_path_buf(),
+            score: score.score,
+            deductions: score.deductions.iter().map(ToString::to_string).collect(),
+        });
+    }
+    for track in log.tracks.iter().filter(|track| track.is_crc_mismatch()) {
+        issues.push(SourceIssue::CrcMismatch {
+            path: path.to_path_buf(),
+            track: track.number,
+            test: format!("{:08X}", track.test_crc.unwrap_or_default()),
+            copy: format!("{:08X}", track.copy_crc.unwrap_or_default()),
+        });
+    }
+    issues
+}
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+
+/// Lowest frequency in Hz checked for a lowpass shelf.
+///
+/// `lame` applies a 16 kHz lowpass at 128 kbps, and lower bitrates are rare.
+const MIN_CUTOFF: f64 = 15_000.0;
+
+/// Highest frequency in Hz checked for a lowpass shelf.
+///
+/// Above this the lowpass is more likely the anti-aliasing filter of a resampler.
+const MAX_CUTOFF: f64 = 20_500.0;
+
+/// Width in Hz of the band below a cutoff that must have content.
+///
+/// Narrow so the level falls as soon as the candidate passes the true cutoff.
+const BELOW_WIDTH: f64 = 300.0;
+
+/// Width in Hz of the band above a cutoff that must be empty.
+const ABOVE_WIDTH: f64 = 2_000.0;
+
+/// Gap in Hz either side of a cutoff excluded from both bands as the transition.
+const TRANSITION_WIDTH: f64 = 50.0;
+
+/// Minimum drop in dB across a cutoff to report a lowpass shelf.
+///
+/// The natural roll off of acoustic instruments and microphones is far more gradual.
+const MIN_DROP_DB: f64 = 30.0;
+
+/// Tolerance in dB of the drop when choosing the lowest candidate as the cutoff.
+const DROP_TOLERANCE_DB: f64 = 3.0;
+
+/// Minimum level in dB of the band below a cutoff for the check to be conclusive.
+const BELOW_MIN_DB: f64 = -110.0;
+
+/// Accumulated [`PowerSpectrum`] of the mono mix of a FLAC, to detect FLACs transcoded from a
+/// lossy source by the lowpass shelf of the lossy encoder.
+pub(crate) struct LossyMasterAnalysis {
+    scale: f64,
+    spectrum: PowerSpectrum,
+}
+
+impl LossyMasterAnalysis {
+    /// Create a [`LossyMasterAnalysis`] for a stream.
+    #[must_use]
+    pub(crate) fn new(bits_per_sample: u32, channels: u32, sample_rate: u32) -> Self {
+        let shift = i32::try_from(bits_per_sample).expect("bit depth should fit in i32") - 1;
+        Self {
+            scale: 2_f64.powi(-shift) / f64::from(channels.max(1)),
+            spectrum: PowerSpectrum::new(sample_rate),
+        }
+    }
+
+    /// Add an interleaved frame with one sample per channel.
+    pub(crate) fn push_frame(&mut self, samples: &[i32]) {
+        let sum: f64 = samples.iter().map(|sample| f64::from(*sample)).sum();
+        self.spectrum.push(sum * self.scale);
+    }
+
+    /// Get a [`SourceIssue`] if the stream has a lowpass shelf.
+    #[must_use]
+    pub(crate) fn get_issue(&self, path: &Path) -> Option<SourceIssue> {
+        let cutoff = find_lowpass_cutoff(&self.spectrum)?;
+        Some(SourceIssue::PossibleLossyMaster {
+            path: path.to_path_buf(),
+            cutoff,
+        })
+    }
+}
+
+/// Find the frequency in Hz of a lowpass shelf typical of an MP3 or AAC encoder.
+///
+/// - Each bin from [`MIN_CUTOFF`] to [`MAX_CUTOFF`] is a candidate
+/// - The drop of a candidate is from the level of the band below to the band above
+/// - The lowest candidate within [`DROP_TOLERANCE_DB`] of the largest drop is chosen
+/// - The drop must be at least [`MIN_DROP_DB`] with content below of at least [`BELOW_MIN_DB`]
+///
+/// Returns the cutoff rounded to the nearest 100 Hz, or `None` if there is no shelf.
+#[must_use]
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn find_lowpass_cutoff(spectrum: &PowerSpectrum) -> Option<u32> {
+    if spectrum.windows() == 0 {
+        return None;
+    }
+    let nyquist = spectrum.get_frequency(FFT_SIZE) / 2.0;
+    let mut candidates: Vec<(f64, f64)> = Vec::new();
+    for bin in 0..FFT_SIZE {
+        let cutoff = spectrum.get_frequency(bin);
+        if cutoff < MIN_CUTOFF {
+            continue;
+        }
+        if cutoff > MAX_CUTOFF || cutoff + TRANSITION_WIDTH >= nyquist {
+            break;
+        }
+        let Some(below) = spectrum.get_band_level(cutoff - BELOW_WIDTH, cutoff - TRANSITION_WIDTH)
+        else {
+            continue;
+        };
+        if below < BELOW_MIN_DB {
+            continue;
+        }
+        let high = (cutoff + ABOVE_WIDTH).min(nyquist);
+        let Some(above) = spectrum.get_band_level(cutoff + TRANSITION_WIDTH, high) else {
+            continue;
+        };
+        candidates.push((cutoff, below - above));
+    }
+    let largest = candidates
+        .iter()
+        .map(|(_, drop)| *drop)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if largest < MIN_DROP_DB {
+        return None;
+    }
+    let (cutoff, _) = candidates
+        .into_iter()
+        .find(|(_, drop)| *drop >= largest - DROP_TOLERANCE_DB)?;
+    Some(((cutoff / 100.0).round() * 100.0) as u32)
+}
@@ -0,0 +1,180 @@
+use crate::prelude::*;
+use futures::StreamExt;
+use futures::stream;
+use std::path::Component;
+
+/// Check the `.ffp`, `.md5`, `.sfv` and `.sha256` manifests of a source against its files.
+#[injectable]
+pub(crate) struct ManifestVerifier {
+    verify_options: Ref<VerifyOptions>,
+    runner_options: Ref<RunnerOptions>,
+}
+
+impl ManifestVerifier {
+    /// Check every entry of every manifest and return a [`SourceIssue`] for each that fails.
+    ///
+    /// - Skips entirely when `no_manifest_check` is set
+    /// - Returns [`SourceIssue::ManifestMissingFile`] for each entry without a file, including
+    ///   absolute entries and entries that resolve outside the source directory
+    /// - `.ffp` entries are compared to the MD5 signature in the STREAMINFO of the FLAC
+    /// - Other entries are compared to the checksum of the whole file, hashing up to `cpus` files
+    ///   at once on the blocking pool
+    pub(crate) async fn execute(&self, source: &Source, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+        if self.verify_options.no_manifest_check {
+            debug!("{} manifest check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        let paths = match ChecksumManifest::find(&source.directory) {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Failed to read checksum manifests of {source}: {error}");
+                return Vec::new();
+            }
+        };
+        trace!("{} {} checksum manifests", "Checking".bold(), paths.len());
+        check_manifests(
+            &source.directory,
+            &paths,
+            flacs,
+            self.runner_options.get_cpus(),
+        )
+        .await
+    }
+}
+
+/// Check every entry of the manifests at `paths` against the files in their directory.
+///
+/// Entries are untrusted so any that don't resolve to a path within `root` are reported as
+/// missing rather than read.
+pub(crate) async fn check_manifests(
+    root: &Path,
+    paths: &[PathBuf],
+    flacs: &[FlacFile],
+    cpus: usize,
+) -> Vec<SourceIssue> {
+    let mut issues = Vec::new();
+    let mut hashes = Vec::new();
+    for path in paths {
+        let manifest = match ChecksumManifest::read(path) {
+            Ok(Some(manifest)) => manifest,
+            Ok(None) => continue,
+            Err(error) => {
+                warn!(
+                    "Failed to read checksum manifest {}: {error}",
+                    path.display()
+                );
+                continue;
+            }
+        };
+        let dir = path.parent().unwrap_or(path);
+        for entry in manifest.entries {
+            let file = resolve_entry(root, dir, &entry.file).filter(|file| file.is_file());
+            match file {
+                None => issues.push(SourceIssue::ManifestMissingFile {
+                    path: path.clone(),
+                    file: entry.file,
+                }),
+                Some(file) if manifest.format == ManifestFormat::Ffp => {
+                    if let Some(flac) = flacs.iter().find(|flac| flac.path == file) {
+                        issues.extend(check_fingerprint(path, flac, &entry.checksum));
+                    } else {
+                        trace!("Skipping fingerprint of {} as it is not a FLAC", entry.file);
+                    }
+                }
+                Some(file) => hashes.push((path.clone(), manifest.format, file, entry.checksum)),
+            }
+        }
+    }
+    let mut mismatches: Vec<SourceIssue> = stream::iter(hashes)
+        .map(|(manifest, format, file, expected)| async move {
+            spawn_blocking(move || check_hash(&manifest, format, &file, &expected))
+                .await
+                .expect("hash task should not panic")
+        })
+        .buffered(cpus)
+        .filter_map(|issue| async move { issue })
+        .collect()
+        .await;
+    issues.append(&mut mismatches);
+    issues
+}
+
+/// Resolve a manifest entry relative to the `dir` of its manifest.
+///
+/// - Returns `None` for absolute entries and entries that resolve outside `root`
+/// - `..` is resolved lexically, then symlinks are resolved if the file exists
+fn resolve_entry(root: &Path, dir: &Path, file: &str) -> Option<PathBuf> {
+    let mut path = dir.to_path_buf();
+    for component in Path::new(file).components() {
+        match component {
+            Component::Normal(name) => path.push(name),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                path.pop();
+            }
+            Component::RootDir | Component::Prefix(_) => {
+                warn!("Ignoring absolute manifest entry: {file}");
+                return None;
+            }
+        }
+    }
+    let is_within = match (path.canonicalize(), root.canonicalize()) {
+        (Ok(resolved), Ok(root)) => resolved.starts_with(root),
+        _ => path.starts_with(root),
+    };
+    if !is_within {
+        warn!("Ignoring manifest entry outside the source directory: {file}");
+        return None;
+    }
+    Some(path)
+}
+
+/// Compare an `.ffp` entry to the MD5 signature in the STREAMINFO of a FLAC.
+///
+/// FLACs that can't be read or have no MD5 signature are skipped as they are reported by
+/// [`FlacVerifier`].
+pub(crate) fn check_fingerprint(
+    manifest: &Path,
+    flac: &FlacFile,
+    expected: &str,
+) -> Option<SourceIssue> {
+    let info = flac.get_stream_info().ok()?;
+    if info.md5sum == [0_u8; 16] {
+        return None;
+    }
+    let actual = to_hex(&info.md5sum);
+    mismatch(manifest, ManifestFormat::Ffp, &flac.path, expected, actual)
+}
+
+/// Compare an entry of an `.md5`, `.sfv` or `.sha256` manifest to the checksum of a file.
+pub(crate) fn check_hash(
+    manifest: &Path,
+    format: ManifestFormat,
+    path: &Path,
+    expected: &str,
+) -> Option<SourceIssue> {
+    trace!("Hashing {}", path.display());
+    match format.hash_file(path) {
+        Ok(actual) => mismatch(manifest, format, path, expected, actual),
+        Err(error) => {
+            warn!("Failed to hash {}: {error}", path.display());
+            None
+        }
+    }
+}
+
+fn mismatch(
+    manifest: &Path,
+    format: ManifestFormat,
+    path: &Path,
+    expected: &str,
+    actual: String,
+) -> Option<SourceIssue> {
+    (actual != expected).then(|| SourceIssue::ManifestMismatch {
+        path: path.to_path_buf(),
+        manifest: manifest.to_path_buf(),
+        checksum: format.to_string(),
+        expected: expected.to_owned(),
+        actual,
+    })
+}
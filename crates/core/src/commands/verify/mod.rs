@@ -1,23 +1,45 @@
 //! Verify a FLAC source is suitable for transcoding.
 
+pub(crate) use album_verifier::*;
 pub(crate) use api_verifier::*;
+pub(crate) use audio_verifier::*;
 pub(crate) use content_verifier::*;
-pub(crate) use decode_verifier::*;
+pub(crate) use cue_verifier::*;
+pub(crate) use explain_command::*;
 pub(crate) use flac_verifier::*;
+pub(crate) use hi_res_verifier::*;
+pub(crate) use log_verifier::*;
+pub(crate) use lossy_master_verifier::*;
+pub(crate) use manifest_verifier::*;
+pub(crate) use mqa_verifier::*;
 pub(crate) use stream_verifier::*;
+pub(crate) use tag_match_verifier::*;
 pub(crate) use tag_verifier::*;
 pub(crate) use verify_action::*;
+pub(crate) use verify_check::*;
 pub(crate) use verify_command::*;
+pub(crate) use verify_rule::*;
 pub(crate) use verify_status::*;
 
+mod album_verifier;
 mod api_verifier;
+mod audio_verifier;
 mod content_verifier;
-mod decode_verifier;
+mod cue_verifier;
+mod explain_command;
 mod flac_verifier;
+mod hi_res_verifier;
+mod log_verifier;
+mod lossy_master_verifier;
+mod manifest_verifier;
+mod mqa_verifier;
 mod stream_verifier;
+mod tag_match_verifier;
 mod tag_verifier;
 #[cfg(test)]
 mod tests;
 mod verify_action;
+mod verify_check;
 mod verify_command;
+mod verify_rule;
 mod verify_status;
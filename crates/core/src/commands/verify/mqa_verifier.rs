@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+/// Scan of the decoded samples of a FLAC for an MQA side channel hidden in the low bits.
+pub(crate) struct MqaAnalysis {
+    detector: MqaDetector,
+    position: Option<u32>,
+}
+
+impl MqaAnalysis {
+    /// Create a [`MqaAnalysis`] for a stream.
+    ///
+    /// Returns `None` if the FLAC has fewer than two channels, or an MQA Vorbis comment as it is
+    /// reported by [`StreamVerifier`].
+    #[must_use]
+    pub(crate) fn new(path: &Path, bits_per_sample: u32, channels: u32) -> Option<Self> {
+        if channels < 2 || matches!(get_mqa_tag(path), Ok(Some(_))) {
+            return None;
+        }
+        Some(Self {
+            detector: MqaDetector::new(bits_per_sample),
+            position: None,
+        })
+    }
+
+    /// Add an interleaved frame with one sample per channel.
+    ///
+    /// Frames are ignored once the sync word is found.
+    pub(crate) fn push_frame(&mut self, samples: &[i32]) {
+        if self.position.is_none() {
+            self.detector.push_frame(samples);
+            self.position = self.detector.get_position();
+        }
+    }
+
+    /// Has the sync word been found?
+    #[must_use]
+    pub(crate) fn is_complete(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Get a [`SourceIssue::Mqa`] if the sync word was found.
+    #[must_use]
+    pub(crate) fn get_issue(&self, path: &Path) -> Option<SourceIssue> {
+        self.position.map(|position| SourceIssue::Mqa {
+            path: path.to_path_buf(),
+            details: format!("sync word in bit {position}"),
+        })
+    }
+}
@@ -6,7 +6,9 @@ pub(crate) struct StreamVerifier;
 
 impl StreamVerifier {
     /// Verify stream properties of a FLAC file and return any issues found.
-    pub(crate) fn execute(flac: &FlacFile, source: &Source) -> Vec<SourceIssue> {
+    ///
+    /// The channel count is not checked if multichannel sources are to be downmixed.
+    pub(crate) fn execute(flac: &FlacFile, source: &Source, downmix: bool) -> Vec<SourceIssue> {
         let info = match check_flac_readable(flac) {
             Ok(info) => info,
             Err(issue) => return vec![issue],
@@ -15,7 +17,10 @@ impl StreamVerifier {
         issues.extend(check_sample_rate(&flac.path, &info));
         issues.extend(check_bit_rate(&flac.path, &info));
         issues.extend(check_duration(&flac.path, &info));
-        issues.extend(check_channels(&flac.path, &info));
+        if !downmix {
+            issues.extend(check_channels(&flac.path, &info));
+        }
+        issues.extend(check_mqa_tags(&flac.path));
         if source.torrent.media == Media::CD {
             issues.extend(check_md5(&flac.path, &info));
         }
@@ -91,3 +96,17 @@ pub(crate) fn check_md5(path: &Path, info: &StreamInfo) -> Option<SourceIssue> {
     }
     None
 }
+
+/// Check the FLAC does not have a Vorbis comment written by an MQA encoder.
+pub(crate) fn check_mqa_tags(path: &Path) -> Option<SourceIssue> {
+    match get_mqa_tag(path) {
+        Ok(tag) => tag.map(|tag| SourceIssue::Mqa {
+            path: path.to_path_buf(),
+            details: format!("{tag} tag"),
+        }),
+        Err(failure) => {
+            warn!("{}", failure.render());
+            None
+        }
+    }
+}
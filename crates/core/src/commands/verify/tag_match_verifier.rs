@@ -0,0 +1,163 @@
+use crate::prelude::*;
+use lofty::prelude::Accessor;
+use lofty::tag::ItemKey;
+
+/// Lowest similarity of a tag to the indexer metadata that is treated as a match.
+pub(crate) const MIN_TAG_SIMILARITY: f64 = 0.8;
+
+/// Verify the tags of a [`Source`]'s FLAC files match the metadata of the indexer.
+#[injectable]
+pub(crate) struct TagMatchVerifier {
+    verify_options: Ref<VerifyOptions>,
+}
+
+impl TagMatchVerifier {
+    /// Compare the artist, album and date tags to the indexer and return a [`SourceIssue`] for
+    /// each mismatch.
+    ///
+    /// - Skips entirely when `no_tag_match_check` is set
+    /// - Each distinct tag value is only reported once
+    pub(crate) fn execute(&self, source: &Source, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+        if self.verify_options.no_tag_match_check {
+            debug!("{} tag match check due to settings", "Skipped".bold());
+            return Vec::new();
+        }
+        trace!("{} tags against the indexer", "Checking".bold());
+        check_tag_match(source, flacs)
+    }
+}
+
+/// Compare the tags of each FLAC to the group and torrent of the indexer.
+///
+/// - Artist is the album artist, or the track artist if there is none. It matches if it is
+///   similar to, or contains, any artist, DJ, composer or conductor of the group
+/// - Album matches if it is similar to the group name, with or without a bracketed suffix
+///   such as `(Deluxe Edition)`
+/// - Date matches if its year is the remaster year or the original year of the group
+pub(crate) fn check_tag_match(source: &Source, flacs: &[FlacFile]) -> Vec<SourceIssue> {
+    let artists = get_artists(source);
+    let years = get_years(source);
+    let mut checked: HashSet<(&str, String)> = HashSet::new();
+    let mut issues = Vec::new();
+    for flac in flacs {
+        let Ok(tags) = flac.vorbis_tags() else {
+            continue;
+        };
+        let album_artist = tags.get_string(ItemKey::AlbumArtist);
+        if let Some(artist) = album_artist.or_else(|| tags.get_string(ItemKey::TrackArtist))
+            && !(album_artist.is_none() && source.metadata.artist == VARIOUS_ARTISTS)
+            && checked.insert(("artist", artist.to_owned()))
+        {
+            issues.extend(match_artist(&flac.path, artist, &artists));
+        }
+        if let Some(album) = tags.album()
+            && checked.insert(("album", album.to_string()))
+        {
+            issues.extend(match_album(&flac.path, &album, &source.group.name));
+        }
+        if let Some(date) = tags.get_string(ItemKey::RecordingDate)
+            && checked.insert(("date", date.to_owned()))
+        {
+            issues.extend(match_date(&flac.path, date, &years));
+        }
+    }
+    issues
+}
+
+/// Compare an artist tag to the credited artists of the group.
+pub(crate) fn match_artist(path: &Path, actual: &str, artists: &[String]) -> Option<SourceIssue> {
+    if artists.is_empty() || artists.iter().any(|artist| contains_name(actual, artist)) {
+        return None;
+    }
+    let (expected, similarity) = artists
+        .iter()
+        .map(|artist| (artist, get_name_similarity(actual, artist)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    mismatch(path, "artist", expected, actual, similarity)
+}
+
+/// Compare an album tag to the group name.
+pub(crate) fn match_album(path: &Path, actual: &str, expected: &str) -> Option<SourceIssue> {
+    if expected.is_empty() {
+        return None;
+    }
+    let similarity = get_name_similarity(actual, expected)
+        .max(get_name_similarity(strip_suffix(actual), expected));
+    mismatch(path, "album", expected, actual, similarity)
+}
+
+/// Compare the year of a date tag to the years of the torrent and group.
+pub(crate) fn match_date(path: &Path, actual: &str, years: &[u16]) -> Option<SourceIssue> {
+    let year = actual.trim().get(..4)?.parse::<u16>().ok()?;
+    if years.is_empty() || years.contains(&year) {
+        return None;
+    }
+    let expected: Vec<String> = years.iter().map(ToString::to_string).collect();
+    mismatch(path, "date", &expected.join(" or "), actual, 0.0)
+}
+
+fn mismatch(
+    path: &Path,
+    tag: &str,
+    expected: &str,
+    actual: &str,
+    similarity: f64,
+) -> Option<SourceIssue> {
+    if similarity >= MIN_TAG_SIMILARITY {
+        return None;
+    }
+    Some(SourceIssue::TagMismatch {
+        path: path.to_path_buf(),
+        tag: tag.to_owned(),
+        expected: decode_html_entities(expected),
+        actual: actual.to_owned(),
+        similarity: to_percent(similarity),
+    })
+}
+
+/// Names of the artists, DJs, composers and conductors of the group.
+fn get_artists(source: &Source) -> Vec<String> {
+    let Some(info) = &source.group.music_info else {
+        return Vec::new();
+    };
+    info.artists
+        .iter()
+        .chain(&info.dj)
+        .chain(&info.composers)
+        .chain(&info.conductor)
+        .map(|credit| credit.name.clone())
+        .collect()
+}
+
+/// Remaster year of the torrent and original year of the group.
+fn get_years(source: &Source) -> Vec<u16> {
+    let mut years: Vec<u16> = source.torrent.remaster_year.into_iter().collect();
+    years.push(source.group.year);
+    years.retain(|year| *year != 0);
+    years.dedup();
+    years
+}
+
+/// Remove a trailing bracketed suffix such as `(Deluxe Edition)` or `[Remastered]`.
+fn strip_suffix(album: &str) -> &str {
+    let album = album.trim_end();
+    let open = match album.chars().last() {
+        Some(')') => '(',
+        Some(']') => '[',
+        _ => return album,
+    };
+    album
+        .rfind(open)
+        .and_then(|index| album.get(..index))
+        .map_or(album, str::trim_end)
+}
+
+#[expect(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "similarity is from 0 to 1"
+)]
+fn to_percent(similarity: f64) -> u32 {
+    (similarity * 100.0).round() as u32
+}
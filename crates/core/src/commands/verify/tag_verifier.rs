@@ -8,13 +8,16 @@ pub(crate) struct TagVerifier;
 impl TagVerifier {
     /// Verify required tags on a FLAC file and return any tag issues.
     ///
-    /// Checks both raw Vorbis tags (presence) and `ID3v2` tags (convertibility).
+    /// Checks both raw Vorbis tags (presence) and `ID3v2` tags (convertibility), with the
+    /// `id3_mapping` and join rules of the [`Id3Options`] applied by [`map_id3_tags`].
     /// Tags missing from Vorbis produce [`SourceIssue::MissingTags`].
     /// Tags present in Vorbis but lost during `ID3v2` conversion produce
     /// [`SourceIssue::InvalidTags`].
     pub(crate) fn execute(
         flac: &FlacFile,
         source: &Source,
+        composer_tags: &[String],
+        id3_options: &Id3Options,
     ) -> Result<Vec<SourceIssue>, Failure<TranscodeAction>> {
         let vorbis = match flac.vorbis_tags() {
             Ok(tags) => tags,
@@ -25,10 +28,9 @@ impl TagVerifier {
             }
             Err(failure) => return Err(failure),
         };
-        let id3 = flac.id3_tags()?;
-        let id3 = Tag::from(id3.clone());
-        let vorbis_missing = collect_missing(vorbis, source, flac);
-        let id3_missing = collect_missing(&id3, source, flac);
+        let id3 = Tag::from(map_id3_tags(flac, id3_options)?);
+        let vorbis_missing = collect_missing(vorbis, source, flac, composer_tags);
+        let id3_missing = collect_missing(&id3, source, flac, composer_tags);
         let invalid: Vec<String> = id3_missing
             .into_iter()
             .filter(|tag| !vorbis_missing.contains(tag))
@@ -51,12 +53,17 @@ impl TagVerifier {
 }
 
 /// Collect tag names that are missing from the given tags.
-fn collect_missing(tags: &Tag, source: &Source, flac: &FlacFile) -> Vec<String> {
+fn collect_missing(
+    tags: &Tag,
+    source: &Source,
+    flac: &FlacFile,
+    composer_tags: &[String],
+) -> Vec<String> {
     let mut missing = Vec::new();
     missing.extend(check_artist_tag(tags));
     missing.extend(check_album_tag(tags));
     missing.extend(check_title_tag(tags));
-    missing.extend(check_composer_tag(tags, source));
+    missing.extend(check_composer_tag(tags, source, composer_tags));
     missing.extend(check_track_number_tag(tags));
     missing.extend(check_disc_number_tag(tags, flac));
     missing
@@ -86,15 +93,22 @@ pub(crate) fn check_title_tag(tags: &Tag) -> Option<String> {
     None
 }
 
-/// Check the composer tag is present when the source is classical with credited composers.
-pub(crate) fn check_composer_tag(tags: &Tag, source: &Source) -> Option<String> {
-    let is_classical = source.group.tags.contains(&"classical".to_owned());
+/// Check the composer tag is present when the group has any of `composer_tags` and credited
+/// composers.
+pub(crate) fn check_composer_tag(
+    tags: &Tag,
+    source: &Source,
+    composer_tags: &[String],
+) -> Option<String> {
+    let is_required = composer_tags
+        .iter()
+        .any(|tag| source.group.tags.contains(tag));
     let has_composers = source
         .group
         .music_info
         .as_ref()
         .is_some_and(|info| !info.composers.is_empty());
-    if is_classical && has_composers && tags.get(ItemKey::Composer).is_none() {
+    if is_required && has_composers && tags.get(ItemKey::Composer).is_none() {
         return Some("composer".to_owned());
     }
     None
@@ -0,0 +1,187 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn check_album_consistent() {
+    // Arrange
+    let dir = TempDirectory::create("check_album_consistent");
+    write_track(&dir, "01", 44_100, &[("TRACKNUMBER", "1/2")]);
+    write_track(&dir, "02", 44_100, &[("TRACKNUMBER", "2/2")]);
+    let flacs = get_flacs(&dir);
+
+    // Act
+    let issues = check_album(&flacs);
+
+    // Assert
+    assert_eq!(issues, Vec::new());
+}
+
+#[test]
+fn check_album_inconsistent_tags_and_track_numbers() {
+    // Arrange
+    let dir = TempDirectory::create("check_album_inconsistent_tags_and_track_numbers");
+    write_track(&dir, "01", 44_100, &[("TRACKNUMBER", "1")]);
+    write_track(
+        &dir,
+        "02",
+        44_100,
+        &[("TRACKNUMBER", "1"), ("DATE", "2002")],
+    );
+    write_track(
+        &dir,
+        "04",
+        44_100,
+        &[
+            ("TRACKNUMBER", "4"),
+            ("ALBUM", "Other"),
+            ("TRACKTOTAL", "4"),
+        ],
+    );
+    let flacs = get_flacs(&dir);
+
+    // Act
+    let issues = check_album(&flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::InconsistentTags {
+                tag: "album".to_owned(),
+                values: vec!["Album".to_owned(), "Other".to_owned()],
+            },
+            SourceIssue::InconsistentTags {
+                tag: "date".to_owned(),
+                values: vec!["2001".to_owned(), "2002".to_owned()],
+            },
+            SourceIssue::DuplicateTrackNumber {
+                disc: 1,
+                track: 1,
+                paths: vec![dir.join("01.flac"), dir.join("02.flac")],
+            },
+            SourceIssue::MissingTrackNumbers {
+                disc: 1,
+                tracks: vec![2, 3],
+            },
+            SourceIssue::TrackTotal {
+                disc: 1,
+                total: 4,
+                actual: 3,
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_album_discs() {
+    // Arrange
+    let dir = TempDirectory::create("check_album_discs");
+    write_track(
+        &dir,
+        "101",
+        44_100,
+        &[
+            ("TRACKNUMBER", "1"),
+            ("DISCNUMBER", "1"),
+            ("DISCTOTAL", "3"),
+        ],
+    );
+    write_track(
+        &dir,
+        "301",
+        44_100,
+        &[("TRACKNUMBER", "1"), ("DISCNUMBER", "3/3")],
+    );
+    let flacs = get_flacs(&dir);
+
+    // Act
+    let issues = check_album(&flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::DiscTotal {
+                total: 3,
+                actual: 2,
+            },
+            SourceIssue::MissingDiscs {
+                discs: vec![2],
+                disc_count: 3,
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_album_groups_untagged_discs_by_directory() {
+    // Arrange
+    let dir = TempDirectory::create("check_album_groups_untagged_discs_by_directory");
+    for disc in ["CD1", "CD2"] {
+        create_dir(dir.join(disc)).expect("should create disc directory");
+        write_track(
+            &dir,
+            &format!("{disc}/01"),
+            44_100,
+            &[("TRACKNUMBER", "1/2")],
+        );
+        write_track(
+            &dir,
+            &format!("{disc}/02"),
+            44_100,
+            &[("TRACKNUMBER", "2/2")],
+        );
+    }
+    let flacs = get_flacs(&dir);
+
+    // Act
+    let issues = check_album(&flacs);
+
+    // Assert
+    assert_eq!(flacs.len(), 4);
+    assert_eq!(issues, Vec::new());
+}
+
+#[test]
+fn check_album_mixed_sample_rate() {
+    // Arrange
+    let dir = TempDirectory::create("check_album_mixed_sample_rate");
+    write_track(&dir, "01", 44_100, &[("TRACKNUMBER", "1")]);
+    write_track(&dir, "02", 48_000, &[("TRACKNUMBER", "2")]);
+    let flacs = get_flacs(&dir);
+
+    // Act
+    let issues = check_album(&flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::MixedFormat {
+            property: "sample rate".to_owned(),
+            values: vec![44_100, 48_000],
+        }]
+    );
+}
+
+/// Encode a short 16-bit stereo FLAC of silence with album tags, replaced by any in `tags`.
+fn write_track(dir: &Path, name: &str, sample_rate: u32, tags: &[(&str, &str)]) {
+    let mut generator = PcmFlacGenerator::new()
+        .with_filename(format!("{name}.flac"))
+        .with_sample_rate(sample_rate);
+    let album = [
+        ("ALBUM", "Album"),
+        ("ALBUMARTIST", "Artist"),
+        ("DATE", "2001"),
+    ]
+    .into_iter()
+    .filter(|(key, _)| !tags.iter().any(|(tag, _)| tag == key));
+    for (key, value) in album.chain(tags.iter().copied()) {
+        generator = generator.with_vorbis_tag(key, value);
+    }
+    generator.generate(dir);
+}
+
+fn get_flacs(dir: &Path) -> Vec<FlacFile> {
+    let mut flacs = Collector::get_flacs_with_context(&dir.to_path_buf());
+    flacs.sort_by(|a, b| a.path.cmp(&b.path));
+    flacs
+}
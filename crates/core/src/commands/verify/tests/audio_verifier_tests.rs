@@ -0,0 +1,208 @@
+use crate::testing_prelude::*;
+use std::fs::OpenOptions;
+
+/// Only the decode check.
+const DECODE: AudioChecks = AudioChecks {
+    decode: true,
+    hi_res: false,
+    lossy_master: false,
+    mqa: false,
+};
+
+/// A valid FLAC decodes without any issue.
+#[tokio::test]
+async fn decode_flac_valid() {
+    // Arrange
+    let path = sample_track().await;
+    let source_dir = path
+        .parent()
+        .expect("track should have a parent directory")
+        .to_path_buf();
+    let flac = FlacFile::new(path, &source_dir);
+
+    // Act
+    let output = check_audio(&flac.path, None, DECODE);
+
+    // Assert
+    assert_eq!(output, AudioIssues::default());
+}
+
+/// A truncated FLAC passes the STREAMINFO header check but fails the full decode.
+#[tokio::test]
+async fn decode_flac_truncated() {
+    // Arrange
+    let source = sample_track().await;
+    let source_dir = TempDirectory::create("decode_flac_truncated");
+    let path = source_dir.join(source.file_name().expect("track should have a file name"));
+    copy(&source, &path).expect("should copy track to isolated directory");
+    truncate_to_half(&path);
+    let flac = FlacFile::new(path, &source_dir.to_path_buf());
+    assert!(
+        flac.get_stream_info().is_ok(),
+        "header check should still pass on a truncated file"
+    );
+
+    // Act
+    let output = check_audio(&flac.path, None, DECODE);
+
+    // Assert
+    let issue = output
+        .decode
+        .first()
+        .expect("truncated decode should report an issue");
+    assert!(
+        matches!(issue, SourceIssue::DecodeError { .. }),
+        "expected DecodeError, got: {issue}"
+    );
+}
+
+/// Several valid FLACs plus one truncated FLAC in a single decode pass.
+#[tokio::test]
+async fn audio_verifier_execute_with_one_truncated() {
+    // Arrange
+    init_logger();
+    let test_dir = TestDirectory::new();
+    let album = AlbumProvider::get(SampleFormat::default()).await;
+    let host = HostBuilder::new()
+        .with_mock_api(album)
+        .with_test_options(&test_dir)
+        .await
+        .expect_build();
+    let audio_verifier = host.services.get_required::<AudioVerifier>();
+    let source_track = sample_track().await;
+    let content_dir = TempDirectory::create("audio_verifier_execute_with_one_truncated");
+    let valid_one = content_dir.join("01 - valid.flac");
+    let valid_two = content_dir.join("02 - valid.flac");
+    let truncated = content_dir.join("03 - truncated.flac");
+    copy(&source_track, &valid_one).expect("should copy valid one");
+    copy(&source_track, &valid_two).expect("should copy valid two");
+    copy(&source_track, &truncated).expect("should copy truncated");
+    truncate_to_half(&truncated);
+    let source_dir = content_dir.to_path_buf();
+    let source = Source {
+        directory: source_dir.clone(),
+        ..Source::mock()
+    };
+    let flacs = vec![
+        FlacFile::new(valid_one, &source_dir),
+        FlacFile::new(valid_two, &source_dir),
+        FlacFile::new(truncated.clone(), &source_dir),
+    ];
+
+    // Act
+    let issues = audio_verifier.execute(&source, &flacs).await;
+
+    // Assert
+    assert_eq!(issues.decode.len(), 1, "exactly one decode error expected");
+    match issues.decode.first().expect("should have one issue") {
+        SourceIssue::DecodeError { path, .. } => assert_eq!(path, &truncated),
+        other => unreachable!("expected DecodeError, got: {other}"),
+    }
+}
+
+/// Path to the single track of the default sample album.
+async fn sample_track() -> PathBuf {
+    let album = AlbumProvider::get(SampleFormat::default()).await;
+    let source_dir = SAMPLE_SOURCES_DIR.join(album.dir_name());
+    let track = album.tracks.first().expect("album should have a track");
+    source_dir.join(album.track_filename(track))
+}
+
+/// Truncate a file to half its length, dropping trailing bytes.
+#[expect(clippy::integer_division, reason = "halve to truncate")]
+fn truncate_to_half(path: &Path) {
+    let length = metadata(path).expect("should read metadata").len();
+    OpenOptions::new()
+        .write(true)
+        .open(path)
+        .expect("should open file")
+        .set_len(length / 2)
+        .expect("should truncate file");
+}
+
+/// Checksums of the FLAC match its rip log.
+#[test]
+fn check_flac_matching_checksums() {
+    // Arrange
+    let dir = TempDirectory::create("check_flac_matching_checksums");
+    let path = write_cd_flac(&dir);
+    let checksums = get_log_checksums(&dir, 0xFA29_8975, 0x655F_8DA7);
+
+    // Act
+    let output = check_audio(&path, Some(&checksums), DECODE);
+
+    // Assert
+    assert_eq!(output.decode, Vec::new());
+}
+
+/// A FLAC edited after ripping does not match the CRC32 of its rip log.
+#[test]
+fn check_flac_mismatched_crc() {
+    // Arrange
+    let dir = TempDirectory::create("check_flac_mismatched_crc");
+    let path = write_cd_flac(&dir);
+    let checksums = get_log_checksums(&dir, 0x1234_5678, 0x655F_8DA7);
+
+    // Act
+    let output = check_audio(&path, Some(&checksums), DECODE);
+
+    // Assert
+    assert_eq!(
+        output.decode,
+        vec![SourceIssue::ChecksumMismatch {
+            path,
+            log: dir.join("Album.log"),
+            checksum: "CRC32".to_owned(),
+            expected: "12345678".to_owned(),
+            actual: "FA298975".to_owned(),
+        }]
+    );
+}
+
+/// Every check runs on a single decode, and a checksum mismatch is reported by the decode check.
+#[test]
+fn check_audio_all_checks() {
+    // Arrange
+    let dir = TempDirectory::create("check_audio_all_checks");
+    let path = write_cd_flac(&dir);
+    let checksums = get_log_checksums(&dir, 0x1234_5678, 0x655F_8DA7);
+    let checks = AudioChecks::from_options(&VerifyOptions::default());
+
+    // Act
+    let output = check_audio(&path, Some(&checksums), checks);
+
+    // Assert
+    assert!(checks.any());
+    assert_eq!(output.decode.len(), 1);
+    assert_eq!(output.hi_res, Vec::new());
+    assert_eq!(output.mqa, Vec::new());
+}
+
+/// Encode the stereo track of the rip checksum tests as a 16-bit 44.1 kHz FLAC.
+fn write_cd_flac(dir: &Path) -> PathBuf {
+    let samples: Vec<i16> = (0..10_000_u32)
+        .flat_map(|index| {
+            let left = i16::try_from((index * 37) % 2001).expect("should fit") - 1000;
+            let right = i16::try_from((index * 53) % 1501).expect("should fit") - 750;
+            let left = if index % 10 == 0 { 0 } else { left };
+            [left, right]
+        })
+        .collect();
+    PcmFlacGenerator::new()
+        .with_filename("02 - Track.flac")
+        .with_samples(samples)
+        .generate(dir)
+}
+
+fn get_log_checksums(dir: &Path, crc32: u32, accurate_rip_v2: u32) -> LogChecksums {
+    LogChecksums {
+        log: dir.join("Album.log"),
+        track: 2,
+        is_first: false,
+        is_last: false,
+        crc32: Some(crc32),
+        null_samples: true,
+        accurate_rip_v1: None,
+        accurate_rip_v2: Some(accurate_rip_v2),
+    }
+}
@@ -0,0 +1,184 @@
+use crate::testing_prelude::*;
+
+/// Cue sheet with gaps appended to the previous track and pre-emphasis on track 2.
+const CUE: &str = "FILE \"01 - First.wav\" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    FLAGS PRE
+    INDEX 00 00:00:40
+FILE \"02 - Second.wav\" WAVE
+    INDEX 01 00:00:00
+";
+
+/// Cue sheet with hidden track one audio in the gap before track 1.
+const HIDDEN_TRACK_CUE: &str = "FILE \"01 - First.wav\" WAVE
+  TRACK 01 AUDIO
+    INDEX 00 00:00:00
+    INDEX 01 00:02:10
+";
+
+#[test]
+fn check_cue_matching() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_matching");
+    let flacs = write_flacs(&dir, &["01 - First", "02 - Second"]);
+    let path = dir.join("Album.cue");
+    write(&path, CUE.replace("FLAGS PRE", "FLAGS DCP")).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(issues, Vec::new());
+}
+
+#[test]
+fn check_cue_pre_emphasis() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_pre_emphasis");
+    let flacs = write_flacs(&dir, &["01 - First", "02 - Second"]);
+    let path = dir.join("Album.cue");
+    write(&path, CUE).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::PreEmphasis {
+            path,
+            tracks: vec![2],
+        }]
+    );
+}
+
+#[test]
+fn check_cue_missing_file_and_track_count() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_missing_file_and_track_count");
+    let flacs = write_flacs(&dir, &["01 - First"]);
+    let path = dir.join("Album.cue");
+    write(&path, CUE.replace("FLAGS PRE", "")).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::CueMissingFile {
+                path: path.clone(),
+                file: "02 - Second.wav".to_owned(),
+            },
+            SourceIssue::CueTrackCount {
+                path,
+                expected: 2,
+                actual: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_cue_index_beyond_end() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_index_beyond_end");
+    let flacs = write_flacs(&dir, &["01 - First", "02 - Second"]);
+    let path = dir.join("Album.cue");
+    let text = CUE
+        .replace("FLAGS PRE", "")
+        .replace("INDEX 00 00:00:40", "INDEX 00 00:04:00");
+    write(&path, text).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::CueIndexBeyondEnd {
+            path,
+            track: 2,
+            index: 0,
+            time: "00:04:00".to_owned(),
+            file: "01 - First.wav".to_owned(),
+            duration: "00:03:00".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn check_cue_hidden_track() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_hidden_track");
+    let flacs = write_flacs(&dir, &["01 - First"]);
+    let path = dir.join("Album.cue");
+    write(&path, HIDDEN_TRACK_CUE).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::HiddenTrack {
+            path,
+            length: "00:02:10".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn check_cue_hidden_track_ripped() {
+    // Arrange
+    let dir = TempDirectory::create("check_cue_hidden_track_ripped");
+    let flacs = write_flacs(&dir, &["00 - Hidden", "01 - First"]);
+    let path = dir.join("Album.cue");
+    write(&path, HIDDEN_TRACK_CUE).expect("should write cue");
+
+    // Act
+    let issues = check_cue(&path, &flacs);
+
+    // Assert
+    assert_eq!(issues, Vec::new());
+}
+
+#[test]
+fn check_image_bit_depth_24_bit() {
+    // Arrange
+    let path = PathBuf::from("/source/Album.flac");
+
+    // Act
+    let issues = [
+        check_image_bit_depth(&path, 16),
+        check_image_bit_depth(&path, 24),
+    ];
+
+    // Assert
+    assert_eq!(
+        issues,
+        [
+            None,
+            Some(SourceIssue::ImageBitDepth {
+                path,
+                bit_depth: 24,
+            }),
+        ]
+    );
+}
+
+/// Encode a 3 second 16-bit 44.1 kHz FLAC of silence for each name.
+fn write_flacs(dir: &Path, names: &[&str]) -> Vec<FlacFile> {
+    names
+        .iter()
+        .map(|name| {
+            PcmFlacGenerator::new()
+                .with_filename(format!("{name}.flac"))
+                .with_samples(vec![0; 44_100 * 2 * 3])
+                .generate_flac_file(dir)
+        })
+        .collect()
+}
@@ -0,0 +1,96 @@
+use crate::testing_prelude::*;
+use std::f64::consts::TAU;
+
+/// Full scale of a 24-bit sample.
+const FULL_SCALE: f64 = 8_388_607.0;
+
+/// Create a [`HiResAnalysis`] of two seconds of 24-bit stereo tones.
+///
+/// Each tone is a frequency in Hz and a level in dBFS. Samples are masked with `mask`.
+#[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+fn analyze_tones(sample_rate: u32, tones: &[(f64, f64)], mask: i32) -> HiResAnalysis {
+    let mut analysis = HiResAnalysis::new(24, sample_rate);
+    for index in 0..sample_rate * 2 {
+        let time = f64::from(index) / f64::from(sample_rate);
+        let value: f64 = tones
+            .iter()
+            .map(|(frequency, level)| 10_f64.powf(level / 20.0) * (TAU * frequency * time).sin())
+            .sum();
+        let sample = (value * FULL_SCALE).round() as i32 & mask;
+        analysis.push_frame(&[sample, sample]);
+    }
+    analysis
+}
+
+#[test]
+fn hi_res_analysis_genuine() {
+    // Arrange
+    let tones = [(1_000.0, -6.0), (18_000.0, -40.0), (30_000.0, -70.0)];
+
+    // Act
+    let issues = analyze_tones(96_000, &tones, !0).get_issues(Path::new("/a.flac"));
+
+    // Assert
+    assert_eq!(issues, Vec::new());
+}
+
+#[test]
+fn hi_res_analysis_padded_bit_depth() {
+    // Arrange
+    let tones = [(1_000.0, -6.0)];
+
+    // Act
+    let issues = analyze_tones(44_100, &tones, !0xFF).get_issues(Path::new("/a.flac"));
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::PaddedBitDepth {
+            path: PathBuf::from("/a.flac"),
+            bit_depth: 24,
+            effective: 16,
+        }]
+    );
+}
+
+#[test]
+fn hi_res_analysis_upsampled() {
+    // Arrange
+    let tones = [(1_000.0, -6.0), (18_000.0, -40.0)];
+
+    // Act
+    let issues = analyze_tones(96_000, &tones, !0).get_issues(Path::new("/a.flac"));
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![SourceIssue::Upsampled {
+            path: PathBuf::from("/a.flac"),
+            rate: 96_000,
+        }]
+    );
+}
+
+#[test]
+fn hi_res_analysis_quiet_high_frequencies_are_inconclusive() {
+    // Arrange
+    let tones = [(1_000.0, -6.0)];
+
+    // Act
+    let analysis = analyze_tones(96_000, &tones, !0);
+
+    // Assert
+    assert_eq!(analysis.get_ultrasonic_drop(), None);
+}
+
+#[test]
+fn hi_res_analysis_silence() {
+    // Arrange
+    let analysis = analyze_tones(96_000, &[], !0);
+
+    // Act
+    let effective = analysis.get_effective_bit_depth();
+
+    // Assert
+    assert_eq!(effective, None);
+}
@@ -0,0 +1,90 @@
+use crate::testing_prelude::*;
+
+/// EAC log with a test and copy CRC mismatch on track 2.
+const EAC_LOG: &str = "Exact Audio Copy V1.6 from 23. October 2020
+
+Read mode               : Secure
+Defeat audio cache      : Yes
+Make use of C2 pointers : No
+Read offset correction  : 30
+Gap handling            : Appended to previous track
+
+Track  1
+
+     Test CRC 8A3B1C2D
+     Copy CRC 8A3B1C2D
+     Copy OK
+
+Track  2
+
+     Test CRC 11111111
+     Copy CRC 22222222
+     Copy finished
+
+==== Log checksum 7F6D1C2B3A4E5F60 ====
+";
+
+#[test]
+fn check_log_utf16_crc_mismatch() {
+    // Arrange
+    let dir = TempDirectory::create("check_log_utf16_crc_mismatch");
+    let path = dir.join("Artist - Album.log");
+    let mut bytes = vec![0xFF, 0xFE];
+    for unit in EAC_LOG.encode_utf16() {
+        bytes.extend_from_slice(&unit.to_le_bytes());
+    }
+    write(&path, bytes).expect("should write log");
+
+    // Act
+    let issues = check_log(&path);
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::LogScore {
+                path: path.clone(),
+                score: 70,
+                deductions: vec!["CRC mismatch on 1 track".to_owned()],
+            },
+            SourceIssue::CrcMismatch {
+                path,
+                track: 2,
+                test: "11111111".to_owned(),
+                copy: "22222222".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_log_perfect() {
+    // Arrange
+    let dir = TempDirectory::create("check_log_perfect");
+    let path = dir.join("Artist - Album.log");
+    write(
+        &path,
+        EAC_LOG.replace("Copy CRC 22222222", "Copy CRC 11111111"),
+    )
+    .expect("should write log");
+
+    // Act
+    let issues = check_log(&path);
+
+    // Assert
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn check_log_unknown_ripper() {
+    // Arrange
+    let dir = TempDirectory::create("check_log_unknown_ripper");
+    let path = dir.join("notes.log");
+    write(&path, "Transcoded with caesura\n").expect("should write log");
+
+    // Act
+    let issues = check_log(&path);
+
+    // Assert
+    assert!(issues.is_empty());
+}
@@ -0,0 +1,81 @@
+use crate::testing_prelude::*;
+use std::f64::consts::TAU;
+
+/// Sample rate of the generated signals.
+const SAMPLE_RATE: u32 = 44_100;
+
+/// Full scale of a 16-bit sample.
+const QUANTIZATION: f64 = 32_768.0;
+
+/// Create a [`PowerSpectrum`] of two seconds of equal level sines every 50 Hz up to `highest`.
+///
+/// The level of each sine falls by `slope` dB per kHz to imitate a natural roll off.
+///
+/// Samples are quantized to 16-bit so the spectrum has a realistic noise floor.
+fn analyze_sines(highest: f64, slope: f64) -> PowerSpectrum {
+    let mut frequencies = Vec::new();
+    let mut frequency = 50.0;
+    while frequency <= highest {
+        let amplitude = 0.002 * 10_f64.powf(-slope * frequency / 1000.0 / 20.0);
+        frequencies.push((frequency, amplitude, frequency * 0.37));
+        frequency += 50.0;
+    }
+    let mut spectrum = PowerSpectrum::new(SAMPLE_RATE);
+    for index in 0..SAMPLE_RATE * 2 {
+        let time = f64::from(index) / f64::from(SAMPLE_RATE);
+        let sample: f64 = frequencies
+            .iter()
+            .map(|(frequency, amplitude, phase)| amplitude * (TAU * frequency * time + phase).sin())
+            .sum();
+        spectrum.push((sample * QUANTIZATION).round() / QUANTIZATION);
+    }
+    spectrum
+}
+
+#[test]
+fn find_lowpass_cutoff_mp3_shelf() {
+    // Arrange
+    let spectrum = analyze_sines(16_000.0, 0.0);
+
+    // Act
+    let cutoff = find_lowpass_cutoff(&spectrum);
+
+    // Assert
+    assert_eq!(cutoff, Some(16_000));
+}
+
+#[test]
+fn find_lowpass_cutoff_19_khz_shelf() {
+    // Arrange
+    let spectrum = analyze_sines(19_000.0, 1.0);
+
+    // Act
+    let cutoff = find_lowpass_cutoff(&spectrum);
+
+    // Assert
+    assert_eq!(cutoff, Some(19_000));
+}
+
+#[test]
+fn find_lowpass_cutoff_natural_roll_off() {
+    // Arrange
+    let spectrum = analyze_sines(22_000.0, 3.0);
+
+    // Act
+    let cutoff = find_lowpass_cutoff(&spectrum);
+
+    // Assert
+    assert_eq!(cutoff, None);
+}
+
+#[test]
+fn find_lowpass_cutoff_quiet_high_frequencies() {
+    // Arrange
+    let spectrum = analyze_sines(10_000.0, 0.0);
+
+    // Act
+    let cutoff = find_lowpass_cutoff(&spectrum);
+
+    // Assert
+    assert_eq!(cutoff, None);
+}
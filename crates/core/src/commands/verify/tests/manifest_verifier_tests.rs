@@ -0,0 +1,130 @@
+use crate::testing_prelude::*;
+
+#[tokio::test]
+async fn check_manifests_matching() {
+    // Arrange
+    let dir = TempDirectory::create("check_manifests_matching");
+    let flac = write_flac(&dir, "01 - First");
+    let fingerprint = get_fingerprint(&flac);
+    let md5 = ManifestFormat::Md5
+        .hash_file(&flac.path)
+        .expect("should hash");
+    let sfv = ManifestFormat::Sfv
+        .hash_file(&flac.path)
+        .expect("should hash");
+    let paths = vec![
+        write_manifest(&dir, "Album.ffp", &format!("01 - First.flac:{fingerprint}")),
+        write_manifest(&dir, "Album.md5", &format!("{md5} *01 - First.flac")),
+        write_manifest(&dir, "Album.sfv", &format!("01 - First.flac {sfv}")),
+    ];
+
+    // Act
+    let issues = check_manifests(&dir, &paths, &[flac], 2).await;
+
+    // Assert
+    assert_eq!(issues, Vec::new());
+}
+
+#[tokio::test]
+async fn check_manifests_mismatch_and_missing() {
+    // Arrange
+    let dir = TempDirectory::create("check_manifests_mismatch_and_missing");
+    let flac = write_flac(&dir, "01 - First");
+    let fingerprint = get_fingerprint(&flac);
+    let md5 = ManifestFormat::Md5
+        .hash_file(&flac.path)
+        .expect("should hash");
+    let wrong = "0".repeat(32);
+    let ffp = write_manifest(
+        &dir,
+        "Album.ffp",
+        &format!("01 - First.flac:{wrong}\n02 - Second.flac:{wrong}"),
+    );
+    let md5_manifest = write_manifest(&dir, "Album.md5", &format!("{wrong}  01 - First.flac"));
+    let paths = vec![ffp.clone(), md5_manifest.clone()];
+
+    // Act
+    let issues = check_manifests(&dir, &paths, &[flac], 2).await;
+
+    // Assert
+    let path = dir.join("01 - First.flac");
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::ManifestMismatch {
+                path: path.clone(),
+                manifest: ffp.clone(),
+                checksum: "FLAC MD5".to_owned(),
+                expected: wrong.clone(),
+                actual: fingerprint,
+            },
+            SourceIssue::ManifestMissingFile {
+                path: ffp,
+                file: "02 - Second.flac".to_owned(),
+            },
+            SourceIssue::ManifestMismatch {
+                path,
+                manifest: md5_manifest,
+                checksum: "MD5".to_owned(),
+                expected: wrong,
+                actual: md5,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn check_manifests_rejects_entries_outside_source() {
+    // Arrange
+    let dir = TempDirectory::create("check_manifests_rejects_entries_outside_source");
+    let source = dir.join("source");
+    create_dir_all(&source).expect("should create source");
+    let outside = write_flac(&dir, "Outside");
+    let md5 = ManifestFormat::Md5
+        .hash_file(&outside.path)
+        .expect("should hash");
+    let absolute = outside.path.display().to_string();
+    let manifest = write_manifest(
+        &source,
+        "Album.md5",
+        &format!("{md5}  ../Outside.flac\n{md5}  {absolute}"),
+    );
+    let paths = vec![manifest.clone()];
+
+    // Act
+    let issues = check_manifests(&source, &paths, &[], 2).await;
+
+    // Assert
+    assert_eq!(
+        issues,
+        vec![
+            SourceIssue::ManifestMissingFile {
+                path: manifest.clone(),
+                file: "../Outside.flac".to_owned(),
+            },
+            SourceIssue::ManifestMissingFile {
+                path: manifest,
+                file: absolute,
+            },
+        ]
+    );
+}
+
+/// Encode a short 16-bit 44.1 kHz FLAC of a ramp so the MD5 signature is not of silence.
+fn write_flac(dir: &Path, name: &str) -> FlacFile {
+    PcmFlacGenerator::new()
+        .with_filename(format!("{name}.flac"))
+        .with_samples((0..4_410_i16).collect())
+        .generate_flac_file(dir)
+}
+
+fn get_fingerprint(flac: &FlacFile) -> String {
+    let info = flac.get_stream_info().expect("should read stream info");
+    to_hex(&info.md5sum)
+}
+
+fn write_manifest(dir: &Path, name: &str, text: &str) -> PathBuf {
+    let path = dir.join(name);
+    write(&path, text).expect("should write manifest");
+    path
+}
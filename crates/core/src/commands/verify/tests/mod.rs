@@ -1,8 +1,18 @@
+mod album_verifier_tests;
 mod api_verifier_tests;
+mod audio_verifier_tests;
 mod content_verifier_tests;
-mod decode_verifier_tests;
+mod cue_verifier_tests;
 mod flac_verifier_tests;
+mod hi_res_verifier_tests;
+mod log_verifier_tests;
+mod lossy_master_verifier_tests;
+mod manifest_verifier_tests;
+mod mqa_verifier_tests;
 mod source_rules_tests;
 mod stream_verifier_tests;
+mod tag_match_verifier_tests;
 mod tag_verifier_tests;
+mod verify_check_tests;
 mod verify_command_tests;
+mod verify_rule_tests;
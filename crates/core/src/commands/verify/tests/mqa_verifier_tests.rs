@@ -0,0 +1,90 @@
+use crate::testing_prelude::*;
+
+/// Only the MQA check.
+const MQA: AudioChecks = AudioChecks {
+    decode: false,
+    hi_res: false,
+    lossy_master: false,
+    mqa: true,
+};
+
+#[test]
+fn check_mqa_sync_word() {
+    // Arrange
+    let dir = TempDirectory::create("check_mqa_sync_word");
+    let path = write_flac(&dir, "01 - MQA", true);
+
+    // Act
+    let output = check_audio(&path, None, MQA).mqa;
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![SourceIssue::Mqa {
+            path,
+            details: "sync word in bit 2".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn check_mqa_silence() {
+    // Arrange
+    let dir = TempDirectory::create("check_mqa_silence");
+    let path = write_flac(&dir, "01 - Silence", false);
+
+    // Act
+    let output = check_audio(&path, None, MQA).mqa;
+
+    // Assert
+    assert_eq!(output, Vec::new());
+}
+
+#[test]
+fn check_mqa_tags_reported_once() {
+    // Arrange
+    let dir = TempDirectory::create("check_mqa_tags_reported_once");
+    let path = write_flac(&dir, "01 - Tagged", true);
+    insert_vorbis_comments_into_flac(&path, vec![("MQAENCODER", "MQAEncode v1.1".to_owned())])
+        .expect("should write tags");
+
+    // Act
+    let tags = check_mqa_tags(&path);
+    let scan = check_audio(&path, None, MQA).mqa;
+
+    // Assert
+    assert_eq!(
+        tags,
+        Some(SourceIssue::Mqa {
+            path,
+            details: "MQAENCODER=MQAEncode v1.1 tag".to_owned(),
+        })
+    );
+    assert_eq!(
+        scan,
+        Vec::new(),
+        "tagged FLACs are reported by the stream check"
+    );
+}
+
+/// Encode a 16-bit 44.1 kHz FLAC of low level noise, with [`MQA_SYNC_WORD`] repeated in bit 2
+/// of the XOR of the channels if `mqa` is set.
+fn write_flac(dir: &Path, name: &str, mqa: bool) -> PathBuf {
+    let mut samples = Vec::new();
+    for repeat in 0..4 {
+        for index in (0..36).rev() {
+            let bit = if mqa {
+                i16::from((MQA_SYNC_WORD >> index) & 1 == 1)
+            } else {
+                0
+            };
+            let noise = i16::try_from((index * 7 + repeat) % 3).expect("noise should fit in i16");
+            samples.extend([noise, noise ^ (bit << 2)]);
+        }
+        samples.extend([0; 128]);
+    }
+    PcmFlacGenerator::new()
+        .with_filename(format!("{name}.flac"))
+        .with_samples(samples)
+        .generate(dir)
+}
@@ -0,0 +1,101 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn match_artist_contains_credit() {
+    // Arrange
+    let artists = vec!["Simon &amp; Garfunkel".to_owned()];
+
+    // Act
+    let issue = match_artist(
+        Path::new("/a.flac"),
+        "Simon & Garfunkel feat. Guest",
+        &artists,
+    );
+
+    // Assert
+    assert_eq!(issue, None);
+}
+
+#[test]
+fn match_artist_mismatch() {
+    // Arrange
+    let artists = vec!["Simon &amp; Garfunkel".to_owned(), "Paul Simon".to_owned()];
+
+    // Act
+    let issue = match_artist(Path::new("/a.flac"), "Art Garfunkel", &artists);
+
+    // Assert
+    assert!(
+        matches!(
+            &issue,
+            Some(SourceIssue::TagMismatch {
+                tag,
+                expected,
+                actual,
+                similarity,
+                ..
+            }) if tag == "artist"
+                && expected == "Simon & Garfunkel"
+                && actual == "Art Garfunkel"
+                && *similarity < 80
+        ),
+        "{issue:?}"
+    );
+}
+
+#[test]
+fn match_album_suffix_and_entities() {
+    // Act
+    let edition = match_album(
+        Path::new("/a.flac"),
+        "Bridge Over Troubled Water (40th Anniversary Edition)",
+        "Bridge Over Troubled Water",
+    );
+    let entities = match_album(
+        Path::new("/a.flac"),
+        "Rock 'n' Roll",
+        "Rock &#39;n&#39; Roll",
+    );
+
+    // Assert
+    assert_eq!(edition, None);
+    assert_eq!(entities, None);
+}
+
+#[test]
+fn match_album_mismatch() {
+    // Act
+    let issue = match_album(
+        Path::new("/a.flac"),
+        "Bookends",
+        "Bridge Over Troubled Water",
+    );
+
+    // Assert
+    assert!(matches!(issue, Some(SourceIssue::TagMismatch { .. })));
+}
+
+#[test]
+fn match_date_years() {
+    // Arrange
+    let years = vec![2011, 1970];
+
+    // Act
+    let remaster = match_date(Path::new("/a.flac"), "2011-03-07", &years);
+    let original = match_date(Path::new("/a.flac"), "1970", &years);
+    let other = match_date(Path::new("/a.flac"), "1999", &years);
+
+    // Assert
+    assert_eq!(remaster, None);
+    assert_eq!(original, None);
+    assert_eq!(
+        other,
+        Some(SourceIssue::TagMismatch {
+            path: PathBuf::from("/a.flac"),
+            tag: "date".to_owned(),
+            expected: "2011 or 1970".to_owned(),
+            actual: "1999".to_owned(),
+            similarity: 0,
+        })
+    );
+}
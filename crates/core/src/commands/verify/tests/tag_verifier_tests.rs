@@ -32,6 +32,10 @@ fn classical_source() -> Source {
     source
 }
 
+fn composer_tags() -> Vec<String> {
+    vec!["classical".to_owned()]
+}
+
 fn mock_flac(is_multi_disc: bool) -> FlacFile {
     let source_dir = PathBuf::from("/tmp");
     let mut flac = FlacFile::new(PathBuf::from("/tmp/test.flac"), &source_dir);
@@ -85,7 +89,7 @@ fn check_title_tag_missing() {
 fn check_composer_tag_classical_present() {
     let tags = full_tags();
     let source = classical_source();
-    assert_eq!(check_composer_tag(&tags, &source), None);
+    assert_eq!(check_composer_tag(&tags, &source, &composer_tags()), None);
 }
 
 #[test]
@@ -93,7 +97,7 @@ fn check_composer_tag_classical_missing() {
     let tags = minimal_tags();
     let source = classical_source();
     assert_eq!(
-        check_composer_tag(&tags, &source),
+        check_composer_tag(&tags, &source, &composer_tags()),
         Some("composer".to_owned())
     );
 }
@@ -102,7 +106,19 @@ fn check_composer_tag_classical_missing() {
 fn check_composer_tag_not_classical() {
     let tags = minimal_tags();
     let source = Source::mock();
-    assert_eq!(check_composer_tag(&tags, &source), None);
+    assert_eq!(check_composer_tag(&tags, &source, &composer_tags()), None);
+}
+
+#[test]
+fn check_composer_tag_configured_tags() {
+    let tags = minimal_tags();
+    let mut source = classical_source();
+    source.group.tags = vec!["opera".to_owned()];
+    assert_eq!(check_composer_tag(&tags, &source, &composer_tags()), None);
+    assert_eq!(
+        check_composer_tag(&tags, &source, &["opera".to_owned()]),
+        Some("composer".to_owned())
+    );
 }
 
 #[test]
@@ -110,7 +126,7 @@ fn check_composer_tag_classical_no_credited_composers() {
     let tags = minimal_tags();
     let mut source = Source::mock();
     source.group.tags = vec!["classical".to_owned()];
-    assert_eq!(check_composer_tag(&tags, &source), None);
+    assert_eq!(check_composer_tag(&tags, &source, &composer_tags()), None);
 }
 
 #[test]
@@ -166,7 +182,8 @@ async fn tag_verifier_execute_no_vorbis_block() {
     let source = Source::mock();
 
     // Act
-    let output = TagVerifier::execute(&flac, &source).expect("should not fail hard");
+    let output = TagVerifier::execute(&flac, &source, &composer_tags(), &Id3Options::default())
+        .expect("should not fail hard");
 
     // Assert
     assert_eq!(output, vec![SourceIssue::NoTags { path: flac_path }]);
@@ -202,7 +219,8 @@ async fn tag_verifier_execute_non_numeric_track() {
     let source = Source::mock();
 
     // Act
-    let output = TagVerifier::execute(&flac, &source).expect("should not fail hard");
+    let output = TagVerifier::execute(&flac, &source, &composer_tags(), &Id3Options::default())
+        .expect("should not fail hard");
 
     // Assert
     assert_eq!(
@@ -230,7 +248,8 @@ async fn tag_verifier_execute_missing_track() {
     let source = Source::mock();
 
     // Act
-    let output = TagVerifier::execute(&flac, &source).expect("should not fail hard");
+    let output = TagVerifier::execute(&flac, &source, &composer_tags(), &Id3Options::default())
+        .expect("should not fail hard");
 
     // Assert
     assert_eq!(
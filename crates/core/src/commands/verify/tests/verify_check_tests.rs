@@ -0,0 +1,116 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn check_recorder_record_status() {
+    // Arrange
+    let options = VerifyOptions {
+        no_decode_check: true,
+        ..VerifyOptions::default()
+    };
+    let mut checks = CheckRecorder::new(&options);
+
+    // Act
+    checks.record(VerifyCheck::Api, vec![SourceIssue::Scene]);
+    checks.record(VerifyCheck::Flac, Vec::new());
+    checks.record(VerifyCheck::Decode, Vec::new());
+    let result = checks.finish();
+
+    // Assert
+    let statuses: Vec<(VerifyCheck, CheckStatus)> =
+        result.checks.iter().map(|x| (x.check, x.status)).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            (VerifyCheck::Api, CheckStatus::Fail),
+            (VerifyCheck::Flac, CheckStatus::Pass),
+            (VerifyCheck::Decode, CheckStatus::Skipped),
+        ]
+    );
+    assert_eq!(result.issues, vec![SourceIssue::Scene]);
+    assert_eq!(
+        result.checks.get(2).map(|x| x.reason.as_str()),
+        Some("no_decode_check is set")
+    );
+}
+
+#[test]
+fn check_recorder_skip_remaining() {
+    // Arrange
+    let options = VerifyOptions::default();
+    let mut checks = CheckRecorder::new(&options);
+    checks.record(VerifyCheck::Api, vec![SourceIssue::Scene]);
+
+    // Act
+    checks.skip_remaining("API checks failed");
+    let result = checks.finish();
+
+    // Assert
+    let order: Vec<VerifyCheck> = result.checks.iter().map(|x| x.check).collect();
+    assert_eq!(order, VerifyCheck::ALL.to_vec());
+    assert!(
+        result
+            .checks
+            .iter()
+            .skip(1)
+            .all(|x| x.status == CheckStatus::Skipped && x.reason == "API checks failed")
+    );
+}
+
+#[test]
+fn render_checks_table() {
+    // Arrange
+    let options = VerifyOptions {
+        no_manifest_check: true,
+        ..VerifyOptions::default()
+    };
+    let mut checks = CheckRecorder::new(&options);
+    checks.record(VerifyCheck::Api, Vec::new());
+    checks.record(
+        VerifyCheck::Hash,
+        vec![SourceIssue::MissingDirectory {
+            path: PathBuf::from("/path/to/content"),
+        }],
+    );
+    checks.record(VerifyCheck::Manifest, Vec::new());
+    checks.skip_remaining("Hash check failed");
+
+    // Act
+    let output = render_checks(&checks.finish().checks);
+
+    // Assert
+    assert_snapshot!(output);
+}
+
+#[test]
+fn render_matches_table() {
+    // Arrange
+    let source = Torrent {
+        id: 1,
+        remaster_record_label: String::new(),
+        ..Torrent::mock()
+    };
+    let less_specific = Torrent {
+        id: 2,
+        format: Format::MP3,
+        encoding: Quality::V0,
+        ..Torrent::mock()
+    };
+    let different = Torrent {
+        id: 3,
+        format: Format::AAC,
+        encoding: Quality::_256,
+        remaster_year: Some(1999),
+        ..Torrent::mock()
+    };
+    let torrents = vec![source.clone(), less_specific, different];
+    let provider = ExistingFormatProvider {
+        options: Ref::new(TargetOptions::default()),
+    };
+    let matches = provider.explain(&source, &torrents);
+
+    // Act
+    let output = render_matches(&torrents, &matches);
+
+    // Assert
+    assert_snapshot!(output);
+}
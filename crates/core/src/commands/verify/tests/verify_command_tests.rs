@@ -56,6 +56,18 @@ async fn verify_command_execute_no_reports_set() -> Result<(), TestError> {
             no_hash_check: true,
             exclude_tags: None,
             no_decode_check: false,
+            no_hi_res_check: false,
+            no_lossy_master_check: false,
+            no_mqa_check: false,
+            no_log_check: false,
+            no_cue_check: false,
+            no_album_check: false,
+            no_tag_match_check: false,
+            no_manifest_check: false,
+            all_checks: false,
+            warning_rules: Vec::new(),
+            off_rules: Vec::new(),
+            composer_tags: vec!["classical".to_owned()],
         })
         .with_options(ReportOptions {
             reports_dir: reports_dir.clone(),
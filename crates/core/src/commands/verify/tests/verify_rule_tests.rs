@@ -0,0 +1,208 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn rule_severity_get_off_takes_precedence() {
+    // Arrange
+    let options = VerifyOptions {
+        warning_rules: vec![VerifyRule::PossibleScene, VerifyRule::Trumpable],
+        off_rules: vec![VerifyRule::Trumpable],
+        ..VerifyOptions::default()
+    };
+
+    // Act & Assert
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::PossibleScene, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::Trumpable, &options),
+        RuleSeverity::Off
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::Scene, &options),
+        RuleSeverity::Error
+    );
+}
+
+#[test]
+fn rule_severity_get_default_warning_rules() {
+    // Arrange
+    let options = VerifyOptions::default();
+
+    // Act & Assert
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::PossibleLossyMaster, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::LogScore, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::CueMissingFile, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::HiddenTrack, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::TagMismatch, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::InconsistentTags, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::MixedFormat, &options),
+        RuleSeverity::Warning
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::DuplicateTrackNumber, &options),
+        RuleSeverity::Error
+    );
+    assert_eq!(
+        RuleSeverity::get(VerifyRule::Upsampled, &options),
+        RuleSeverity::Error
+    );
+}
+
+#[test]
+fn rule_severity_classify_issue_without_rule_is_error() {
+    // Arrange
+    let options = VerifyOptions {
+        off_rules: vec![VerifyRule::FlacError],
+        ..VerifyOptions::default()
+    };
+    let issue = SourceIssue::DecodeError {
+        path: PathBuf::from("01.flac"),
+        error: "lost sync".to_owned(),
+    };
+
+    // Act
+    let output = RuleSeverity::classify(issue.clone(), &options);
+
+    // Assert
+    assert_eq!(output, vec![(RuleSeverity::Error, issue)]);
+}
+
+#[test]
+fn rule_severity_classify_analysis_issues() {
+    // Arrange
+    let options = VerifyOptions {
+        warning_rules: vec![VerifyRule::CueTrackCount],
+        off_rules: vec![VerifyRule::InconsistentTags],
+        ..VerifyOptions::default()
+    };
+    let cue = SourceIssue::CueTrackCount {
+        path: PathBuf::from("album.cue"),
+        expected: 12,
+        actual: 11,
+    };
+    let tags = SourceIssue::InconsistentTags {
+        tag: "album".to_owned(),
+        values: vec!["A".to_owned(), "B".to_owned()],
+    };
+    let manifest = SourceIssue::ManifestMissingFile {
+        path: PathBuf::from("album.md5"),
+        file: "01.flac".to_owned(),
+    };
+
+    // Act
+    let cue_output = RuleSeverity::classify(cue.clone(), &options);
+    let tags_output = RuleSeverity::classify(tags.clone(), &options);
+    let manifest_output = RuleSeverity::classify(manifest.clone(), &options);
+
+    // Assert
+    assert_eq!(cue_output, vec![(RuleSeverity::Warning, cue)]);
+    assert_eq!(tags_output, vec![(RuleSeverity::Off, tags)]);
+    assert_eq!(manifest_output, vec![(RuleSeverity::Error, manifest)]);
+}
+
+#[test]
+fn rule_severity_classify_splits_tags() {
+    // Arrange
+    let options = VerifyOptions {
+        warning_rules: vec![VerifyRule::ComposerTag],
+        off_rules: vec![VerifyRule::DiscNumberTag],
+        ..VerifyOptions::default()
+    };
+    let path = PathBuf::from("01.flac");
+    let issue = SourceIssue::MissingTags {
+        path: path.clone(),
+        tags: vec![
+            "artist".to_owned(),
+            "composer".to_owned(),
+            "disc_number".to_owned(),
+        ],
+    };
+
+    // Act
+    let output = RuleSeverity::classify(issue, &options);
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![
+            (
+                RuleSeverity::Error,
+                SourceIssue::MissingTags {
+                    path: path.clone(),
+                    tags: vec!["artist".to_owned()],
+                }
+            ),
+            (
+                RuleSeverity::Warning,
+                SourceIssue::MissingTags {
+                    path: path.clone(),
+                    tags: vec!["composer".to_owned()],
+                }
+            ),
+            (
+                RuleSeverity::Off,
+                SourceIssue::MissingTags {
+                    path,
+                    tags: vec!["disc_number".to_owned()],
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn check_recorder_record_warning_rules() {
+    // Arrange
+    let options = VerifyOptions {
+        warning_rules: vec![VerifyRule::PossibleScene],
+        off_rules: vec![VerifyRule::Trumpable],
+        ..VerifyOptions::default()
+    };
+    let mut checks = CheckRecorder::new(&options);
+
+    // Act
+    checks.record(
+        VerifyCheck::Api,
+        vec![SourceIssue::PossibleScene, SourceIssue::Trumpable],
+    );
+    let result = checks.finish();
+
+    // Assert
+    assert!(result.verified());
+    assert_eq!(result.warnings, vec![SourceIssue::PossibleScene]);
+    assert_eq!(
+        result.checks.first().map(|x| x.status),
+        Some(CheckStatus::Warning)
+    );
+}
+
+#[test]
+fn verify_rule_display_matches_config_id() {
+    // Act
+    let output = VerifyRule::TrackNumberTag.to_string();
+    let yaml = yaml_to_string(&VerifyRule::TrackNumberTag).expect("should serialize");
+
+    // Assert
+    assert_eq!(output, "track_number_tag");
+    assert_eq!(yaml.trim(), output);
+}
@@ -7,6 +7,8 @@ pub enum VerifyAction {
     GetSource,
     #[error("get source torrent file")]
     GetSourceTorrent,
+    #[error("get torrent group")]
+    GetTorrentGroup,
     #[error("verify torrent hash")]
     VerifyHash,
     #[error("verify tags")]
@@ -0,0 +1,232 @@
+use crate::prelude::*;
+
+/// Maximum width of the reason column of the checks table.
+const REASON_WIDTH: usize = 80;
+
+/// A group of rules checked by one verifier of [`VerifyCommand`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum VerifyCheck {
+    Api,
+    Hash,
+    Flac,
+    Album,
+    TagMatch,
+    Decode,
+    HiRes,
+    LossyMaster,
+    Mqa,
+    Log,
+    Cue,
+    Manifest,
+}
+
+impl VerifyCheck {
+    /// Every [`VerifyCheck`] in the order they are run.
+    pub(crate) const ALL: [VerifyCheck; 12] = [
+        Self::Api,
+        Self::Hash,
+        Self::Flac,
+        Self::Album,
+        Self::TagMatch,
+        Self::Decode,
+        Self::HiRes,
+        Self::LossyMaster,
+        Self::Mqa,
+        Self::Log,
+        Self::Cue,
+        Self::Manifest,
+    ];
+
+    /// Display name of the check.
+    #[must_use]
+    pub(crate) fn get_name(self) -> &'static str {
+        match self {
+            Self::Api => "API",
+            Self::Hash => "Hash",
+            Self::Flac => "FLAC",
+            Self::Album => "Album consistency",
+            Self::TagMatch => "Tag match",
+            Self::Decode => "Decode",
+            Self::HiRes => "Fake hi-res",
+            Self::LossyMaster => "Lossy master",
+            Self::Mqa => "MQA",
+            Self::Log => "Rip log",
+            Self::Cue => "Cue sheet",
+            Self::Manifest => "Checksum manifest",
+        }
+    }
+
+    /// Name of the option that disables the check, if it is set.
+    #[must_use]
+    pub(crate) fn get_disabled_by(self, options: &VerifyOptions) -> Option<&'static str> {
+        let (name, disabled) = match self {
+            Self::Api | Self::Flac => return None,
+            Self::Hash => ("no_hash_check", options.no_hash_check),
+            Self::Album => ("no_album_check", options.no_album_check),
+            Self::TagMatch => ("no_tag_match_check", options.no_tag_match_check),
+            Self::Decode => ("no_decode_check", options.no_decode_check),
+            Self::HiRes => ("no_hi_res_check", options.no_hi_res_check),
+            Self::LossyMaster => ("no_lossy_master_check", options.no_lossy_master_check),
+            Self::Mqa => ("no_mqa_check", options.no_mqa_check),
+            Self::Log => ("no_log_check", options.no_log_check),
+            Self::Cue => ("no_cue_check", options.no_cue_check),
+            Self::Manifest => ("no_manifest_check", options.no_manifest_check),
+        };
+        disabled.then_some(name)
+    }
+}
+
+impl Display for VerifyCheck {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.get_name())
+    }
+}
+
+/// Outcome of a [`VerifyCheck`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum CheckStatus {
+    Pass,
+    Warning,
+    Fail,
+    Skipped,
+}
+
+impl Display for CheckStatus {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let status = match self {
+            Self::Pass => "pass",
+            Self::Warning => "warning",
+            Self::Fail => "fail",
+            Self::Skipped => "skipped",
+        };
+        write!(formatter, "{status}")
+    }
+}
+
+/// Outcome of a [`VerifyCheck`] and why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct CheckResult {
+    /// Check that was run or skipped.
+    pub check: VerifyCheck,
+    /// Outcome of the check.
+    pub status: CheckStatus,
+    /// Issues found if the check failed, or why it was skipped.
+    pub reason: String,
+}
+
+/// Record the [`CheckResult`] and [`SourceIssue`] issues of each [`VerifyCheck`].
+pub(crate) struct CheckRecorder<'a> {
+    options: &'a VerifyOptions,
+    /// Issues of every recorded check.
+    pub issues: Vec<SourceIssue>,
+    /// Issues of every recorded check that are only warnings.
+    pub warnings: Vec<SourceIssue>,
+    /// Result of every recorded check.
+    pub results: Vec<CheckResult>,
+}
+
+impl<'a> CheckRecorder<'a> {
+    /// Create an empty [`CheckRecorder`].
+    #[must_use]
+    pub(crate) fn new(options: &'a VerifyOptions) -> Self {
+        Self {
+            options,
+            issues: Vec::new(),
+            warnings: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Whether any recorded check found an issue.
+    #[must_use]
+    pub(crate) fn has_issues(&self) -> bool {
+        !self.issues.is_empty()
+    }
+
+    /// Record the issues found by a check.
+    ///
+    /// - A check disabled by its option is recorded as skipped
+    /// - Issues are split by the [`RuleSeverity`] of their [`VerifyRule`] so issues of rules that
+    ///   are off are dropped and issues of warning rules are recorded as warnings
+    pub(crate) fn record(&mut self, check: VerifyCheck, issues: Vec<SourceIssue>) {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        for issue in issues {
+            for (severity, issue) in RuleSeverity::classify(issue, self.options) {
+                match severity {
+                    RuleSeverity::Error => errors.push(issue),
+                    RuleSeverity::Warning => warnings.push(issue),
+                    RuleSeverity::Off => trace!("Ignoring issue as its rule is off: {issue}"),
+                }
+            }
+        }
+        let (status, reason) = if let Some(option) = check.get_disabled_by(self.options) {
+            (CheckStatus::Skipped, format!("{option} is set"))
+        } else if errors.is_empty() && warnings.is_empty() {
+            (CheckStatus::Pass, String::new())
+        } else {
+            let reasons: Vec<String> = errors
+                .iter()
+                .chain(&warnings)
+                .map(ToString::to_string)
+                .collect();
+            let status = if errors.is_empty() {
+                CheckStatus::Warning
+            } else {
+                CheckStatus::Fail
+            };
+            (status, reasons.join("\n"))
+        };
+        self.results.push(CheckResult {
+            check,
+            status,
+            reason,
+        });
+        self.issues.append(&mut errors);
+        self.warnings.append(&mut warnings);
+    }
+
+    /// Record a check as skipped.
+    pub(crate) fn skip(&mut self, check: VerifyCheck, reason: &str) {
+        self.results.push(CheckResult {
+            check,
+            status: CheckStatus::Skipped,
+            reason: reason.to_owned(),
+        });
+    }
+
+    /// Record every check that has not been recorded as skipped.
+    pub(crate) fn skip_remaining(&mut self, reason: &str) {
+        for check in VerifyCheck::ALL {
+            if !self.results.iter().any(|result| result.check == check) {
+                self.skip(check, reason);
+            }
+        }
+    }
+
+    /// Consume the recorder into a [`VerifySuccess`].
+    #[must_use]
+    pub(crate) fn finish(self) -> VerifySuccess {
+        VerifySuccess {
+            issues: self.issues,
+            warnings: self.warnings,
+            checks: self.results,
+        }
+    }
+}
+
+/// Render a table of the status and reason of each check.
+#[must_use]
+pub(crate) fn render_checks(checks: &[CheckResult]) -> String {
+    let mut builder = TableBuilder::new()
+        .headers(["Check", "Status", "Reason"])
+        .max_column_width(2, REASON_WIDTH);
+    for result in checks {
+        builder = builder.row([
+            result.check.to_string(),
+            result.status.to_string(),
+            result.reason.clone(),
+        ]);
+    }
+    builder.build()
+}
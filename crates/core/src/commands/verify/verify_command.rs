@@ -4,10 +4,16 @@ use crate::prelude::*;
 #[injectable]
 pub(crate) struct VerifyCommand {
     source_provider: Ref<SourceProvider>,
+    verify_options: Ref<VerifyOptions>,
     api_verifier: Ref<ApiVerifier>,
     content_verifier: Ref<ContentVerifier>,
     flac_verifier: Ref<FlacVerifier>,
-    decode_verifier: Ref<DecodeVerifier>,
+    album_verifier: Ref<AlbumVerifier>,
+    tag_match_verifier: Ref<TagMatchVerifier>,
+    audio_verifier: Ref<AudioVerifier>,
+    log_verifier: Ref<LogVerifier>,
+    cue_verifier: Ref<CueVerifier>,
+    manifest_verifier: Ref<ManifestVerifier>,
     reporter: Ref<SourceReporter>,
 }
 
@@ -36,6 +42,17 @@ impl VerifyCommand {
         };
         let result = self.execute(&source).await?;
         let id = source.to_string();
+        if self.verify_options.all_checks {
+            info!(
+                "{} checks of {id}\n{}",
+                "Ran".bold(),
+                render_checks(&result.checks)
+            );
+        }
+        if !result.warnings.is_empty() {
+            let warnings = SourceIssuesRenderer::render(&result.warnings, &source.directory);
+            warn!("{} for {id}\n{warnings}", "Warnings".bold());
+        }
         if result.verified() {
             info!("{} {id}", "Verified".bold());
         } else {
@@ -51,33 +68,81 @@ impl VerifyCommand {
     pub(crate) async fn execute(
         &self,
         source: &Source,
+    ) -> Result<VerifySuccess, Failure<VerifyAction>> {
+        self.execute_with(source, self.verify_options.all_checks)
+            .await
+    }
+
+    /// Execute [`VerifyCommand`] on a [`Source`].
+    ///
+    /// Unless `all_checks` is set:
+    /// - Hash and FLAC checks are skipped if API checks fail
+    /// - FLAC checks are skipped if the hash check fails
+    /// - Audio analysis is skipped if FLAC checks fail
+    ///
+    /// Returns a [`VerifySuccess`] containing any issues found and the result of each check.
+    pub(crate) async fn execute_with(
+        &self,
+        source: &Source,
+        all_checks: bool,
     ) -> Result<VerifySuccess, Failure<VerifyAction>> {
         debug!("{} {}", "Verifying".bold(), source);
-        let mut issues: Vec<SourceIssue> = Vec::new();
-        issues.append(&mut self.api_verifier.execute(source));
-        if !issues.is_empty() {
+        let mut checks = CheckRecorder::new(&self.verify_options);
+        checks.record(VerifyCheck::Api, self.api_verifier.execute(source));
+        if !all_checks && checks.has_issues() {
             trace!("Skipping hash and FLAC checks as API checks failed");
-            return Ok(VerifySuccess { issues });
+            checks.skip_remaining("API checks failed");
+            return Ok(checks.finish());
         }
-        if let Some(issue) = self.content_verifier.execute(source).await? {
-            issues.push(issue);
+        let hash = self.content_verifier.execute(source).await?;
+        checks.record(VerifyCheck::Hash, hash.into_iter().collect());
+        if !all_checks && checks.has_issues() {
             trace!("Skipping FLAC checks as hash check failed");
-            return Ok(VerifySuccess { issues });
+            checks.skip_remaining("Hash check failed");
+            return Ok(checks.finish());
         }
         match Collector::collect_flacs(source) {
             Ok(flacs) => {
-                issues.append(&mut self.flac_verifier.execute(source, &flacs)?);
-                if issues.is_empty() {
-                    issues.append(&mut self.decode_verifier.execute(&flacs).await);
+                // Audio analysis and file checks are of the files rather than each track of
+                // a FLAC image
+                let files = Collector::get_flacs(&source.directory);
+                checks.record(
+                    VerifyCheck::Flac,
+                    self.flac_verifier.execute(source, &flacs)?,
+                );
+                checks.record(VerifyCheck::Album, self.album_verifier.execute(&flacs));
+                checks.record(
+                    VerifyCheck::TagMatch,
+                    self.tag_match_verifier.execute(source, &flacs),
+                );
+                if all_checks || !checks.has_issues() {
+                    let audio = self.audio_verifier.execute(source, &files).await;
+                    checks.record(VerifyCheck::Decode, audio.decode);
+                    checks.record(VerifyCheck::HiRes, audio.hi_res);
+                    checks.record(VerifyCheck::LossyMaster, audio.lossy_master);
+                    checks.record(VerifyCheck::Mqa, audio.mqa);
                 } else {
-                    trace!("Skipping decode check as FLAC checks failed");
+                    trace!("Skipping audio analysis as FLAC checks failed");
+                    checks.skip(VerifyCheck::Decode, "FLAC checks failed");
+                    checks.skip(VerifyCheck::HiRes, "FLAC checks failed");
+                    checks.skip(VerifyCheck::LossyMaster, "FLAC checks failed");
+                    checks.skip(VerifyCheck::Mqa, "FLAC checks failed");
                 }
+                checks.record(VerifyCheck::Log, self.log_verifier.execute(source));
+                checks.record(VerifyCheck::Cue, self.cue_verifier.execute(source, &files));
+                checks.record(
+                    VerifyCheck::Manifest,
+                    self.manifest_verifier.execute(source, &files).await,
+                );
+            }
+            Err(issue) => {
+                checks.record(VerifyCheck::Flac, vec![issue]);
+                checks.skip_remaining("FLACs could not be collected");
             }
-            Err(issue) => issues.push(issue),
         }
-        if let Err(failure) = self.reporter.execute(source, &issues) {
+        if let Err(failure) = self.reporter.execute(source, &checks.issues) {
             warn!("{}", failure.render());
         }
-        Ok(VerifySuccess { issues })
+        Ok(checks.finish())
     }
 }
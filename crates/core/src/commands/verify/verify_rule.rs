@@ -0,0 +1,259 @@
+use crate::prelude::*;
+use clap::ValueEnum;
+
+/// Identifier of a rule checked by a verifier.
+///
+/// Every issue except decode errors and issues that prevent the checks from running has a rule.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum VerifyRule {
+    /// Group category must be Music.
+    Category,
+    /// Source must not be a scene release.
+    Scene,
+    /// Source must not look like an unmarked scene release.
+    PossibleScene,
+    /// Source must not need lossy master approval.
+    LossyMaster,
+    /// Source must not need lossy web approval.
+    LossyWeb,
+    /// Source must not be trumpable.
+    Trumpable,
+    /// Source edition must be confirmed.
+    Unconfirmed,
+    /// Group must not have any of the `exclude_tags`.
+    ExcludedTags,
+    /// There must be target formats that don't already exist.
+    NoTargets,
+    /// Number of FLACs must match the torrent.
+    FlacCount,
+    /// FLACs must not share an unnecessary subdirectory.
+    UnnecessaryDirectory,
+    /// Transcode paths must not exceed the maximum length.
+    PathLength,
+    /// FLACs must be readable.
+    FlacError,
+    /// Sample rate must be a multiple of 44.1 or 48 kHz.
+    SampleRate,
+    /// Average bit rate must meet the minimum.
+    BitRate,
+    /// Duration must not exceed the maximum.
+    Duration,
+    /// FLACs must not have more than two channels unless `downmix` is set.
+    Channels,
+    /// CD sources must have an MD5 signature.
+    MissingMd5,
+    /// FLACs must not be MQA encoded.
+    Mqa,
+    /// FLACs must have Vorbis comments.
+    NoTags,
+    /// FLACs must have an artist tag.
+    ArtistTag,
+    /// FLACs must have an album tag.
+    AlbumTag,
+    /// FLACs must have a title tag.
+    TitleTag,
+    /// FLACs of groups with a `composer_tags` tag and credited composers must have a composer tag.
+    ComposerTag,
+    /// FLACs must have a track number tag.
+    TrackNumberTag,
+    /// FLACs of multi-disc sources must have a disc number tag.
+    DiscNumberTag,
+    /// Low-order bits of hi-res FLACs must be used.
+    PaddedBitDepth,
+    /// Hi-res FLACs must have content above the Nyquist frequency of a CD or DAT master.
+    Upsampled,
+    /// FLACs must not have the lowpass shelf of an MP3 or AAC encoder.
+    PossibleLossyMaster,
+    /// Rip logs must score 100.
+    LogScore,
+    /// Test and copy CRCs of rip logs must match.
+    CrcMismatch,
+    /// CRC32 and `AccurateRip` checksums of FLACs must match their rip log.
+    ChecksumMismatch,
+    /// Files referenced by cue sheets must have a matching FLAC.
+    CueMissingFile,
+    /// Number of audio tracks in cue sheets must match the FLACs.
+    CueTrackCount,
+    /// Indexes of cue sheets must be within the FLAC of their file.
+    CueIndexBeyondEnd,
+    /// Hidden track one audio of cue sheets must be ripped.
+    HiddenTrack,
+    /// FLAC images split by their cue sheet must be 16-bit.
+    ImageBitDepth,
+    /// Tracks must not be flagged with pre-emphasis if `pre_emphasis` is `block`.
+    PreEmphasis,
+    /// Files referenced by checksum manifests must exist.
+    ManifestMissingFile,
+    /// Checksums of files must match their checksum manifest.
+    ManifestMismatch,
+    /// Album, album artist and date tags must be consistent across the FLACs.
+    InconsistentTags,
+    /// Track numbers of a disc must be unique.
+    DuplicateTrackNumber,
+    /// Track numbers of a disc must not have gaps.
+    MissingTrackNumbers,
+    /// Track totals must match the FLACs of each disc.
+    TrackTotal,
+    /// Disc total must match the discs with FLACs.
+    DiscTotal,
+    /// Discs up to the highest disc number must have FLACs.
+    MissingDiscs,
+    /// Bit depth and sample rate must be consistent across the FLACs.
+    MixedFormat,
+    /// Artist, album and date tags must match the indexer.
+    TagMismatch,
+}
+
+impl VerifyRule {
+    /// Get the rule of an issue.
+    ///
+    /// Returns `None` for [`SourceIssue::MissingTags`] and [`SourceIssue::InvalidTags`] which
+    /// have a rule per tag, and for issues without a rule such as [`SourceIssue::DecodeError`].
+    #[must_use]
+    pub(crate) fn from_issue(issue: &SourceIssue) -> Option<Self> {
+        let rule = match issue {
+            SourceIssue::Category { .. } => Self::Category,
+            SourceIssue::Scene => Self::Scene,
+            SourceIssue::PossibleScene => Self::PossibleScene,
+            SourceIssue::LossyMaster => Self::LossyMaster,
+            SourceIssue::LossyWeb => Self::LossyWeb,
+            SourceIssue::Trumpable => Self::Trumpable,
+            SourceIssue::Unconfirmed => Self::Unconfirmed,
+            SourceIssue::Excluded { .. } => Self::ExcludedTags,
+            SourceIssue::NoTargets { .. } => Self::NoTargets,
+            SourceIssue::FlacCount { .. } => Self::FlacCount,
+            SourceIssue::UnnecessaryDirectory { .. } => Self::UnnecessaryDirectory,
+            SourceIssue::Length { .. } => Self::PathLength,
+            SourceIssue::FlacError { .. } => Self::FlacError,
+            SourceIssue::SampleRate { .. } => Self::SampleRate,
+            SourceIssue::BitRate { .. } => Self::BitRate,
+            SourceIssue::Duration { .. } => Self::Duration,
+            SourceIssue::Channels { .. } => Self::Channels,
+            SourceIssue::MissingMd5 { .. } => Self::MissingMd5,
+            SourceIssue::Mqa { .. } => Self::Mqa,
+            SourceIssue::NoTags { .. } => Self::NoTags,
+            SourceIssue::PaddedBitDepth { .. } => Self::PaddedBitDepth,
+            SourceIssue::Upsampled { .. } => Self::Upsampled,
+            SourceIssue::PossibleLossyMaster { .. } => Self::PossibleLossyMaster,
+            SourceIssue::LogScore { .. } => Self::LogScore,
+            SourceIssue::CrcMismatch { .. } => Self::CrcMismatch,
+            SourceIssue::ChecksumMismatch { .. } => Self::ChecksumMismatch,
+            SourceIssue::CueMissingFile { .. } => Self::CueMissingFile,
+            SourceIssue::CueTrackCount { .. } => Self::CueTrackCount,
+            SourceIssue::CueIndexBeyondEnd { .. } => Self::CueIndexBeyondEnd,
+            SourceIssue::HiddenTrack { .. } => Self::HiddenTrack,
+            SourceIssue::ImageBitDepth { .. } => Self::ImageBitDepth,
+            SourceIssue::PreEmphasis { .. } => Self::PreEmphasis,
+            SourceIssue::ManifestMissingFile { .. } => Self::ManifestMissingFile,
+            SourceIssue::ManifestMismatch { .. } => Self::ManifestMismatch,
+            SourceIssue::InconsistentTags { .. } => Self::InconsistentTags,
+            SourceIssue::DuplicateTrackNumber { .. } => Self::DuplicateTrackNumber,
+            SourceIssue::MissingTrackNumbers { .. } => Self::MissingTrackNumbers,
+            SourceIssue::TrackTotal { .. } => Self::TrackTotal,
+            SourceIssue::DiscTotal { .. } => Self::DiscTotal,
+            SourceIssue::MissingDiscs { .. } => Self::MissingDiscs,
+            SourceIssue::MixedFormat { .. } => Self::MixedFormat,
+            SourceIssue::TagMismatch { .. } => Self::TagMismatch,
+            _ => return None,
+        };
+        Some(rule)
+    }
+
+    /// Get the rule of a tag reported by [`TagVerifier`].
+    #[must_use]
+    pub(crate) fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "artist" => Some(Self::ArtistTag),
+            "album" => Some(Self::AlbumTag),
+            "title" => Some(Self::TitleTag),
+            "composer" => Some(Self::ComposerTag),
+            "track_number" => Some(Self::TrackNumberTag),
+            "disc_number" => Some(Self::DiscNumberTag),
+            _ => None,
+        }
+    }
+}
+
+impl Display for VerifyRule {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let value = self
+            .to_possible_value()
+            .expect("rules should not be skipped");
+        write!(formatter, "{}", value.get_name())
+    }
+}
+
+/// How an issue found by a [`VerifyRule`] is treated.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum RuleSeverity {
+    /// The rule is not checked.
+    Off,
+    /// The issue is logged but the source is still verified.
+    Warning,
+    /// The source is unsuitable for transcoding.
+    Error,
+}
+
+impl RuleSeverity {
+    /// Get the severity of a rule from [`VerifyOptions`].
+    ///
+    /// Rules in `off_rules` take precedence over `warning_rules`. Every other rule is an error.
+    #[must_use]
+    pub(crate) fn get(rule: VerifyRule, options: &VerifyOptions) -> Self {
+        if options.off_rules.contains(&rule) {
+            Self::Off
+        } else if options.warning_rules.contains(&rule) {
+            Self::Warning
+        } else {
+            Self::Error
+        }
+    }
+
+    /// Split an issue by the severity of its rules.
+    ///
+    /// [`SourceIssue::MissingTags`] and [`SourceIssue::InvalidTags`] are split into an issue per
+    /// severity with the tags of that severity. Issues without a rule are always an error.
+    #[must_use]
+    pub(crate) fn classify(
+        issue: SourceIssue,
+        options: &VerifyOptions,
+    ) -> Vec<(Self, SourceIssue)> {
+        match issue {
+            SourceIssue::MissingTags { path, tags } => split_tags(tags, options)
+                .into_iter()
+                .map(|(severity, tags)| {
+                    let path = path.clone();
+                    (severity, SourceIssue::MissingTags { path, tags })
+                })
+                .collect(),
+            SourceIssue::InvalidTags { path, tags } => split_tags(tags, options)
+                .into_iter()
+                .map(|(severity, tags)| {
+                    let path = path.clone();
+                    (severity, SourceIssue::InvalidTags { path, tags })
+                })
+                .collect(),
+            issue => {
+                let severity = VerifyRule::from_issue(&issue)
+                    .map_or(Self::Error, |rule| Self::get(rule, options));
+                vec![(severity, issue)]
+            }
+        }
+    }
+}
+
+/// Group tags by the severity of their rule, from most to least severe.
+fn split_tags(tags: Vec<String>, options: &VerifyOptions) -> Vec<(RuleSeverity, Vec<String>)> {
+    let mut groups: BTreeMap<RuleSeverity, Vec<String>> = BTreeMap::new();
+    for tag in tags {
+        let severity = VerifyRule::from_tag(&tag)
+            .map_or(RuleSeverity::Error, |rule| RuleSeverity::get(rule, options));
+        groups.entry(severity).or_default().push(tag);
+    }
+    groups.into_iter().rev().collect()
+}
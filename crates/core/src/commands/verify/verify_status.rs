@@ -4,6 +4,10 @@ use crate::prelude::*;
 pub(crate) struct VerifySuccess {
     /// Validation issues found during verification.
     pub issues: Vec<SourceIssue>,
+    /// Issues of rules with a warning severity that don't prevent verification.
+    pub warnings: Vec<SourceIssue>,
+    /// Result of each check.
+    pub checks: Vec<CheckResult>,
 }
 
 impl VerifySuccess {
@@ -21,6 +25,9 @@ pub(crate) struct VerifyStatus {
     /// Validation issues found, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issues: Option<Vec<SourceIssue>>,
+    /// Issues of rules with a warning severity, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<SourceIssue>>,
     /// When the operation completed.
     pub completed: TimeStamp,
 }
@@ -28,16 +35,19 @@ pub(crate) struct VerifyStatus {
 impl VerifyStatus {
     /// Create a [`VerifyStatus`] from a [`VerifySuccess`].
     pub fn from_success(success: VerifySuccess) -> Self {
+        let warnings = (!success.warnings.is_empty()).then_some(success.warnings);
         if success.issues.is_empty() {
             Self {
                 verified: true,
                 issues: None,
+                warnings,
                 completed: TimeStamp::now(),
             }
         } else {
             Self {
                 verified: false,
                 issues: Some(success.issues),
+                warnings,
                 completed: TimeStamp::now(),
             }
         }
@@ -49,6 +59,7 @@ impl VerifyStatus {
         Self {
             verified: true,
             issues: None,
+            warnings: None,
             completed: TimeStamp::now(),
         }
     }
@@ -58,6 +69,7 @@ impl VerifyStatus {
         Self {
             verified: false,
             issues: Some(vec![issue]),
+            warnings: None,
             completed: TimeStamp::now(),
         }
     }
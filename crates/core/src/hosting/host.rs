@@ -23,6 +23,7 @@ impl Host {
     /// 1. Configure logging
     /// 2. Determine the command to execute
     /// 3. Execute the command
+    #[expect(clippy::too_many_lines, reason = "flat dispatch of commands")]
     pub async fn execute(&self) -> Result<bool, Report> {
         let _ = self.services.get_required::<Logger>();
         let shutdown = self.services.get_required::<Shutdown>();
@@ -54,6 +55,12 @@ impl Host {
                 .await
                 .map_err(Report::new),
             Command::Docs => Ok(self.services.get_required::<DocsCommand>().execute()),
+            Command::Explain => self
+                .services
+                .get_required::<ExplainCommand>()
+                .execute_cli()
+                .await
+                .map_err(Report::new),
             Command::Inspect => self
                 .services
                 .get_required::<InspectCommand>()
@@ -87,11 +87,13 @@ impl HostBuilder {
             // Add spectrogram services
             .add(SpectrogramCommand::transient())
             .add(SpectrogramJobFactory::transient())
+            .add(SpectrogramReviewWriter::transient())
             .add(singleton_as_self().from(semaphore_factory))
             .add(singleton_as_self().from(joinset_factory))
             // Add transcode services
             .add(TranscodeCommand::transient())
             .add(TranscodeJobFactory::transient())
+            .add(TranscodeValidator::transient())
             .add(AdditionalJobFactory::transient())
             // Add shared injection services
             .add(TorrentInjector::transient())
@@ -100,10 +102,16 @@ impl HostBuilder {
             // Add verify services
             .add(ApiVerifier::transient())
             .add(ContentVerifier::transient())
-            .add(DecodeVerifier::transient())
+            .add(AudioVerifier::transient())
+            .add(LogVerifier::transient())
+            .add(AlbumVerifier::transient())
+            .add(CueVerifier::transient())
+            .add(ManifestVerifier::transient())
+            .add(TagMatchVerifier::transient())
             .add(FlacVerifier::transient())
             .add(TorrentFileProvider::transient())
             .add(VerifyCommand::transient())
+            .add(ExplainCommand::transient())
             // Add report services
             .add(ReportRenderer::transient())
             .add(SourceReporter::transient())
@@ -44,6 +44,17 @@ pub struct FileOptions {
     /// Only applied if the image is greater than `max_file_size`.
     #[arg(long)]
     pub no_png_to_jpg: bool,
+
+    /// How to handle pictures embedded in the tags of transcoded output.
+    ///
+    /// `keep` embeds the pictures of the source. `strip` removes them. `resize` downscales
+    /// pictures larger than `max_pixel_size` with `jpg_quality`. `embed` adds the folder cover, `cover.jpg`
+    /// or `folder.jpg`, as the front cover if the source has no embedded pictures.
+    ///
+    /// Applies to both FLAC and MP3 output.
+    #[arg(long, value_enum)]
+    #[options(default = ArtworkPolicy::Keep)]
+    pub artwork: ArtworkPolicy,
 }
 
 impl FileOptions {
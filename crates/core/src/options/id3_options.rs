@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+/// Options for the `ID3v2` tags of MP3 output
+#[derive(Options, Clone, Debug, Deserialize, Serialize)]
+pub struct Id3Options {
+    /// Additional mappings of Vorbis comment keys to `ID3v2` text frames of MP3 output.
+    ///
+    /// Each is a key and a frame ID such as `LABEL=TPUB`, or a user defined text frame and its
+    /// description such as `CATALOGNUMBER=TXXX:CATALOG NUMBER`. Every value of the key is
+    /// written to the frame, replacing the frame the key is converted to by default.
+    #[arg(long)]
+    #[options(default = Vec::<Id3Mapping>::new())]
+    pub id3_mapping: Vec<Id3Mapping>,
+
+    /// Separator to join multiple artists of MP3 output with, such as `; `.
+    ///
+    /// By default multiple artists are written as a multi-value `TPE1` frame for `ID3v2.4`,
+    /// and joined with `/` for `ID3v2.3`.
+    #[arg(long)]
+    pub id3_artist_separator: Option<String>,
+
+    /// Separator to join multiple genres of MP3 output with, such as `; `.
+    ///
+    /// By default multiple genres are written as a multi-value `TCON` frame for `ID3v2.4`,
+    /// and joined with `/` for `ID3v2.3`.
+    #[arg(long)]
+    pub id3_genre_separator: Option<String>,
+
+    /// Version of the `ID3v2` tags of MP3 output.
+    ///
+    /// `v2.3` is for players that can't read `v2.4` multi-value frames. Multiple values are
+    /// joined and frames introduced in `v2.4`, such as the sort order frames, are not written.
+    #[arg(long, value_enum)]
+    #[options(default = Id3Version::V24)]
+    pub id3_version: Id3Version,
+}
+
+impl OptionsContract for Id3Options {
+    type Partial = Id3OptionsPartial;
+    fn validate(&self, _validator: &mut OptionsValidator) {}
+}
@@ -9,6 +9,7 @@ pub use copy_options::*;
 pub use cross_config_options::*;
 pub use cross_options::*;
 pub use file_options::*;
+pub use id3_options::*;
 pub(crate) use indexer::*;
 pub(crate) use options_validator_ext::*;
 pub use qbit_cross_options::*;
@@ -36,6 +37,7 @@ mod copy_options;
 mod cross_config_options;
 mod cross_options;
 mod file_options;
+mod id3_options;
 mod indexer;
 mod options_validator_ext;
 mod qbit_cross_options;
@@ -1,5 +1,5 @@
-use crate::Size;
 use crate::prelude::*;
+use crate::{Size, SpectrogramBackend, SpectrogramVariant};
 
 /// Options for spectrograms
 #[derive(Options, Clone, Debug, Deserialize, Serialize)]
@@ -8,6 +8,52 @@ pub struct SpectrogramOptions {
     #[arg(long)]
     #[options(default = vec![Size::Full, Size::Zoom])]
     pub spectrogram_size: Vec<Size>,
+
+    /// Additional spectrograms to generate with a custom channel, zoom window or resolution.
+    ///
+    /// Each is a size optionally followed by a colon and comma separated settings:
+    /// `channel` as `left`, `right`, `mid` or `side`; `width` and `height` in pixels; `range` in
+    /// dB; and for zoom only, `at` as `auto`, a time such as `1:30`, a percentage such as `50%`
+    /// or `loudest`, and `duration` in seconds.
+    ///
+    /// For example `zoom:at=loudest,channel=side` or `full:channel=right,range=140`.
+    #[arg(long)]
+    #[options(default = Vec::<SpectrogramVariant>::new())]
+    pub spectrogram_variant: Vec<SpectrogramVariant>,
+
+    /// Backend to render spectrograms with.
+    ///
+    /// `sox` runs the `SoX` binary. `native` renders in process so `SoX` is not required.
+    #[arg(long, value_enum)]
+    #[options(default = SpectrogramBackend::Sox)]
+    pub spectrogram_backend: SpectrogramBackend,
+
+    /// Should a contact sheet and HTML review page be written for each source?
+    ///
+    /// `contact_sheet.png` composites every spectrogram of the source labelled by disc and
+    /// track. `index.html` lists the audio properties of each track next to its spectrograms.
+    #[arg(long)]
+    pub spectrogram_review: bool,
+}
+
+impl SpectrogramOptions {
+    /// Get the variants to render: each `spectrogram_size` followed by each
+    /// `spectrogram_variant`, without duplicates.
+    #[must_use]
+    pub(crate) fn get_variants(&self) -> Vec<SpectrogramVariant> {
+        let mut variants: Vec<SpectrogramVariant> = Vec::new();
+        let sizes = self
+            .spectrogram_size
+            .iter()
+            .copied()
+            .map(SpectrogramVariant::from);
+        for variant in sizes.chain(self.spectrogram_variant.iter().cloned()) {
+            if !variants.contains(&variant) {
+                variants.push(variant);
+            }
+        }
+        variants
+    }
 }
 
 impl OptionsContract for SpectrogramOptions {
@@ -2,6 +2,7 @@ use crate::prelude::*;
 
 /// Options for transcoding
 #[derive(Options, Clone, Debug, Deserialize, Serialize)]
+#[expect(clippy::struct_excessive_bools, reason = "independent transcode flags")]
 pub struct TargetOptions {
     /// Formats to attempt to transcode to.
     #[arg(long)]
@@ -23,9 +24,67 @@ pub struct TargetOptions {
     /// By default, `SoX` runs in repeatable mode (`-R`) which seeds the dither
     /// random number generator with a fixed value, producing deterministic output.
     /// Set this to `true` to use random dithering instead.
+    ///
+    /// The `native` resample backend follows the same behavior.
     #[arg(long)]
     pub sox_random_dither: bool,
 
+    /// Backend to resample and dither high-resolution sources with.
+    ///
+    /// `sox` runs the `SoX` binary. `native` resamples in process so `SoX` is not required.
+    ///
+    /// `SoX` can only process a whole file so tracks of a FLAC image split by a cue sheet are
+    /// always resampled and de-emphasized with `native`.
+    #[arg(long, value_enum)]
+    #[options(default = ResampleBackend::Sox)]
+    pub resample_backend: ResampleBackend,
+
+    /// How to handle CD sources flagged with pre-emphasis.
+    ///
+    /// Tracks are flagged by the `PRE` flag of a cue sheet or a `PRE_EMPHASIS` tag. `deemphasize`
+    /// applies the standard 50/15 µs de-emphasis filter before encoding MP3 targets so they are
+    /// not overly bright. `block` reports the source as unsuitable for transcoding instead.
+    #[arg(long, value_enum)]
+    #[options(default = PreEmphasisPolicy::Deemphasize)]
+    pub pre_emphasis: PreEmphasisPolicy,
+
+    /// De-emphasize pre-emphasized sources for FLAC targets as well as MP3 targets.
+    ///
+    /// By default the FLAC target is a copy of the source that keeps its pre-emphasis flag.
+    /// Set this to `true` to write a de-emphasized 16-bit FLAC instead. Only applies if
+    /// `pre_emphasis` is `deemphasize`.
+    #[arg(long)]
+    pub deemphasize_flac: bool,
+
+    /// Analyze the replay gain of the source and write it to transcoded output.
+    ///
+    /// Track and album gain and peak are measured with EBU R128 and written as
+    /// `REPLAYGAIN_*` tags. Names listed in `exclude_vorbis_comments` are not written.
+    ///
+    /// FLAC output is copied rather than hard linked so the source is not modified.
+    #[arg(long)]
+    pub replay_gain: bool,
+
+    /// Downmix sources with more than two channels to stereo for MP3 targets.
+    ///
+    /// Channels are mixed with the ITU-R BS.775 coefficients and the LFE channel is
+    /// discarded. FLAC targets keep every channel.
+    ///
+    /// Without this, verify reports multichannel sources as unsuitable for transcoding.
+    #[arg(long)]
+    pub downmix: bool,
+
+    /// Re-encode every 16-bit FLAC target instead of copying the source.
+    ///
+    /// Regardless of this, sources without an MD5 signature, from an outdated encoder,
+    /// or with over 1 MiB of embedded pictures or 64 KiB of padding are re-encoded.
+    /// Re-encoding is lossless and verified against the MD5 of the decoded audio.
+    ///
+    /// Re-encodes use the strongest compression of `flacenc`. If a re-encode that is requested
+    /// or of an outdated encoder is not smaller than the source, the source is copied instead.
+    #[arg(long)]
+    pub reencode_flac: bool,
+
     /// Vorbis comment tag names to exclude from transcoded output.
     #[arg(long)]
     #[options(default = TargetOptions::default_exclude_vorbis_comments())]
@@ -232,6 +232,7 @@ fn target_options_rejects_empty_target_list() {
 fn spectrogram_options_rejects_empty_size_list() {
     let result = SpectrogramOptionsPartial {
         spectrogram_size: Some(vec![]),
+        ..SpectrogramOptionsPartial::default()
     }
     .resolve();
     let errors = result.expect_err("should reject empty size list");
@@ -292,6 +293,12 @@ fn target_options_yaml_round_trip() {
         allow_existing: Some(true),
         allow_less_specific: Some(true),
         sox_random_dither: Some(true),
+        resample_backend: Some(ResampleBackend::Native),
+        pre_emphasis: Some(PreEmphasisPolicy::Block),
+        deemphasize_flac: Some(true),
+        replay_gain: Some(true),
+        downmix: Some(true),
+        reencode_flac: Some(true),
         exclude_vorbis_comments: Some(TargetOptions::default_exclude_vorbis_comments()),
     };
 
@@ -303,12 +310,44 @@ fn target_options_yaml_round_trip() {
     assert_eq!(original.target, parsed.target);
     assert_eq!(original.allow_existing, parsed.allow_existing);
     assert_eq!(original.sox_random_dither, parsed.sox_random_dither);
+    assert_eq!(original.resample_backend, parsed.resample_backend);
+    assert_eq!(original.pre_emphasis, parsed.pre_emphasis);
+    assert_eq!(original.deemphasize_flac, parsed.deemphasize_flac);
+    assert_eq!(original.replay_gain, parsed.replay_gain);
+    assert_eq!(original.downmix, parsed.downmix);
+    assert_eq!(original.reencode_flac, parsed.reencode_flac);
     assert_eq!(
         original.exclude_vorbis_comments,
         parsed.exclude_vorbis_comments
     );
 }
 
+/// Verify `Id3OptionsPartial` round-trips through YAML.
+#[test]
+fn id3_options_yaml_round_trip() {
+    // Arrange
+    let original = Id3OptionsPartial {
+        id3_mapping: Some(vec![
+            "CATALOGNUMBER=TXXX:CATALOG NUMBER"
+                .parse()
+                .expect("should parse"),
+        ]),
+        id3_artist_separator: Some("; ".to_owned()),
+        id3_genre_separator: Some(", ".to_owned()),
+        id3_version: Some(Id3Version::V23),
+    };
+
+    // Act
+    let yaml = yaml_to_string(&original).expect("should serialize");
+    let parsed: Id3OptionsPartial = yaml_from_str(&yaml).expect("should deserialize");
+
+    // Assert
+    assert_eq!(original.id3_mapping, parsed.id3_mapping);
+    assert_eq!(original.id3_artist_separator, parsed.id3_artist_separator);
+    assert_eq!(original.id3_genre_separator, parsed.id3_genre_separator);
+    assert_eq!(original.id3_version, parsed.id3_version);
+}
+
 /// Verify `SharedOptionsPartial` round-trips through YAML.
 #[test]
 fn shared_options_yaml_round_trip() {
@@ -704,6 +743,7 @@ fn valid_shared_options_with_content(content: &str) -> SharedOptionsPartial {
 fn inspect_arg_rejects_nonexistent_path() {
     let result = InspectArgPartial {
         inspect_path: Some(PathBuf::from("/nonexistent/path/that/does/not/exist")),
+        id3_dry_run: None,
     }
     .resolve();
     let errors = result.expect_err("should reject nonexistent path");
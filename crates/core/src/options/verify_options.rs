@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 /// Options for verify
+#[expect(clippy::struct_excessive_bools, reason = "one skip flag per check")]
 #[derive(Options, Clone, Debug, Deserialize, Serialize)]
 pub struct VerifyOptions {
     /// Should the hash check of source files be skipped?
@@ -18,6 +19,130 @@ pub struct VerifyOptions {
     /// By default every audio frame is decoded to detect truncation or corruption.
     #[arg(long)]
     pub no_decode_check: bool,
+
+    /// Should the fake hi-res check of each 24-bit or high sample rate FLAC be skipped?
+    ///
+    /// By default hi-res FLACs are analyzed for zero padded low-order bits and for missing
+    /// ultrasonic content that indicates upsampling from 44.1 or 48 kHz.
+    #[arg(long)]
+    pub no_hi_res_check: bool,
+
+    /// Should the lossy master check of each FLAC be skipped?
+    ///
+    /// By default each FLAC is analyzed for the lowpass shelf that MP3 and AAC encoders apply
+    /// between 15 and 20.5 kHz.
+    #[arg(long)]
+    pub no_lossy_master_check: bool,
+
+    /// Should the MQA check of each FLAC be skipped?
+    ///
+    /// By default each FLAC with two or more channels is scanned for the sync word of the MQA
+    /// side channel in the low bits of its samples. FLACs with an `MQAENCODER` or
+    /// `ORIGINALSAMPLERATE` tag are reported by the `mqa` rule regardless.
+    #[arg(long)]
+    pub no_mqa_check: bool,
+
+    /// Should the rip log check of each CD source be skipped?
+    ///
+    /// By default each EAC or XLD log is scored like the tracker's log checker and test and copy
+    /// CRCs are compared. A score below 100 is a warning unless `log_score` is removed from
+    /// `warning_rules`. If the decode check is enabled the CRC32 and `AccurateRip` checksums of
+    /// each FLAC are also compared to its log.
+    #[arg(long)]
+    pub no_log_check: bool,
+
+    /// Should the cue sheet check of each CD source be skipped?
+    ///
+    /// By default each cue sheet is compared to the FLACs in its directory for track count, file
+    /// references, gaps and hidden track one audio. Tracks flagged with pre-emphasis are reported
+    /// if `pre_emphasis` is `block`. Files renamed after ripping and hidden track one audio are
+    /// warnings unless `cue_missing_file` and `hidden_track` are removed from `warning_rules`.
+    #[arg(long)]
+    pub no_cue_check: bool,
+
+    /// Should the album consistency check of the FLACs be skipped?
+    ///
+    /// By default the album, album artist and date tags must match across every FLAC, track
+    /// numbers must be unique and without gaps on each disc, track and disc totals must match
+    /// the FLACs found, and every FLAC must have the same bit depth and sample rate. FLACs
+    /// without a disc tag are grouped into discs by directory. Only duplicate track numbers
+    /// are an error by default, the rest are warnings.
+    #[arg(long)]
+    pub no_album_check: bool,
+
+    /// Should the check of tags against the indexer be skipped?
+    ///
+    /// By default the artist, album and date tags of each FLAC are compared to the artists,
+    /// name and year of the indexer. Names are compared after decoding HTML entities, Unicode
+    /// normalization and removing punctuation, and must be at least 80% similar. Mismatches are
+    /// warnings unless `tag_mismatch` is removed from `warning_rules`.
+    #[arg(long)]
+    pub no_tag_match_check: bool,
+
+    /// Should the checksum manifest check of each source be skipped?
+    ///
+    /// By default each `.ffp` manifest is compared to the MD5 signature of each FLAC, and each
+    /// `.md5`, `.sfv` and `.sha256` manifest to the checksum of each file. Unlike `.ffp`, the
+    /// other formats hash the whole file so they also fail if the tags were edited after the
+    /// manifest was created.
+    #[arg(long)]
+    pub no_manifest_check: bool,
+
+    /// Should every check run even if an earlier check fails?
+    ///
+    /// By default the hash and FLAC checks are skipped if the API checks fail, the FLAC checks
+    /// are skipped if the hash check fails, and audio analysis is skipped if the FLAC checks
+    /// fail. If set, every check is run and a table of the status of each check is logged.
+    #[arg(long)]
+    pub all_checks: bool,
+
+    /// Verify rules to report as warnings instead of errors.
+    ///
+    /// Warnings are logged but the source is still suitable for transcoding. Every rule can be
+    /// set, for example `possible_scene`, `composer_tag`, `cue_track_count` or
+    /// `duplicate_track_number`. Decode errors are always an error.
+    ///
+    /// Setting this replaces the default rules, so include them to keep them as warnings.
+    #[arg(long, value_enum)]
+    #[options(default = VerifyOptions::default_warning_rules())]
+    pub warning_rules: Vec<VerifyRule>,
+
+    /// Verify rules to turn off.
+    ///
+    /// Takes precedence over `warning_rules`. Unlike the `no_*_check` options this turns off a
+    /// single rule of a check, for example `hidden_track` of the cue sheet check.
+    #[arg(long, value_enum)]
+    pub off_rules: Vec<VerifyRule>,
+
+    /// Group tags that require a composer tag on each FLAC.
+    ///
+    /// The composer tag is only required if the group also credits a composer.
+    #[arg(long)]
+    #[options(default = vec!["classical".to_owned()])]
+    pub composer_tags: Vec<String>,
+}
+
+impl VerifyOptions {
+    /// Default verify rules to report as warnings.
+    ///
+    /// Rules of issues that don't make a source unsuitable for transcoding, or of heuristic
+    /// checks that can't tell a problem from a legitimate source.
+    #[must_use]
+    pub fn default_warning_rules() -> Vec<VerifyRule> {
+        vec![
+            VerifyRule::PossibleLossyMaster,
+            VerifyRule::LogScore,
+            VerifyRule::CueMissingFile,
+            VerifyRule::HiddenTrack,
+            VerifyRule::TagMismatch,
+            VerifyRule::InconsistentTags,
+            VerifyRule::MissingTrackNumbers,
+            VerifyRule::TrackTotal,
+            VerifyRule::DiscTotal,
+            VerifyRule::MissingDiscs,
+            VerifyRule::MixedFormat,
+        ]
+    }
 }
 
 impl OptionsContract for VerifyOptions {
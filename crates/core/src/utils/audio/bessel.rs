@@ -0,0 +1,20 @@
+/// Relative size of the last term of [`bessel_i0`], below the precision of `f64`.
+const TOLERANCE: f64 = 1e-21;
+
+/// Zeroth order modified Bessel function of the first kind.
+///
+/// Used for the Kaiser windows of the resampler and the spectrogram. The series is summed
+/// until a term no longer changes the result.
+#[must_use]
+pub(crate) fn bessel_i0(x: f64) -> f64 {
+    let half = x / 2.0;
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * TOLERANCE {
+        term *= (half / k) * (half / k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
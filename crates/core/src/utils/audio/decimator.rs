@@ -0,0 +1,139 @@
+use crate::prelude::*;
+use std::f64::consts::PI;
+
+/// Stopband attenuation in dB, matching `SoX` very high quality (`rate -v`).
+const ATTENUATION: f64 = 175.0;
+
+/// Fraction of the output Nyquist frequency that is passed, matching `SoX` `rate -v`.
+const PASSBAND: f64 = 0.95;
+
+/// Number of consumed samples to accumulate before compacting the buffer.
+const COMPACT_THRESHOLD: usize = 1 << 16;
+
+/// Low-pass filter and decimate a single channel by an integer factor.
+///
+/// - Linear phase windowed sinc FIR, equivalent to `SoX` `rate -v -L`
+/// - The filter delay is compensated so output is aligned with input
+/// - Output length is the input length divided by the factor, rounded up
+/// - A factor of 1 passes samples through unfiltered
+pub(crate) struct Decimator {
+    factor: usize,
+    taps: Vec<f64>,
+    buffer: Vec<f64>,
+    consumed: usize,
+    received: usize,
+    produced: usize,
+}
+
+impl Decimator {
+    /// Create a [`Decimator`] for an integer decimation `factor`.
+    #[must_use]
+    pub(crate) fn new(factor: u32) -> Self {
+        let taps = design_taps(factor);
+        let delay = taps.len().div_ceil(2) - 1;
+        Self {
+            factor: usize::try_from(factor).expect("factor should fit in usize"),
+            taps,
+            buffer: vec![0.0; delay],
+            consumed: 0,
+            received: 0,
+            produced: 0,
+        }
+    }
+
+    /// Push an input sample and return an output sample if one is ready.
+    pub(crate) fn push(&mut self, sample: f64) -> Option<f64> {
+        self.buffer.push(sample);
+        self.received += 1;
+        self.next_output()
+    }
+
+    /// Flush the filter and return the remaining output samples.
+    pub(crate) fn finish(&mut self) -> Vec<f64> {
+        let total = self.received.div_ceil(self.factor);
+        let mut output = Vec::new();
+        while self.produced < total {
+            self.buffer.push(0.0);
+            if let Some(sample) = self.next_output() {
+                output.push(sample);
+            }
+        }
+        output
+    }
+
+    fn next_output(&mut self) -> Option<f64> {
+        let start = self.produced * self.factor - self.consumed;
+        let window = self.buffer.get(start..start + self.taps.len())?;
+        let sample = dot(&self.taps, window);
+        self.produced += 1;
+        if start >= COMPACT_THRESHOLD {
+            self.buffer.drain(..start);
+            self.consumed += start;
+        }
+        Some(sample)
+    }
+}
+
+/// Design a Kaiser windowed sinc low-pass filter for decimation by `factor`.
+///
+/// The taps are normalized for unity gain at DC.
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn design_taps(factor: u32) -> Vec<f64> {
+    if factor <= 1 {
+        return vec![1.0];
+    }
+    let nyquist = 0.5 / f64::from(factor);
+    let transition = nyquist * (1.0 - PASSBAND);
+    let cutoff = nyquist - transition / 2.0;
+    let order = ((ATTENUATION - 7.95) / (14.36 * transition)).ceil() as u32;
+    let order = order + order % 2;
+    let beta = 0.1102 * (ATTENUATION - 8.7);
+    let center = f64::from(order) / 2.0;
+    let denominator = bessel_i0(beta);
+    let mut taps: Vec<f64> = (0..=order)
+        .map(|n| {
+            let x = f64::from(n) - center;
+            let ratio = x / center;
+            let window = bessel_i0(beta * (1.0 - ratio * ratio).max(0.0).sqrt()) / denominator;
+            2.0 * cutoff * sinc(2.0 * cutoff * x) * window
+        })
+        .collect();
+    let sum: f64 = taps.iter().sum();
+    for tap in &mut taps {
+        *tap /= sum;
+    }
+    taps
+}
+
+/// Normalized sinc function.
+fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        let pi_x = PI * x;
+        pi_x.sin() / pi_x
+    }
+}
+
+/// Dot product with four independent accumulators so the loop can be vectorized.
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    let mut sums = [0.0; 4];
+    let chunks_a = a.chunks_exact(4);
+    let chunks_b = b.chunks_exact(4);
+    let remainder: f64 = chunks_a
+        .remainder()
+        .iter()
+        .zip(chunks_b.remainder())
+        .map(|(x, y)| x * y)
+        .sum();
+    for (x, y) in chunks_a.zip(chunks_b) {
+        for ((sum, x), y) in sums.iter_mut().zip(x).zip(y) {
+            *sum += x * y;
+        }
+    }
+    sums.iter().sum::<f64>() + remainder
+}
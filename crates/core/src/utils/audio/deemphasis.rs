@@ -0,0 +1,45 @@
+/// Time constant in seconds of the pole of the CD pre-emphasis curve.
+const POLE_TIME_CONSTANT: f64 = 50e-6;
+
+/// Time constant in seconds of the zero of the CD pre-emphasis curve.
+const ZERO_TIME_CONSTANT: f64 = 15e-6;
+
+/// Undo the 50/15 µs pre-emphasis of a single channel of CD audio.
+///
+/// - First order high shelf cut of about 10 dB, the inverse of IEC 60908 pre-emphasis
+/// - Designed with the matched-z transform so the gain is exactly 1 at DC and within 0.5 dB
+///   of the analog curve up to 20 kHz at 44.1 kHz
+/// - Equivalent to the `SoX` `deemph` effect, which only supports 44.1 kHz
+pub(crate) struct DeemphasisFilter {
+    gain: f64,
+    zero: f64,
+    pole: f64,
+    previous_input: f64,
+    previous_output: f64,
+}
+
+impl DeemphasisFilter {
+    /// Create a [`DeemphasisFilter`] for audio at `sample_rate`.
+    #[must_use]
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        let period = 1.0 / f64::from(sample_rate);
+        let zero = (-period / ZERO_TIME_CONSTANT).exp();
+        let pole = (-period / POLE_TIME_CONSTANT).exp();
+        Self {
+            gain: (1.0 - pole) / (1.0 - zero),
+            zero,
+            pole,
+            previous_input: 0.0,
+            previous_output: 0.0,
+        }
+    }
+
+    /// Filter a sample and return the output sample.
+    pub(crate) fn process(&mut self, sample: f64) -> f64 {
+        let output = self.gain * (sample - self.zero * self.previous_input)
+            + self.pole * self.previous_output;
+        self.previous_input = sample;
+        self.previous_output = output;
+        output
+    }
+}
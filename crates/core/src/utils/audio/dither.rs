@@ -0,0 +1,68 @@
+use std::process::id as process_id;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Fixed seed used in repeatable mode, equivalent to `SoX` `-R`.
+const REPEATABLE_SEED: u64 = 0x5EED_CAE5_0DA7_A5E7;
+
+/// Largest 16-bit sample value.
+pub(crate) const MAX_SAMPLE: f64 = 32767.0;
+
+/// Smallest 16-bit sample value.
+const MIN_SAMPLE: f64 = -32768.0;
+
+/// Quantize to 16-bit with triangular probability density function (TPDF) dither.
+///
+/// Equivalent to the `SoX` `dither` effect with default settings:
+/// - Noise is the difference of two uniform values, spanning ±1 LSB
+/// - Noise is added before rounding to the nearest integer
+/// - Output is clamped to the 16-bit range
+pub(crate) struct TpdfDither {
+    state: u64,
+}
+
+impl TpdfDither {
+    /// Create a [`TpdfDither`].
+    ///
+    /// If `repeatable` the generator is seeded with a fixed value so output is deterministic,
+    /// otherwise it is seeded from the clock and process id.
+    #[must_use]
+    pub(crate) fn new(repeatable: bool) -> Self {
+        if repeatable {
+            Self::with_seed(REPEATABLE_SEED)
+        } else {
+            Self::with_seed(random_seed())
+        }
+    }
+
+    /// Create a [`TpdfDither`] with a specific seed.
+    #[must_use]
+    pub(crate) fn with_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Quantize a sample, scaled so 1.0 is one 16-bit LSB, to a dithered 16-bit value.
+    #[allow(clippy::as_conversions, clippy::cast_possible_truncation)]
+    pub(crate) fn quantize(&mut self, sample: f64) -> i16 {
+        let noise = self.next_uniform() - self.next_uniform();
+        (sample + noise).round().clamp(MIN_SAMPLE, MAX_SAMPLE) as i16
+    }
+
+    /// Next uniform value in `[0, 1)` from a `SplitMix64` generator.
+    fn next_uniform(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        let high = u32::try_from(z >> 32).expect("upper 32 bits should fit in u32");
+        f64::from(high) / f64::from(u32::MAX) * (1.0 - f64::EPSILON)
+    }
+}
+
+fn random_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or_default();
+    (u64::from(nanos) << 32) ^ u64::from(process_id()) ^ REPEATABLE_SEED
+}
@@ -0,0 +1,66 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+/// Gain of the center and surround channels in the downmix, -3 dB.
+const MIX_GAIN: f64 = FRAC_1_SQRT_2;
+
+/// Downmix multichannel audio to stereo with the ITU-R BS.775 coefficients.
+///
+/// - Channels are in the FLAC channel order for each channel count
+/// - Front left and right are mixed at unity gain
+/// - Center and surround channels are mixed at -3 dB, with a back center to both sides
+/// - The LFE channel is discarded
+///
+/// Output may exceed full scale so gain must be applied before quantizing.
+pub(crate) struct Downmixer {
+    left: Vec<f64>,
+    right: Vec<f64>,
+}
+
+impl Downmixer {
+    /// Create a [`Downmixer`] for a FLAC with `channels`.
+    ///
+    /// Returns `None` for mono and stereo, which are not downmixed, or more than 8 channels
+    /// which FLAC does not support.
+    #[must_use]
+    pub(crate) fn new(channels: u32) -> Option<Self> {
+        let g = MIX_GAIN;
+        let (left, right) = match channels {
+            // L R C
+            3 => (vec![1.0, 0.0, g], vec![0.0, 1.0, g]),
+            // L R Ls Rs
+            4 => (vec![1.0, 0.0, g, 0.0], vec![0.0, 1.0, 0.0, g]),
+            // L R C Ls Rs
+            5 => (vec![1.0, 0.0, g, g, 0.0], vec![0.0, 1.0, g, 0.0, g]),
+            // L R C LFE Ls Rs
+            6 => (
+                vec![1.0, 0.0, g, 0.0, g, 0.0],
+                vec![0.0, 1.0, g, 0.0, 0.0, g],
+            ),
+            // L R C LFE Cs Ls Rs
+            7 => (
+                vec![1.0, 0.0, g, 0.0, g, g, 0.0],
+                vec![0.0, 1.0, g, 0.0, g, 0.0, g],
+            ),
+            // L R C LFE Lb Rb Ls Rs
+            8 => (
+                vec![1.0, 0.0, g, 0.0, g, 0.0, g, 0.0],
+                vec![0.0, 1.0, g, 0.0, 0.0, g, 0.0, g],
+            ),
+            _ => return None,
+        };
+        Some(Self { left, right })
+    }
+
+    /// Mix a frame with one sample per channel to a left and right sample.
+    #[must_use]
+    pub(crate) fn process(&self, frame: &[f64]) -> (f64, f64) {
+        let mix = |coefficients: &[f64]| -> f64 {
+            coefficients
+                .iter()
+                .zip(frame)
+                .map(|(coefficient, sample)| coefficient * sample)
+                .sum()
+        };
+        (mix(&self.left), mix(&self.right))
+    }
+}
@@ -0,0 +1,119 @@
+use std::fs::File;
+use std::io::{BufReader, Error as IoError, ErrorKind, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Marker at the start of every FLAC stream.
+const FLAC_MARKER: &[u8; 4] = b"fLaC";
+
+/// Metadata block type of a `PADDING` block.
+const PADDING: u8 = 1;
+
+/// Metadata block type of a `VORBIS_COMMENT` block.
+const VORBIS_COMMENT: u8 = 4;
+
+/// Metadata block type of a `PICTURE` block.
+const PICTURE: u8 = 6;
+
+/// Prefix of the vendor string written by the reference encoder.
+const LIBFLAC_VENDOR: &str = "reference libFLAC ";
+
+/// Earliest version of the reference encoder that is not outdated.
+///
+/// Earlier releases could write invalid seek tables and predate the `--best` preset of
+/// current releases.
+const MIN_LIBFLAC_VERSION: (u32, u32) = (1, 2);
+
+/// Summary of the metadata blocks of a FLAC file that affect whether it is worth re-encoding.
+///
+/// Refer to the [FLAC format specification](https://www.rfc-editor.org/rfc/rfc9639).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct FlacMetadata {
+    /// Vendor string of the `VORBIS_COMMENT` block, identifying the encoder.
+    pub vendor: Option<String>,
+    /// Total size in bytes of the `PICTURE` blocks.
+    pub picture_bytes: u64,
+    /// Total size in bytes of the `PADDING` blocks.
+    pub padding_bytes: u64,
+    /// Size in bytes of the audio frames after the metadata blocks.
+    pub audio_bytes: u64,
+}
+
+impl FlacMetadata {
+    /// Read the metadata blocks of the FLAC file at `path`.
+    pub(crate) fn read(path: &Path) -> Result<Self, IoError> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Read the metadata blocks of a FLAC stream, skipping the content of every block except
+    /// the `VORBIS_COMMENT` block.
+    pub(crate) fn read_from(mut reader: impl Read + Seek) -> Result<Self, IoError> {
+        let mut marker = [0_u8; 4];
+        reader.read_exact(&mut marker)?;
+        if &marker != FLAC_MARKER {
+            return Err(IoError::new(ErrorKind::InvalidData, "not a FLAC stream"));
+        }
+        let mut metadata = Self::default();
+        loop {
+            let mut header = [0_u8; 4];
+            reader.read_exact(&mut header)?;
+            let [flags, length @ ..] = header;
+            let is_last = flags & 0x80 != 0;
+            let length = u32::from_be_bytes([0, length[0], length[1], length[2]]);
+            match flags & 0x7F {
+                PADDING => {
+                    metadata.padding_bytes += u64::from(length);
+                    reader.seek(SeekFrom::Current(i64::from(length)))?;
+                }
+                PICTURE => {
+                    metadata.picture_bytes += u64::from(length);
+                    reader.seek(SeekFrom::Current(i64::from(length)))?;
+                }
+                VORBIS_COMMENT => {
+                    let mut block = vec![0_u8; usize::try_from(length).unwrap_or_default()];
+                    reader.read_exact(&mut block)?;
+                    metadata.vendor = parse_vendor(&block);
+                }
+                _ => {
+                    reader.seek(SeekFrom::Current(i64::from(length)))?;
+                }
+            }
+            if is_last {
+                let start = reader.stream_position()?;
+                metadata.audio_bytes = reader.seek(SeekFrom::End(0))?.saturating_sub(start);
+                return Ok(metadata);
+            }
+        }
+    }
+
+    /// Check if the vendor string is of a release of the reference encoder before
+    /// [`MIN_LIBFLAC_VERSION`].
+    ///
+    /// Other encoders are not considered outdated as their versions can't be compared.
+    #[must_use]
+    pub(crate) fn is_outdated_encoder(&self) -> bool {
+        let Some(version) = self
+            .vendor
+            .as_deref()
+            .and_then(|vendor| vendor.strip_prefix(LIBFLAC_VENDOR))
+        else {
+            return false;
+        };
+        let mut parts = version
+            .split(|c: char| !c.is_ascii_digit())
+            .map(str::parse::<u32>);
+        match (parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor))) => (major, minor) < MIN_LIBFLAC_VERSION,
+            _ => false,
+        }
+    }
+}
+
+/// Parse the vendor string at the start of a `VORBIS_COMMENT` block.
+///
+/// Unlike the rest of the FLAC stream, the length is little-endian.
+fn parse_vendor(block: &[u8]) -> Option<String> {
+    let length: [u8; 4] = block.get(..4)?.try_into().ok()?;
+    let length = usize::try_from(u32::from_le_bytes(length)).ok()?;
+    let vendor = block.get(4..4 + length)?;
+    Some(String::from_utf8_lossy(vendor).into_owned())
+}
@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+/// Size in bytes of the LAME extension of a Xing header.
+pub(crate) const LAME_TAG_SIZE: usize = 36;
+
+/// Length of the encoder version string.
+const ENCODER_SIZE: usize = 9;
+
+/// Offset of the info tag CRC within the LAME extension.
+const TAG_CRC_OFFSET: usize = 34;
+
+/// Preset value written by `lame -V 0`.
+///
+/// `lame` applies preset `500 - 10 * n` for `-V n`, and the bitrate for `-b n`.
+const V0_PRESET: u16 = 500;
+
+/// LAME extension of the Xing header in the first MPEG frame.
+///
+/// Refer to the [LAME tag specification](http://gabriel.mp3-tech.org/mp3infotag.html).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LameTag {
+    /// Encoder version string, e.g. `LAME3.100`.
+    pub encoder: String,
+    /// Info tag revision.
+    pub revision: u8,
+    /// VBR method as a numeric code.
+    pub vbr_method: u8,
+    /// Lowpass filter frequency in Hz.
+    pub lowpass: u32,
+    /// Number of samples of encoder delay at the start of the stream.
+    pub encoder_delay: u16,
+    /// Number of samples of padding at the end of the stream.
+    pub padding: u16,
+    /// Preset value, either a bitrate or a named preset code.
+    pub preset: u16,
+    /// Length in bytes of the audio data including the info frame.
+    pub music_length: u32,
+    /// CRC-16 of the audio data.
+    pub music_crc: u16,
+    /// Whether the CRC-16 of the info frame matches the stored value.
+    pub is_tag_crc_valid: bool,
+}
+
+impl LameTag {
+    /// Parse a [`LameTag`] from an MPEG `frame` where the extension begins at `offset`.
+    ///
+    /// Returns `None` if the encoder version is not printable ASCII, as it is then not a LAME tag.
+    #[must_use]
+    pub(crate) fn parse(frame: &[u8], offset: usize) -> Option<Self> {
+        let tag: [u8; LAME_TAG_SIZE] =
+            frame.get(offset..offset + LAME_TAG_SIZE)?.try_into().ok()?;
+        let encoder = &tag[..ENCODER_SIZE];
+        if !encoder.first().is_some_and(u8::is_ascii_alphabetic)
+            || !encoder
+                .iter()
+                .all(|byte| byte.is_ascii_graphic() || *byte == b' ' || *byte == 0)
+        {
+            return None;
+        }
+        let encoder = String::from_utf8_lossy(encoder)
+            .trim_end_matches(['\0', ' '])
+            .to_owned();
+        let crc_end = offset + TAG_CRC_OFFSET;
+        let tag_crc = u16::from_be_bytes([tag[TAG_CRC_OFFSET], tag[TAG_CRC_OFFSET + 1]]);
+        let is_tag_crc_valid = crc16(frame.get(..crc_end)?) == tag_crc;
+        Some(Self {
+            encoder,
+            revision: tag[9] >> 4,
+            vbr_method: tag[9] & 0x0F,
+            lowpass: u32::from(tag[10]) * 100,
+            encoder_delay: (u16::from(tag[21]) << 4) | (u16::from(tag[22]) >> 4),
+            padding: (u16::from(tag[22] & 0x0F) << 8) | u16::from(tag[23]),
+            preset: u16::from_be_bytes([tag[26], tag[27]]) & 0x07FF,
+            music_length: u32::from_be_bytes([tag[28], tag[29], tag[30], tag[31]]),
+            music_crc: u16::from_be_bytes([tag[32], tag[33]]),
+            is_tag_crc_valid,
+        })
+    }
+
+    /// Get the preset value `lame` writes for a [`TargetFormat`].
+    #[must_use]
+    pub(crate) fn get_expected_preset(target: TargetFormat) -> Option<u16> {
+        match target {
+            TargetFormat::_320 => Some(320),
+            TargetFormat::V0 => Some(V0_PRESET),
+            TargetFormat::Flac => None,
+        }
+    }
+
+    /// Format the VBR method for display.
+    #[must_use]
+    pub(crate) fn format_vbr_method(&self) -> String {
+        let method = match self.vbr_method {
+            1 => "CBR",
+            2 => "ABR",
+            3 => "VBR rh",
+            4 => "VBR mtrh",
+            5 => "VBR mt",
+            8 => "CBR 2-pass",
+            9 => "ABR 2-pass",
+            _ => "Unknown",
+        };
+        method.to_owned()
+    }
+
+    /// Format the preset as the equivalent `lame` option.
+    #[must_use]
+    #[expect(
+        clippy::integer_division,
+        reason = "preset is a multiple of 10 in the V range"
+    )]
+    pub(crate) fn format_preset(&self) -> String {
+        match self.preset {
+            0 => "Unknown".to_owned(),
+            8..=320 => format!("-b {}", self.preset),
+            preset @ 410..=V0_PRESET if preset % 10 == 0 => {
+                format!("-V {}", (V0_PRESET - preset) / 10)
+            }
+            1000 => "r3mix".to_owned(),
+            1001 => "standard".to_owned(),
+            1002 => "extreme".to_owned(),
+            1003 => "insane".to_owned(),
+            1004 => "fast standard".to_owned(),
+            1005 => "fast extreme".to_owned(),
+            1006 => "medium".to_owned(),
+            1007 => "fast medium".to_owned(),
+            preset => preset.to_string(),
+        }
+    }
+}
+
+/// CRC-16 as used by the LAME tag, with polynomial `0x8005` reflected and zero initial value.
+#[must_use]
+pub(crate) fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0, |crc, byte| {
+        let mut crc = crc ^ u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xA001
+            } else {
+                crc >> 1
+            };
+        }
+        crc
+    })
+}
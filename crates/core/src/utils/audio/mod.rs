@@ -0,0 +1,29 @@
+//! In process audio signal processing.
+
+pub(crate) use bessel::*;
+pub(crate) use decimator::*;
+pub(crate) use deemphasis::*;
+pub(crate) use dither::*;
+pub(crate) use downmix::*;
+pub(crate) use flac_metadata::*;
+pub(crate) use lame_tag::*;
+pub(crate) use mqa_detector::*;
+pub(crate) use power_spectrum::*;
+pub(crate) use rip_checksum::*;
+pub(crate) use sample_range::*;
+pub(crate) use xing_header::*;
+
+mod bessel;
+mod decimator;
+mod deemphasis;
+mod dither;
+mod downmix;
+mod flac_metadata;
+mod lame_tag;
+mod mqa_detector;
+mod power_spectrum;
+mod rip_checksum;
+mod sample_range;
+#[cfg(test)]
+mod tests;
+mod xing_header;
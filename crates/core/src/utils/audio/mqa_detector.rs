@@ -0,0 +1,83 @@
+use crate::prelude::*;
+
+/// Vorbis comments written by MQA encoders.
+pub(crate) const MQA_TAGS: [&str; 2] = ["MQAENCODER", "ORIGINALSAMPLERATE"];
+
+/// Sync word that starts each block of the MQA side channel.
+pub(crate) const MQA_SYNC_WORD: u64 = 0xb_e049_8c88;
+
+/// Mask of the 36 bits of [`MQA_SYNC_WORD`].
+const SYNC_MASK: u64 = 0xf_ffff_ffff;
+
+/// Number of times [`MQA_SYNC_WORD`] must be found at the same bit position.
+///
+/// The side channel repeats the sync word so requiring more than one match rules out a chance
+/// match in 36 bits of noise.
+const MIN_SYNC_MATCHES: u32 = 2;
+
+/// Detect the MQA side channel hidden in the low bits of decoded samples.
+///
+/// MQA encodes its side channel in the XOR of a bit of the left and right channels, so each bit
+/// position is scanned for [`MQA_SYNC_WORD`].
+pub(crate) struct MqaDetector {
+    buffers: Vec<u64>,
+    matches: Vec<u32>,
+}
+
+impl MqaDetector {
+    /// Create a [`MqaDetector`] scanning every bit position of a stream.
+    #[must_use]
+    pub(crate) fn new(bits_per_sample: u32) -> Self {
+        let positions = usize::try_from(bits_per_sample).expect("bit depth should fit in usize");
+        Self {
+            buffers: vec![0; positions],
+            matches: vec![0; positions],
+        }
+    }
+
+    /// Add an interleaved frame with one sample per channel.
+    ///
+    /// Frames with fewer than two channels are ignored.
+    pub(crate) fn push_frame(&mut self, samples: &[i32]) {
+        let (Some(left), Some(right)) = (samples.first(), samples.get(1)) else {
+            return;
+        };
+        let bits = left ^ right;
+        for (position, (buffer, matches)) in self
+            .buffers
+            .iter_mut()
+            .zip(self.matches.iter_mut())
+            .enumerate()
+        {
+            let bit = u64::from((bits >> position) & 1 == 1);
+            *buffer = ((*buffer << 1) | bit) & SYNC_MASK;
+            if *buffer == MQA_SYNC_WORD {
+                *matches += 1;
+            }
+        }
+    }
+
+    /// Bit position of the MQA side channel, counted from the least significant bit.
+    ///
+    /// Returns `None` if no position has [`MIN_SYNC_MATCHES`] matches.
+    #[must_use]
+    pub(crate) fn get_position(&self) -> Option<u32> {
+        let position = self
+            .matches
+            .iter()
+            .position(|matches| *matches >= MIN_SYNC_MATCHES)?;
+        u32::try_from(position).ok()
+    }
+}
+
+/// Get the first MQA Vorbis comment of a FLAC, formatted as `KEY=value`.
+///
+/// Returns `None` if the FLAC has none of [`MQA_TAGS`].
+pub(crate) fn get_mqa_tag(path: &Path) -> Result<Option<String>, Failure<TagsAction>> {
+    for key in MQA_TAGS {
+        if let Some(value) = get_vorbis_comment(path, key)? {
+            return Ok(Some(format!("{key}={value}")));
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,115 @@
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+/// Number of samples in each FFT window.
+pub(crate) const FFT_SIZE: usize = 4096;
+
+/// Number of bins from DC to the Nyquist frequency.
+#[expect(clippy::integer_division, reason = "FFT_SIZE is even")]
+const BINS: usize = FFT_SIZE / 2 + 1;
+
+/// Power level in dB reported for a band without energy.
+const SILENCE_DB: f64 = -200.0;
+
+/// Average power spectrum of a mono signal.
+///
+/// - Samples are split into consecutive non-overlapping windows of [`FFT_SIZE`]
+/// - Each window is multiplied by a Hann window before the FFT
+/// - The power of each bin is averaged across every complete window
+pub(crate) struct PowerSpectrum {
+    sample_rate: u32,
+    fft: Arc<dyn Fft<f64>>,
+    window: Vec<f64>,
+    buffer: Vec<Complex<f64>>,
+    power: Vec<f64>,
+    windows: u32,
+}
+
+impl PowerSpectrum {
+    /// Create a [`PowerSpectrum`] for a signal at `sample_rate`.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub(crate) fn new(sample_rate: u32) -> Self {
+        let size = FFT_SIZE as f64;
+        let window = (0..FFT_SIZE)
+            .map(|index| 0.5 - 0.5 * (2.0 * PI * index as f64 / size).cos())
+            .collect();
+        Self {
+            sample_rate,
+            fft: FftPlanner::new().plan_fft_forward(FFT_SIZE),
+            window,
+            buffer: Vec::with_capacity(FFT_SIZE),
+            power: vec![0.0; BINS],
+            windows: 0,
+        }
+    }
+
+    /// Add a sample, scaled so 1.0 is full scale.
+    pub(crate) fn push(&mut self, sample: f64) {
+        let weight = self
+            .window
+            .get(self.buffer.len())
+            .copied()
+            .unwrap_or_default();
+        self.buffer.push(Complex::new(sample * weight, 0.0));
+        if self.buffer.len() == FFT_SIZE {
+            self.fft.process(&mut self.buffer);
+            for (power, bin) in self.power.iter_mut().zip(&self.buffer) {
+                *power += bin.norm_sqr();
+            }
+            self.buffer.clear();
+            self.windows += 1;
+        }
+    }
+
+    /// Number of complete windows analyzed.
+    #[must_use]
+    pub(crate) fn windows(&self) -> u32 {
+        self.windows
+    }
+
+    /// Frequency in Hz of the center of each bin.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub(crate) fn get_frequency(&self, bin: usize) -> f64 {
+        bin as f64 * f64::from(self.sample_rate) / FFT_SIZE as f64
+    }
+
+    /// Average power in dB of the bins from `low` to `high` Hz.
+    ///
+    /// Returns `None` if no bins are in the band.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub(crate) fn get_band_level(&self, low: f64, high: f64) -> Option<f64> {
+        let bins: Vec<f64> = self
+            .power
+            .iter()
+            .enumerate()
+            .filter(|(bin, _)| {
+                let frequency = self.get_frequency(*bin);
+                frequency >= low && frequency <= high
+            })
+            .map(|(_, power)| *power)
+            .collect();
+        if bins.is_empty() {
+            return None;
+        }
+        let mean = bins.iter().sum::<f64>() / bins.len() as f64;
+        Some(self.to_db(mean))
+    }
+
+    /// Convert a summed bin power to dB relative to a full scale sine.
+    ///
+    /// A full scale sine with a Hann window peaks at `(FFT_SIZE / 4)²` in a single bin.
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    fn to_db(&self, power: f64) -> f64 {
+        if self.windows == 0 || power <= 0.0 {
+            return SILENCE_DB;
+        }
+        let reference = (FFT_SIZE as f64 / 4.0).powi(2);
+        let mean = power / f64::from(self.windows);
+        (10.0 * (mean / reference).log10()).max(SILENCE_DB)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::prelude::*;
+use crc32fast::Hasher as Crc32;
+
+/// Samples per CD sector.
+const SAMPLES_PER_SECTOR: u32 = 588;
+
+/// Samples excluded from the `AccurateRip` checksum at the start of the first track and the end
+/// of the last track, as drives cannot read them consistently.
+const ACCURATE_RIP_SKIP: u32 = SAMPLES_PER_SECTOR * 5;
+
+/// Checksums of the audio of a track as reported by EAC and XLD.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct RipChecksums {
+    /// CRC32 of every sample.
+    pub crc32: u32,
+    /// CRC32 excluding samples that are zero.
+    pub crc32_skip_zero: u32,
+    /// `AccurateRip` v1 checksum.
+    pub accurate_rip_v1: u32,
+    /// `AccurateRip` v2 checksum.
+    pub accurate_rip_v2: u32,
+}
+
+/// Calculate [`RipChecksums`] of 16-bit stereo samples of a CD track.
+pub(crate) struct RipChecksumCalculator {
+    crc32: Crc32,
+    crc32_skip_zero: Crc32,
+    accurate_rip_v1: u32,
+    accurate_rip_v2: u32,
+    position: u32,
+    check_start: u32,
+    check_end: u32,
+    bytes: Vec<u8>,
+    non_zero_bytes: Vec<u8>,
+}
+
+impl RipChecksumCalculator {
+    /// Create a calculator for a track of `samples` per channel.
+    ///
+    /// The `AccurateRip` checksum excludes the first five sectors of the first track and the
+    /// last five sectors of the last track.
+    #[must_use]
+    pub(crate) fn new(samples: u32, is_first: bool, is_last: bool) -> Self {
+        Self {
+            crc32: Crc32::new(),
+            crc32_skip_zero: Crc32::new(),
+            accurate_rip_v1: 0,
+            accurate_rip_v2: 0,
+            position: 0,
+            check_start: if is_first { ACCURATE_RIP_SKIP - 1 } else { 0 },
+            check_end: if is_last {
+                samples.saturating_sub(ACCURATE_RIP_SKIP)
+            } else {
+                samples
+            },
+            bytes: Vec::new(),
+            non_zero_bytes: Vec::new(),
+        }
+    }
+
+    /// Add the samples of a block as left and right pairs.
+    pub(crate) fn push(&mut self, samples: impl Iterator<Item = (i16, i16)>) {
+        self.bytes.clear();
+        self.non_zero_bytes.clear();
+        for (left, right) in samples {
+            let left = left.to_le_bytes();
+            let right = right.to_le_bytes();
+            self.bytes.extend_from_slice(&left);
+            self.bytes.extend_from_slice(&right);
+            for sample in [left, right] {
+                if sample != [0, 0] {
+                    self.non_zero_bytes.extend_from_slice(&sample);
+                }
+            }
+            self.position += 1;
+            if self.position >= self.check_start && self.position <= self.check_end {
+                let value = u32::from_le_bytes([left[0], left[1], right[0], right[1]]);
+                let product = u64::from(value) * u64::from(self.position);
+                let (high, low) = split_u64(product);
+                self.accurate_rip_v1 = self.accurate_rip_v1.wrapping_add(low);
+                self.accurate_rip_v2 = self.accurate_rip_v2.wrapping_add(low).wrapping_add(high);
+            }
+        }
+        self.crc32.update(&self.bytes);
+        self.crc32_skip_zero.update(&self.non_zero_bytes);
+    }
+
+    /// Get the checksums of every sample added.
+    #[must_use]
+    pub(crate) fn finish(self) -> RipChecksums {
+        RipChecksums {
+            crc32: self.crc32.finalize(),
+            crc32_skip_zero: self.crc32_skip_zero.finalize(),
+            accurate_rip_v1: self.accurate_rip_v1,
+            accurate_rip_v2: self.accurate_rip_v2,
+        }
+    }
+}
+
+/// Split a 64-bit value into its high and low 32-bit halves.
+fn split_u64(value: u64) -> (u32, u32) {
+    let high = u32::try_from(value >> 32).expect("high half should fit in u32");
+    let low = u32::try_from(value & u64::from(u32::MAX)).expect("low half should fit in u32");
+    (high, low)
+}
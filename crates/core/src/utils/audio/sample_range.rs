@@ -0,0 +1,48 @@
+use std::ops::Range;
+
+/// Range of samples per channel of a stream.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SampleRange {
+    /// First sample of the range.
+    pub start: u64,
+    /// Sample after the end of the range, or `None` to continue to the end of the stream.
+    pub end: Option<u64>,
+}
+
+impl SampleRange {
+    /// Number of samples in the range of a stream of `total` samples.
+    ///
+    /// Returns `None` if the range has no end and `total` is unknown.
+    #[must_use]
+    pub(crate) fn get_length(&self, total: Option<u64>) -> Option<u64> {
+        let end = match (self.end, total) {
+            (Some(end), Some(total)) => end.min(total),
+            (Some(end), None) => end,
+            (None, total) => total?,
+        };
+        Some(end.saturating_sub(self.start))
+    }
+
+    /// Indexes of a block starting at sample `offset` with `duration` samples that are in the
+    /// range.
+    ///
+    /// Returns `None` if no sample of the block is in the range.
+    #[must_use]
+    pub(crate) fn get_block_indexes(&self, offset: u64, duration: u32) -> Option<Range<u32>> {
+        let block_end = offset + u64::from(duration);
+        let start = self.start.max(offset);
+        let end = self.end.map_or(block_end, |end| end.min(block_end));
+        if start >= end {
+            return None;
+        }
+        let start = u32::try_from(start - offset).expect("index should fit in u32");
+        let end = u32::try_from(end - offset).expect("index should fit in u32");
+        Some(start..end)
+    }
+
+    /// Check if every sample after `offset` is past the end of the range.
+    #[must_use]
+    pub(crate) fn is_past_end(&self, offset: u64) -> bool {
+        self.end.is_some_and(|end| offset >= end)
+    }
+}
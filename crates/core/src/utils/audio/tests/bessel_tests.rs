@@ -0,0 +1,22 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn bessel_i0_known_values() {
+    // Arrange
+    let cases = [
+        (0.0, 1.0),
+        (1.0, 1.266_065_877_752_008_4),
+        (5.0, 27.239_871_823_604_442),
+        (20.0, 43_558_282.559_553_53),
+    ];
+
+    // Act
+    // Assert
+    for (x, expected) in cases {
+        let actual = bessel_i0(x);
+        assert!(
+            (actual - expected).abs() <= expected * 1e-14,
+            "I0({x}) should be {expected} but was {actual}"
+        );
+    }
+}
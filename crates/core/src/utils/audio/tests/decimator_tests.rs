@@ -0,0 +1,88 @@
+use crate::testing_prelude::*;
+use std::f64::consts::PI;
+
+/// Decimate a sine wave of `frequency` at `sample_rate` and return the output.
+fn decimate_sine(factor: u32, sample_rate: u32, frequency: f64, count: u32) -> Vec<f64> {
+    let mut decimator = Decimator::new(factor);
+    let mut output: Vec<f64> = (0..count)
+        .filter_map(|n| {
+            let t = f64::from(n) / f64::from(sample_rate);
+            decimator.push((2.0 * PI * frequency * t).sin())
+        })
+        .collect();
+    output.extend(decimator.finish());
+    output
+}
+
+/// Peak amplitude of the middle half of `samples`, avoiding the filter edges.
+fn middle_peak(samples: &[f64]) -> f64 {
+    let quarter = samples.len().div_ceil(4);
+    samples
+        .iter()
+        .skip(quarter)
+        .take(quarter * 2)
+        .fold(0.0, |peak: f64, sample| peak.max(sample.abs()))
+}
+
+#[test]
+fn decimator_factor_one_passes_through() {
+    // Arrange
+    let input = [0.5, -0.25, 1.0, 0.0];
+    let mut decimator = Decimator::new(1);
+
+    // Act
+    let mut output: Vec<f64> = input
+        .iter()
+        .filter_map(|sample| decimator.push(*sample))
+        .collect();
+    output.extend(decimator.finish());
+
+    // Assert
+    assert_eq!(output, input);
+}
+
+#[test]
+fn decimator_output_length_is_rounded_up() {
+    // Act
+    let output = decimate_sine(2, 96000, 1000.0, 9601);
+
+    // Assert
+    assert_eq!(output.len(), 4801);
+}
+
+#[test]
+fn decimator_preserves_dc() {
+    // Arrange
+    let mut decimator = Decimator::new(2);
+
+    // Act
+    let mut output: Vec<f64> = (0..9600).filter_map(|_| decimator.push(0.5)).collect();
+    output.extend(decimator.finish());
+
+    // Assert
+    let middle = output.get(2400).expect("should have output");
+    assert!(
+        (middle - 0.5).abs() < 1e-9,
+        "DC should be preserved: {middle}"
+    );
+}
+
+#[test]
+fn decimator_passes_audible_tone() {
+    // Act
+    let output = decimate_sine(2, 96000, 20000.0, 19200);
+
+    // Assert
+    let peak = middle_peak(&output);
+    assert!((peak - 1.0).abs() < 1e-3, "passband should be flat: {peak}");
+}
+
+#[test]
+fn decimator_rejects_tone_above_nyquist() {
+    // Act
+    let output = decimate_sine(2, 96000, 30000.0, 19200);
+
+    // Assert
+    let peak = middle_peak(&output);
+    assert!(peak < 1e-6, "stopband should be attenuated: {peak}");
+}
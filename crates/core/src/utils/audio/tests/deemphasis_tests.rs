@@ -0,0 +1,56 @@
+use crate::testing_prelude::*;
+use std::f64::consts::PI;
+
+/// Gain in dB of the filter for a sine wave of `frequency` at 44.1 kHz.
+#[expect(clippy::integer_division, reason = "approximate half")]
+fn get_gain(frequency: f64) -> f64 {
+    let sample_rate = 44_100;
+    let mut filter = DeemphasisFilter::new(sample_rate);
+    let output: Vec<f64> = (0..sample_rate)
+        .map(|n| {
+            let t = f64::from(n) / f64::from(sample_rate);
+            filter.process((2.0 * PI * frequency * t).sin())
+        })
+        .collect();
+    let peak = output
+        .iter()
+        .skip(output.len() / 2)
+        .fold(0.0, |peak: f64, sample| peak.max(sample.abs()));
+    20.0 * peak.log10()
+}
+
+/// Gain in dB of the analog 50/15 µs de-emphasis curve.
+fn get_analog_gain(frequency: f64) -> f64 {
+    let omega = 2.0 * PI * frequency;
+    let zero = (1.0 + (omega * 15e-6).powi(2)).sqrt();
+    let pole = (1.0 + (omega * 50e-6).powi(2)).sqrt();
+    20.0 * (zero / pole).log10()
+}
+
+#[test]
+fn deemphasis_filter_passes_dc() {
+    // Arrange
+    let mut filter = DeemphasisFilter::new(44_100);
+
+    // Act
+    let output = (0..1000).map(|_| filter.process(1000.0)).last();
+
+    // Assert
+    let output = output.expect("should have output");
+    assert!((output - 1000.0).abs() < 1e-6, "DC output was {output}");
+}
+
+#[test]
+fn deemphasis_filter_matches_analog_curve() {
+    for frequency in [100.0, 1_000.0, 3_183.0, 10_000.0, 16_000.0, 20_000.0] {
+        // Act
+        let gain = get_gain(frequency);
+
+        // Assert
+        let expected = get_analog_gain(frequency);
+        assert!(
+            (gain - expected).abs() < 0.5,
+            "{frequency} Hz: {gain:.2} dB, expected {expected:.2} dB"
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn dither_repeatable_is_deterministic() {
+    // Arrange
+    let mut first = TpdfDither::new(true);
+    let mut second = TpdfDither::new(true);
+
+    // Act
+    let first: Vec<i16> = (0..1000)
+        .map(|n| first.quantize(f64::from(n) / 7.0))
+        .collect();
+    let second: Vec<i16> = (0..1000)
+        .map(|n| second.quantize(f64::from(n) / 7.0))
+        .collect();
+
+    // Assert
+    assert_eq!(first, second);
+}
+
+#[test]
+fn dither_different_seeds_differ() {
+    // Arrange
+    let mut first = TpdfDither::with_seed(1);
+    let mut second = TpdfDither::with_seed(2);
+
+    // Act
+    let first: Vec<i16> = (0..1000).map(|_| first.quantize(0.5)).collect();
+    let second: Vec<i16> = (0..1000).map(|_| second.quantize(0.5)).collect();
+
+    // Assert
+    assert_ne!(first, second);
+}
+
+#[test]
+fn dither_stays_within_one_lsb() {
+    // Arrange
+    let mut dither = TpdfDither::new(true);
+
+    // Act
+    let output: Vec<i16> = (0..10000).map(|_| dither.quantize(100.0)).collect();
+
+    // Assert
+    assert!(output.iter().all(|sample| (99..=101).contains(sample)));
+    assert!(output.contains(&99), "dither should add negative noise");
+    assert!(output.contains(&101), "dither should add positive noise");
+}
+
+#[test]
+fn dither_clamps_to_16_bit_range() {
+    // Arrange
+    let mut dither = TpdfDither::new(true);
+
+    // Act
+    let high = dither.quantize(40000.0);
+    let low = dither.quantize(-40000.0);
+
+    // Assert
+    assert_eq!(high, i16::MAX);
+    assert_eq!(low, i16::MIN);
+}
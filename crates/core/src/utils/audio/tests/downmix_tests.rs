@@ -0,0 +1,46 @@
+use crate::testing_prelude::*;
+use std::f64::consts::FRAC_1_SQRT_2;
+
+#[test]
+fn downmixer_new_skips_mono_and_stereo() {
+    // Arrange
+    // Act
+    let mono = Downmixer::new(1);
+    let stereo = Downmixer::new(2);
+    let nine = Downmixer::new(9);
+
+    // Assert
+    assert!(mono.is_none());
+    assert!(stereo.is_none());
+    assert!(nine.is_none());
+}
+
+#[test]
+fn downmixer_process_5_1() {
+    // Arrange
+    let downmixer = Downmixer::new(6).expect("should downmix 5.1");
+
+    // Act
+    let front = downmixer.process(&[1.0, 0.5, 0.0, 0.0, 0.0, 0.0]);
+    let center = downmixer.process(&[0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    let lfe = downmixer.process(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    let surround = downmixer.process(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+
+    // Assert
+    assert_eq!(front, (1.0, 0.5));
+    assert_eq!(center, (FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+    assert_eq!(lfe, (0.0, 0.0));
+    assert_eq!(surround, (FRAC_1_SQRT_2, 0.0));
+}
+
+#[test]
+fn downmixer_process_6_1_back_center() {
+    // Arrange
+    let downmixer = Downmixer::new(7).expect("should downmix 6.1");
+
+    // Act
+    let output = downmixer.process(&[0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    // Assert
+    assert_eq!(output, (FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+}
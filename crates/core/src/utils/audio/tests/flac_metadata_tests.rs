@@ -0,0 +1,90 @@
+use crate::testing_prelude::*;
+use std::io::Cursor;
+
+#[test]
+fn flac_metadata_read_from_sums_pictures_padding_and_audio() {
+    // Arrange
+    let mut bytes = b"fLaC".to_vec();
+    bytes.extend(block(0, false, &[0; 34]));
+    bytes.extend(block(
+        4,
+        false,
+        &vorbis_comment("reference libFLAC 1.3.2 20170101"),
+    ));
+    bytes.extend(block(6, false, &[0; 300]));
+    bytes.extend(block(6, false, &[0; 200]));
+    bytes.extend(block(1, true, &[0; 1024]));
+    bytes.extend([0xFF; 4096]);
+
+    // Act
+    let metadata = FlacMetadata::read_from(Cursor::new(bytes)).expect("should read metadata");
+
+    // Assert
+    assert_eq!(
+        metadata,
+        FlacMetadata {
+            vendor: Some("reference libFLAC 1.3.2 20170101".to_owned()),
+            picture_bytes: 500,
+            padding_bytes: 1024,
+            audio_bytes: 4096,
+        }
+    );
+    assert!(!metadata.is_outdated_encoder());
+}
+
+#[test]
+fn flac_metadata_read_from_rejects_other_streams() {
+    // Arrange
+    let bytes = b"RIFF\0\0\0\0WAVE".to_vec();
+
+    // Act
+    let result = FlacMetadata::read_from(Cursor::new(bytes));
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn flac_metadata_is_outdated_encoder() {
+    // Arrange
+    let cases = [
+        ("reference libFLAC 1.1.4 20070213", true),
+        ("reference libFLAC 1.2.1 20070917", false),
+        ("reference libFLAC 1.4.3 20230623", false),
+        ("Lavf58.76.100", false),
+    ];
+
+    // Act
+    // Assert
+    for (vendor, expected) in cases {
+        let metadata = FlacMetadata {
+            vendor: Some(vendor.to_owned()),
+            ..FlacMetadata::default()
+        };
+        assert_eq!(metadata.is_outdated_encoder(), expected, "{vendor}");
+    }
+}
+
+/// Create a metadata block of `block_type` with `content`.
+fn block(block_type: u8, is_last: bool, content: &[u8]) -> Vec<u8> {
+    let flags = if is_last {
+        0x80 | block_type
+    } else {
+        block_type
+    };
+    let length = u32::try_from(content.len())
+        .expect("length should fit in u32")
+        .to_be_bytes();
+    let mut bytes = vec![flags, length[1], length[2], length[3]];
+    bytes.extend(content);
+    bytes
+}
+
+/// Create a `VORBIS_COMMENT` block with `vendor` and no comments.
+fn vorbis_comment(vendor: &str) -> Vec<u8> {
+    let length = u32::try_from(vendor.len()).expect("length should fit in u32");
+    let mut bytes = length.to_le_bytes().to_vec();
+    bytes.extend(vendor.as_bytes());
+    bytes.extend(0_u32.to_le_bytes());
+    bytes
+}
@@ -0,0 +1,113 @@
+use crate::testing_prelude::*;
+
+/// MPEG-1 layer III, 320 kbps, 44.1 kHz, joint stereo frame header.
+const MPEG1_STEREO: [u8; 4] = [0xFF, 0xFB, 0xE0, 0x64];
+
+/// Size in bytes of the side information of [`MPEG1_STEREO`].
+const SIDE_INFO_SIZE: usize = 32;
+
+/// Offset of the LAME tag within a frame built by [`frame`].
+const LAME_OFFSET: usize = 4 + SIDE_INFO_SIZE + 12;
+
+/// Build an MPEG frame with an `Info` header declaring frames and a LAME tag.
+///
+/// The tag CRC is calculated with `crc_preset` so a different `preset` corrupts the tag.
+fn frame(encoder: [u8; 9], preset: u16, crc_preset: u16) -> Vec<u8> {
+    let mut bytes = MPEG1_STEREO.to_vec();
+    bytes.extend(vec![0; SIDE_INFO_SIZE]);
+    bytes.extend_from_slice(b"Info");
+    bytes.extend_from_slice(&1_u32.to_be_bytes());
+    bytes.extend_from_slice(&100_u32.to_be_bytes());
+    bytes.extend_from_slice(&encoder);
+    bytes.extend_from_slice(&[0x01, 205]);
+    bytes.extend(vec![0; 10]);
+    bytes.extend_from_slice(&[0x24, 0x04, 0xEC, 0, 0]);
+    let preset_offset = bytes.len();
+    bytes.extend_from_slice(&crc_preset.to_be_bytes());
+    bytes.extend_from_slice(&2_457_600_u32.to_be_bytes());
+    bytes.extend_from_slice(&0xBEEF_u16.to_be_bytes());
+    let crc = crc16(&bytes);
+    bytes.extend_from_slice(&crc.to_be_bytes());
+    bytes.splice(preset_offset..preset_offset + 2, preset.to_be_bytes());
+    bytes.extend(vec![0; 64]);
+    bytes
+}
+
+#[test]
+fn lame_tag_parse_fields() {
+    // Arrange
+    let bytes = frame(*b"LAME3.100", 320, 320);
+
+    // Act
+    let lame = XingHeader::parse(&bytes).and_then(|header| header.lame);
+
+    // Assert
+    assert_eq!(
+        lame,
+        Some(LameTag {
+            encoder: "LAME3.100".to_owned(),
+            revision: 0,
+            vbr_method: 1,
+            lowpass: 20_500,
+            encoder_delay: 576,
+            padding: 1260,
+            preset: 320,
+            music_length: 2_457_600,
+            music_crc: 0xBEEF,
+            is_tag_crc_valid: true,
+        })
+    );
+}
+
+#[test]
+fn lame_tag_parse_detects_corrupt_tag() {
+    // Arrange
+    let bytes = frame(*b"LAME3.100", 500, 320);
+
+    // Act
+    let lame = XingHeader::parse(&bytes).and_then(|header| header.lame);
+
+    // Assert
+    let lame = lame.expect("should parse LAME tag");
+    assert_eq!(lame.preset, 500);
+    assert!(!lame.is_tag_crc_valid);
+}
+
+#[test]
+fn lame_tag_parse_without_encoder() {
+    // Arrange
+    let bytes = frame([0; 9], 320, 320);
+
+    // Act
+    let lame = XingHeader::parse(&bytes).and_then(|header| header.lame);
+
+    // Assert
+    assert_eq!(lame, None);
+}
+
+#[test]
+fn lame_tag_format_preset() {
+    // Arrange
+    let v0 = LameTag::parse(&frame(*b"LAME3.100", 500, 500), LAME_OFFSET);
+    let cbr = LameTag::parse(&frame(*b"LAME3.100", 320, 320), LAME_OFFSET);
+
+    // Act
+    let v0 = v0.map(|lame| lame.format_preset());
+    let cbr = cbr.map(|lame| lame.format_preset());
+
+    // Assert
+    assert_eq!(v0.as_deref(), Some("-V 0"));
+    assert_eq!(cbr.as_deref(), Some("-b 320"));
+}
+
+#[test]
+fn lame_tag_get_expected_preset() {
+    // Arrange
+    let targets = [TargetFormat::V0, TargetFormat::_320, TargetFormat::Flac];
+
+    // Act
+    let presets = targets.map(LameTag::get_expected_preset);
+
+    // Assert
+    assert_eq!(presets, [Some(500), Some(320), None]);
+}
@@ -0,0 +1,12 @@
+mod bessel_tests;
+mod decimator_tests;
+mod deemphasis_tests;
+mod dither_tests;
+mod downmix_tests;
+mod flac_metadata_tests;
+mod lame_tag_tests;
+mod mqa_detector_tests;
+mod power_spectrum_tests;
+mod rip_checksum_tests;
+mod sample_range_tests;
+mod xing_header_tests;
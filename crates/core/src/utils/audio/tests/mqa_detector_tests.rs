@@ -0,0 +1,57 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn mqa_detector_finds_repeated_sync_word() {
+    // Arrange
+    let mut detector = MqaDetector::new(16);
+
+    // Act
+    for frame in sync_frames(3, 2) {
+        detector.push_frame(&frame);
+    }
+
+    // Assert
+    assert_eq!(detector.get_position(), Some(3));
+}
+
+#[test]
+fn mqa_detector_ignores_single_sync_word() {
+    // Arrange
+    let mut detector = MqaDetector::new(16);
+
+    // Act
+    for frame in sync_frames(3, 1) {
+        detector.push_frame(&frame);
+    }
+
+    // Assert
+    assert_eq!(detector.get_position(), None);
+}
+
+#[test]
+fn mqa_detector_ignores_mono() {
+    // Arrange
+    let mut detector = MqaDetector::new(16);
+
+    // Act
+    for frame in sync_frames(3, 2) {
+        detector.push_frame(frame.get(..1).expect("frame should have a sample"));
+    }
+
+    // Assert
+    assert_eq!(detector.get_position(), None);
+}
+
+/// Stereo frames with [`MQA_SYNC_WORD`] in bit `position` of the XOR of the channels `count`
+/// times, each followed by silence.
+fn sync_frames(position: u32, count: usize) -> Vec<[i32; 2]> {
+    let mut frames = Vec::new();
+    for _ in 0..count {
+        for index in (0..36).rev() {
+            let bit = i32::from((MQA_SYNC_WORD >> index) & 1 == 1);
+            frames.push([0x1234, 0x1234 ^ (bit << position)]);
+        }
+        frames.extend([[0, 0]; 64]);
+    }
+    frames
+}
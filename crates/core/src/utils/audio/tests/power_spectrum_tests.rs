@@ -0,0 +1,53 @@
+use crate::testing_prelude::*;
+use std::f64::consts::TAU;
+
+/// Create a [`PowerSpectrum`] of one second of a sine at `frequency` Hz and `amplitude`.
+fn sine(frequency: f64, amplitude: f64) -> PowerSpectrum {
+    let mut spectrum = PowerSpectrum::new(48_000);
+    for index in 0..48_000 {
+        let time = f64::from(index) / 48_000.0;
+        spectrum.push(amplitude * (TAU * frequency * time).sin());
+    }
+    spectrum
+}
+
+#[test]
+fn power_spectrum_full_scale_sine_is_zero_db() {
+    // Arrange
+    let spectrum = sine(3_000.0, 1.0);
+
+    // Act
+    let level = spectrum
+        .get_band_level(3_000.0, 3_000.0)
+        .expect("should have a bin at 3 kHz");
+
+    // Assert
+    assert_eq!(spectrum.windows(), 11);
+    assert!(level.abs() < 0.1, "level should be 0 dB: {level}");
+}
+
+#[test]
+fn power_spectrum_band_without_content() {
+    // Arrange
+    let spectrum = sine(3_000.0, 0.5);
+
+    // Act
+    let level = spectrum
+        .get_band_level(15_000.0, 20_000.0)
+        .expect("should have bins in band");
+
+    // Assert
+    assert!(level < -120.0, "band should be silent: {level}");
+}
+
+#[test]
+fn power_spectrum_band_above_nyquist() {
+    // Arrange
+    let spectrum = sine(3_000.0, 0.5);
+
+    // Act
+    let level = spectrum.get_band_level(30_000.0, 40_000.0);
+
+    // Assert
+    assert_eq!(level, None);
+}
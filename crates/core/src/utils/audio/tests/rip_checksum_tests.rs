@@ -0,0 +1,70 @@
+use crate::testing_prelude::*;
+
+/// Samples per channel of the generated track.
+const SAMPLES: u32 = 10_000;
+
+/// Generate a deterministic stereo track where every tenth left sample is zero.
+pub(crate) fn generate_samples() -> Vec<(i16, i16)> {
+    (0..SAMPLES)
+        .map(|index| {
+            let left = i16::try_from((index * 37) % 2001).expect("should fit") - 1000;
+            let right = i16::try_from((index * 53) % 1501).expect("should fit") - 750;
+            if index % 10 == 0 {
+                (0, right)
+            } else {
+                (left, right)
+            }
+        })
+        .collect()
+}
+
+/// Calculate the checksums of the generated track in blocks of 4096 samples.
+fn calculate(is_first: bool, is_last: bool) -> RipChecksums {
+    let mut calculator = RipChecksumCalculator::new(SAMPLES, is_first, is_last);
+    for block in generate_samples().chunks(4096) {
+        calculator.push(block.iter().copied());
+    }
+    calculator.finish()
+}
+
+#[test]
+fn rip_checksum_calculator_middle_track() {
+    // Arrange
+    // Act
+    let checksums = calculate(false, false);
+
+    // Assert
+    assert_eq!(
+        checksums,
+        RipChecksums {
+            crc32: 0xFA29_8975,
+            crc32_skip_zero: 0x1872_3E20,
+            accurate_rip_v1: 0x63E2_AF7E,
+            accurate_rip_v2: 0x655F_8DA7,
+        }
+    );
+}
+
+#[test]
+fn rip_checksum_calculator_first_track() {
+    // Arrange
+    // Act
+    let checksums = calculate(true, false);
+
+    // Assert
+    assert_eq!(checksums.crc32, 0xFA29_8975);
+    assert_eq!(checksums.accurate_rip_v1, 0xB4CA_7600);
+    assert_eq!(checksums.accurate_rip_v2, 0xB626_744E);
+}
+
+#[test]
+fn rip_checksum_calculator_last_track() {
+    // Arrange
+    // Act
+    let checksums = calculate(false, true);
+
+    // Assert
+    assert_eq!(checksums.crc32, 0xFA29_8975);
+    assert_eq!(checksums.accurate_rip_v1, 0x900B_07F0);
+    assert_eq!(checksums.accurate_rip_v2, 0x90C9_1DB7);
+}
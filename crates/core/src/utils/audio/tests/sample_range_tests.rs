@@ -0,0 +1,69 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn sample_range_get_length() {
+    // Arrange
+    let bounded = SampleRange {
+        start: 100,
+        end: Some(250),
+    };
+    let open = SampleRange {
+        start: 100,
+        end: None,
+    };
+
+    // Act
+    let bounded_length = bounded.get_length(Some(1000));
+    let truncated_length = bounded.get_length(Some(200));
+    let open_length = open.get_length(Some(1000));
+    let unknown_length = open.get_length(None);
+
+    // Assert
+    assert_eq!(bounded_length, Some(150));
+    assert_eq!(truncated_length, Some(100));
+    assert_eq!(open_length, Some(900));
+    assert_eq!(unknown_length, None);
+}
+
+#[test]
+fn sample_range_get_block_indexes() {
+    // Arrange
+    let range = SampleRange {
+        start: 4000,
+        end: Some(9000),
+    };
+
+    // Act
+    let before = range.get_block_indexes(0, 4000);
+    let start = range.get_block_indexes(0, 4096);
+    let inside = range.get_block_indexes(4096, 4096);
+    let end = range.get_block_indexes(8192, 4096);
+    let after = range.get_block_indexes(9000, 4096);
+
+    // Assert
+    assert_eq!(before, None);
+    assert_eq!(start, Some(4000..4096));
+    assert_eq!(inside, Some(0..4096));
+    assert_eq!(end, Some(0..808));
+    assert_eq!(after, None);
+}
+
+#[test]
+fn sample_range_is_past_end() {
+    // Arrange
+    let bounded = SampleRange {
+        start: 0,
+        end: Some(9000),
+    };
+    let open = SampleRange::default();
+
+    // Act
+    let before = bounded.is_past_end(8999);
+    let at = bounded.is_past_end(9000);
+    let never = open.is_past_end(u64::MAX);
+
+    // Assert
+    assert!(!before);
+    assert!(at);
+    assert!(!never);
+}
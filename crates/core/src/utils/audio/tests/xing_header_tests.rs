@@ -0,0 +1,126 @@
+use crate::testing_prelude::*;
+
+/// MPEG-1 layer III, 320 kbps, 44.1 kHz, joint stereo frame header.
+const MPEG1_STEREO: [u8; 4] = [0xFF, 0xFB, 0xE0, 0x64];
+
+/// MPEG-1 layer III, 320 kbps, 44.1 kHz, mono frame header.
+const MPEG1_MONO: [u8; 4] = [0xFF, 0xFB, 0xE0, 0xC4];
+
+/// Build an MPEG frame with a Xing header following the side information.
+fn frame(header: [u8; 4], side_info: usize, tag: &[u8], frames: Option<u32>) -> Vec<u8> {
+    let mut bytes = header.to_vec();
+    bytes.extend(vec![0; side_info]);
+    bytes.extend_from_slice(tag);
+    let flags: u32 = if frames.is_some() { 1 } else { 0 };
+    bytes.extend_from_slice(&flags.to_be_bytes());
+    if let Some(frames) = frames {
+        bytes.extend_from_slice(&frames.to_be_bytes());
+    }
+    bytes.extend(vec![0; 64]);
+    bytes
+}
+
+#[test]
+fn xing_header_parse_info_is_constant() {
+    // Arrange
+    let bytes = frame(MPEG1_STEREO, 32, b"Info", Some(1234));
+
+    // Act
+    let header = XingHeader::parse(&bytes);
+
+    // Assert
+    assert_eq!(
+        header,
+        Some(XingHeader {
+            mode: BitrateMode::Constant,
+            frames: Some(1234),
+            lame: None,
+        })
+    );
+}
+
+#[test]
+fn xing_header_parse_xing_is_variable() {
+    // Arrange
+    let bytes = frame(MPEG1_STEREO, 32, b"Xing", None);
+
+    // Act
+    let header = XingHeader::parse(&bytes);
+
+    // Assert
+    assert_eq!(
+        header,
+        Some(XingHeader {
+            mode: BitrateMode::Variable,
+            frames: None,
+            lame: None,
+        })
+    );
+}
+
+#[test]
+fn xing_header_parse_mono_side_info() {
+    // Arrange
+    let bytes = frame(MPEG1_MONO, 17, b"Xing", Some(1));
+
+    // Act
+    let header = XingHeader::parse(&bytes);
+
+    // Assert
+    assert_eq!(
+        header.map(|header| header.mode),
+        Some(BitrateMode::Variable)
+    );
+}
+
+#[test]
+fn xing_header_parse_skips_leading_padding() {
+    // Arrange
+    let mut bytes = vec![0; 100];
+    bytes.extend(frame(MPEG1_STEREO, 32, b"Info", None));
+
+    // Act
+    let header = XingHeader::parse(&bytes);
+
+    // Assert
+    assert_eq!(
+        header.map(|header| header.mode),
+        Some(BitrateMode::Constant)
+    );
+}
+
+#[test]
+fn xing_header_parse_without_tag() {
+    // Arrange
+    let bytes = frame(MPEG1_STEREO, 32, b"\0\0\0\0", None);
+
+    // Act
+    let header = XingHeader::parse(&bytes);
+
+    // Assert
+    assert_eq!(header, None);
+}
+
+#[test]
+fn xing_header_read_skips_id3v2_tag() {
+    // Arrange
+    let dir = TempDirectory::create("xing_header_read_skips_id3v2_tag");
+    let path = dir.join("track.mp3");
+    let mut bytes = b"ID3\x04\x00\x00\x00\x00\x01\x00".to_vec();
+    bytes.extend(vec![0xFF; 128]);
+    bytes.extend(frame(MPEG1_STEREO, 32, b"Xing", Some(42)));
+    write(&path, bytes).expect("should write file");
+
+    // Act
+    let header = XingHeader::read(&path).expect("should read file");
+
+    // Assert
+    assert_eq!(
+        header,
+        Some(XingHeader {
+            mode: BitrateMode::Variable,
+            frames: Some(42),
+            lame: None,
+        })
+    );
+}
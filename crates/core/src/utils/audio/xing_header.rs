@@ -0,0 +1,147 @@
+use crate::prelude::*;
+use std::io::{Read, Seek, SeekFrom};
+
+/// Size in bytes of an `ID3v2` tag header.
+const ID3V2_HEADER_SIZE: usize = 10;
+
+/// Number of bytes after the `ID3v2` tag searched for the first MPEG frame.
+const SEARCH_LIMIT: u64 = 8192;
+
+/// Size in bytes of an MPEG frame header.
+const FRAME_HEADER_SIZE: usize = 4;
+
+/// Xing header flag indicating the frame count is present.
+const FRAMES_FLAG: u32 = 0x1;
+
+/// Xing header flag indicating the byte count is present.
+const BYTES_FLAG: u32 = 0x2;
+
+/// Xing header flag indicating the seek table of contents is present.
+const TOC_FLAG: u32 = 0x4;
+
+/// Xing header flag indicating the quality indicator is present.
+const QUALITY_FLAG: u32 = 0x8;
+
+/// Size in bytes of the seek table of contents.
+const TOC_SIZE: usize = 100;
+
+/// Bitrate mode of an MP3 declared by its Xing header.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) enum BitrateMode {
+    /// Constant bitrate, declared by an `Info` header.
+    Constant,
+    /// Variable bitrate, declared by a `Xing` header.
+    Variable,
+}
+
+impl Display for BitrateMode {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let mode = match self {
+            BitrateMode::Constant => "CBR",
+            BitrateMode::Variable => "VBR",
+        };
+        write!(formatter, "{mode}")
+    }
+}
+
+/// Xing or Info header stored in the first MPEG frame of an MP3.
+///
+/// `lame` writes a `Xing` header for VBR and an `Info` header for CBR, followed by a [`LameTag`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct XingHeader {
+    /// Bitrate mode declared by the header tag.
+    pub mode: BitrateMode,
+    /// Total number of MPEG frames, if declared.
+    pub frames: Option<u32>,
+    /// LAME extension, if present.
+    pub lame: Option<LameTag>,
+}
+
+impl XingHeader {
+    /// Read the [`XingHeader`] of an MP3 file.
+    ///
+    /// Returns `None` if the first MPEG frame does not contain a Xing header.
+    pub(crate) fn read(path: &Path) -> Result<Option<Self>, IoError> {
+        let mut file = File::open(path)?;
+        let mut header = [0_u8; ID3V2_HEADER_SIZE];
+        file.read_exact(&mut header)?;
+        file.seek(SeekFrom::Start(get_id3v2_size(&header)))?;
+        let mut bytes = Vec::new();
+        file.take(SEARCH_LIMIT).read_to_end(&mut bytes)?;
+        Ok(Self::parse(&bytes))
+    }
+
+    /// Parse a [`XingHeader`] from the bytes following the `ID3v2` tag.
+    #[must_use]
+    pub(crate) fn parse(bytes: &[u8]) -> Option<Self> {
+        let start = find_frame_sync(bytes)?;
+        let frame = bytes.get(start..)?;
+        let offset = FRAME_HEADER_SIZE + get_side_info_size(frame)?;
+        let mode = match frame.get(offset..offset + 4)? {
+            b"Xing" => BitrateMode::Variable,
+            b"Info" => BitrateMode::Constant,
+            _ => return None,
+        };
+        let flags = read_u32(frame, offset + 4)?;
+        let frames = if flags & FRAMES_FLAG == 0 {
+            None
+        } else {
+            read_u32(frame, offset + 8)
+        };
+        let lame_offset = [
+            (FRAMES_FLAG, 4),
+            (BYTES_FLAG, 4),
+            (TOC_FLAG, TOC_SIZE),
+            (QUALITY_FLAG, 4),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .fold(offset + 8, |lame_offset, (_, size)| lame_offset + size);
+        Some(Self {
+            mode,
+            frames,
+            lame: LameTag::parse(frame, lame_offset),
+        })
+    }
+}
+
+/// Get the total size of an `ID3v2` tag from its header, or zero if there is no tag.
+fn get_id3v2_size(header: &[u8; ID3V2_HEADER_SIZE]) -> u64 {
+    let [b'I', b'D', b'3', _, _, flags, size @ ..] = *header else {
+        return 0;
+    };
+    let size = size
+        .iter()
+        .fold(0_u64, |acc, byte| (acc << 7) | u64::from(byte & 0x7F));
+    let header_size = u64::try_from(ID3V2_HEADER_SIZE).expect("header size should fit in u64");
+    let footer_size = if flags & 0x10 == 0 { 0 } else { header_size };
+    header_size + size + footer_size
+}
+
+/// Find the offset of the first MPEG frame sync.
+fn find_frame_sync(bytes: &[u8]) -> Option<usize> {
+    bytes
+        .windows(2)
+        .position(|pair| matches!(pair, [0xFF, second] if second & 0xE0 == 0xE0))
+}
+
+/// Get the size of the layer III side information from an MPEG frame header.
+///
+/// The Xing header immediately follows the side information.
+fn get_side_info_size(frame: &[u8]) -> Option<usize> {
+    let version = (frame.get(1)? >> 3) & 0x3;
+    let mono = (frame.get(3)? >> 6) & 0x3 == 0x3;
+    let is_mpeg1 = version == 0x3;
+    let size = match (is_mpeg1, mono) {
+        (true, false) => 32,
+        (true, true) | (false, false) => 17,
+        (false, true) => 9,
+    };
+    Some(size)
+}
+
+/// Read a big endian `u32` at `offset`.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use crc32fast::Hasher as Crc32;
+use md5::{Digest, Md5};
+use sha2::Sha256;
+use std::fs::read as read_bytes;
+use std::io::Read;
+
+/// Size of the buffer used to hash files.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Format of a checksum manifest, identified by its extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ManifestFormat {
+    /// FLAC fingerprint of the MD5 signature in the STREAMINFO of each FLAC.
+    Ffp,
+    /// MD5 of each file in `md5sum` or BSD format.
+    Md5,
+    /// CRC32 of each file in simple file verification format.
+    Sfv,
+    /// SHA-256 of each file in `sha256sum` or BSD format.
+    Sha256,
+}
+
+impl ManifestFormat {
+    /// Extensions of every [`ManifestFormat`].
+    pub(crate) const EXTENSIONS: [&'static str; 4] = ["ffp", "md5", "sfv", "sha256"];
+
+    /// Get the format of a manifest from its extension.
+    #[must_use]
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_string_lossy().to_lowercase();
+        match extension.as_str() {
+            "ffp" => Some(Self::Ffp),
+            "md5" => Some(Self::Md5),
+            "sfv" => Some(Self::Sfv),
+            "sha256" => Some(Self::Sha256),
+            _ => None,
+        }
+    }
+
+    /// Number of hexadecimal digits of a checksum.
+    #[must_use]
+    pub(crate) fn get_digits(self) -> usize {
+        match self {
+            Self::Ffp | Self::Md5 => 32,
+            Self::Sfv => 8,
+            Self::Sha256 => 64,
+        }
+    }
+
+    /// Calculate the checksum of a file as lowercase hexadecimal.
+    ///
+    /// Not applicable to [`ManifestFormat::Ffp`] which is compared to the STREAMINFO instead.
+    pub(crate) fn hash_file(self, path: &Path) -> Result<String, IoError> {
+        let file = File::open(path)?;
+        match self {
+            Self::Ffp | Self::Md5 => hash_reader::<Md5>(file),
+            Self::Sha256 => hash_reader::<Sha256>(file),
+            Self::Sfv => {
+                let mut hasher = Crc32::new();
+                read_chunks(file, |chunk| hasher.update(chunk))?;
+                Ok(format!("{:08x}", hasher.finalize()))
+            }
+        }
+    }
+}
+
+impl Display for ManifestFormat {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Ffp => "FLAC MD5",
+            Self::Md5 => "MD5",
+            Self::Sfv => "CRC32",
+            Self::Sha256 => "SHA-256",
+        };
+        write!(formatter, "{name}")
+    }
+}
+
+/// A file and its expected checksum in a manifest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ManifestEntry {
+    /// Path relative to the manifest with `/` separators.
+    pub file: String,
+    /// Checksum as lowercase hexadecimal.
+    pub checksum: String,
+}
+
+/// Entries of an `.ffp`, `.md5`, `.sfv` or `.sha256` manifest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ChecksumManifest {
+    /// Format of the manifest.
+    pub format: ManifestFormat,
+    /// Entries in the order they are listed.
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl ChecksumManifest {
+    /// Read and parse a manifest.
+    ///
+    /// Returns `None` if the extension is not a [`ManifestFormat`].
+    pub(crate) fn read(path: &Path) -> Result<Option<Self>, IoError> {
+        let Some(format) = ManifestFormat::from_path(path) else {
+            return Ok(None);
+        };
+        let bytes = read_bytes(path)?;
+        Ok(Some(Self::parse(format, &decode_text(&bytes))))
+    }
+
+    /// Find the manifests in a directory, sorted by path.
+    pub(crate) fn find(dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+        let mut paths = DirectoryReader::new()
+            .with_extensions(ManifestFormat::EXTENSIONS.to_vec())
+            .read(dir)?;
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Parse the text of a manifest.
+    ///
+    /// Comments starting with `;` or `#` and malformed lines are ignored.
+    #[must_use]
+    pub(crate) fn parse(format: ManifestFormat, text: &str) -> Self {
+        let entries = text
+            .lines()
+            .map(|line| line.trim().trim_start_matches('\u{feff}'))
+            .filter(|line| !line.is_empty() && !line.starts_with(';') && !line.starts_with('#'))
+            .filter_map(|line| parse_entry(format, line))
+            .collect();
+        Self { format, entries }
+    }
+}
+
+/// Parse a single line of a manifest.
+fn parse_entry(format: ManifestFormat, line: &str) -> Option<ManifestEntry> {
+    let (file, checksum) = match format {
+        ManifestFormat::Ffp => line.rsplit_once(':')?,
+        ManifestFormat::Sfv => line.rsplit_once(char::is_whitespace)?,
+        ManifestFormat::Md5 | ManifestFormat::Sha256 => parse_bsd(line).or_else(|| {
+            let (checksum, file) = line.split_once(char::is_whitespace)?;
+            let file = file.trim_start();
+            Some((file.strip_prefix('*').unwrap_or(file), checksum))
+        })?,
+    };
+    let checksum = checksum.trim().to_lowercase();
+    if checksum.len() != format.get_digits()
+        || !checksum.chars().all(|char| char.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    let file = file.trim().replace('\\', "/");
+    let file = file.strip_prefix("./").unwrap_or(&file);
+    if file.is_empty() {
+        return None;
+    }
+    Some(ManifestEntry {
+        file: file.to_owned(),
+        checksum,
+    })
+}
+
+/// Parse a line in BSD format such as `MD5 (01.flac) = d41d8cd9…`.
+fn parse_bsd(line: &str) -> Option<(&str, &str)> {
+    let (_, rest) = line.split_once(" (")?;
+    rest.rsplit_once(") = ")
+}
+
+fn hash_reader<D: Digest>(reader: impl Read) -> Result<String, IoError> {
+    let mut hasher = D::new();
+    read_chunks(reader, |chunk| hasher.update(chunk))?;
+    Ok(to_hex(&hasher.finalize()))
+}
+
+/// Format bytes as lowercase hexadecimal.
+#[must_use]
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
+
+fn read_chunks(mut reader: impl Read, mut action: impl FnMut(&[u8])) -> Result<(), IoError> {
+    let mut buffer = vec![0; BUFFER_SIZE];
+    loop {
+        let count = reader.read(&mut buffer)?;
+        if count == 0 {
+            return Ok(());
+        }
+        action(buffer.get(..count).unwrap_or_default());
+    }
+}
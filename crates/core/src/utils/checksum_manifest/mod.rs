@@ -0,0 +1,7 @@
+//! Parse checksum manifests shipped with sources.
+
+pub(crate) use checksum_manifest::*;
+
+mod checksum_manifest;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,132 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn checksum_manifest_parse_ffp() {
+    // Arrange
+    let text = "; Generated by foobar2000\r\n\
+        01 - First.flac:D41D8CD98F00B204E9800998ECF8427E\r\n\
+        CD2\\01 - Second.flac:0123456789abcdef0123456789abcdef\r\n\
+        03 - Truncated.flac:0123\r\n";
+
+    // Act
+    let manifest = ChecksumManifest::parse(ManifestFormat::Ffp, text);
+
+    // Assert
+    assert_eq!(
+        manifest.entries,
+        vec![
+            ManifestEntry {
+                file: "01 - First.flac".to_owned(),
+                checksum: "d41d8cd98f00b204e9800998ecf8427e".to_owned(),
+            },
+            ManifestEntry {
+                file: "CD2/01 - Second.flac".to_owned(),
+                checksum: "0123456789abcdef0123456789abcdef".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn checksum_manifest_parse_md5() {
+    // Arrange
+    let text = "d41d8cd98f00b204e9800998ecf8427e  01 - First.flac\n\
+        0123456789abcdef0123456789abcdef *./02 - Second.flac\n\
+        MD5 (03 - Third (Live).flac) = fedcba9876543210fedcba9876543210\n";
+
+    // Act
+    let manifest = ChecksumManifest::parse(ManifestFormat::Md5, text);
+
+    // Assert
+    let files: Vec<&str> = manifest
+        .entries
+        .iter()
+        .map(|entry| entry.file.as_str())
+        .collect();
+    assert_eq!(
+        files,
+        vec![
+            "01 - First.flac",
+            "02 - Second.flac",
+            "03 - Third (Live).flac"
+        ]
+    );
+    assert_eq!(
+        manifest.entries.last().map(|entry| entry.checksum.as_str()),
+        Some("fedcba9876543210fedcba9876543210")
+    );
+}
+
+#[test]
+fn checksum_manifest_parse_sfv() {
+    // Arrange
+    let text = "; Generated by QuickSFV\n01 - First Track.flac 352441C2\n";
+
+    // Act
+    let manifest = ChecksumManifest::parse(ManifestFormat::Sfv, text);
+
+    // Assert
+    assert_eq!(
+        manifest.entries,
+        vec![ManifestEntry {
+            file: "01 - First Track.flac".to_owned(),
+            checksum: "352441c2".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn checksum_manifest_parse_sha256_ignores_md5() {
+    // Arrange
+    let text = "d41d8cd98f00b204e9800998ecf8427e  01 - First.flac\n";
+
+    // Act
+    let manifest = ChecksumManifest::parse(ManifestFormat::Sha256, text);
+
+    // Assert
+    assert_eq!(manifest.entries, Vec::new());
+}
+
+#[test]
+fn manifest_format_hash_file() {
+    // Arrange
+    let dir = TempDirectory::create("manifest_format_hash_file");
+    let path = dir.join("abc.txt");
+    write(&path, "abc").expect("should write file");
+
+    // Act
+    let md5 = ManifestFormat::Md5.hash_file(&path).expect("should hash");
+    let sfv = ManifestFormat::Sfv.hash_file(&path).expect("should hash");
+    let sha256 = ManifestFormat::Sha256
+        .hash_file(&path)
+        .expect("should hash");
+
+    // Assert
+    assert_eq!(md5, "900150983cd24fb0d6963f7d28e17f72");
+    assert_eq!(sfv, "352441c2");
+    assert_eq!(
+        sha256,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn manifest_format_from_path() {
+    // Act
+    let formats: Vec<Option<ManifestFormat>> = ["a.FFP", "a.md5", "a.sfv", "a.sha256", "a.log"]
+        .iter()
+        .map(|name| ManifestFormat::from_path(Path::new(name)))
+        .collect();
+
+    // Assert
+    assert_eq!(
+        formats,
+        vec![
+            Some(ManifestFormat::Ffp),
+            Some(ManifestFormat::Md5),
+            Some(ManifestFormat::Sfv),
+            Some(ManifestFormat::Sha256),
+            None,
+        ]
+    );
+}
@@ -0,0 +1 @@
+mod checksum_manifest_tests;
@@ -0,0 +1,290 @@
+use crate::prelude::*;
+use std::fs::read as read_bytes;
+
+/// Frames per second of CD audio, the resolution of cue sheet times.
+pub(crate) const FRAMES_PER_SECOND: u32 = 75;
+
+/// Position in a file of a cue sheet as `mm:ss:ff`.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) struct CueTime {
+    /// Total number of frames.
+    pub frames: u32,
+}
+
+impl CueTime {
+    /// Create a [`CueTime`] from a number of frames.
+    #[must_use]
+    pub(crate) fn from_frames(frames: u32) -> Self {
+        Self { frames }
+    }
+
+    /// Create a [`CueTime`] from a number of samples at `sample_rate`, rounded down.
+    #[must_use]
+    #[expect(clippy::integer_division, reason = "whole frames")]
+    pub(crate) fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        let frames = samples * u64::from(FRAMES_PER_SECOND) / u64::from(sample_rate.max(1));
+        Self {
+            frames: u32::try_from(frames).unwrap_or(u32::MAX),
+        }
+    }
+}
+
+impl FromStr for CueTime {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.trim().split(':');
+        let mut next = || -> Result<u32, ()> { parts.next().ok_or(())?.parse().map_err(|_| ()) };
+        let minutes = next()?;
+        let seconds = next()?;
+        let frames = next()?;
+        if parts.next().is_some() || seconds >= 60 || frames >= FRAMES_PER_SECOND {
+            return Err(());
+        }
+        Ok(Self {
+            frames: (minutes * 60 + seconds) * FRAMES_PER_SECOND + frames,
+        })
+    }
+}
+
+impl Display for CueTime {
+    #[expect(clippy::integer_division, reason = "whole minutes and seconds")]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let seconds = self.frames / FRAMES_PER_SECOND;
+        write!(
+            formatter,
+            "{:02}:{:02}:{:02}",
+            seconds / 60,
+            seconds % 60,
+            self.frames % FRAMES_PER_SECOND
+        )
+    }
+}
+
+/// An `INDEX` of a track in a cue sheet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct CueIndex {
+    /// Index number where `0` is the start of the gap and `1` the start of the track.
+    pub number: u32,
+    /// Position of [`CueSheet::files`] the index is in.
+    pub file: usize,
+    /// Position in the file.
+    pub time: CueTime,
+}
+
+/// A `TRACK` of a cue sheet.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CueTrack {
+    /// Track number.
+    pub number: u32,
+    /// Data type such as `AUDIO` or `MODE1/2352`.
+    pub data_type: String,
+    /// Title of the track.
+    pub title: Option<String>,
+    /// Performer of the track.
+    pub performer: Option<String>,
+    /// Is the `PRE` flag set?
+    pub pre_emphasis: bool,
+    /// Silence generated before the track that is not in any file.
+    pub pregap: Option<CueTime>,
+    /// Indexes of the track in the order they are listed.
+    ///
+    /// `INDEX 00` may be in an earlier file than `INDEX 01` when gaps are appended to the
+    /// previous track.
+    pub indexes: Vec<CueIndex>,
+}
+
+impl CueTrack {
+    /// Check if the track is audio rather than data.
+    #[must_use]
+    pub(crate) fn is_audio(&self) -> bool {
+        self.data_type.eq_ignore_ascii_case("AUDIO")
+    }
+
+    /// Get an index by number.
+    #[must_use]
+    pub(crate) fn get_index(&self, number: u32) -> Option<&CueIndex> {
+        self.indexes.iter().find(|index| index.number == number)
+    }
+
+    /// Length of the gap between `INDEX 00` and `INDEX 01` if both are in the same file.
+    #[must_use]
+    pub(crate) fn get_gap(&self) -> Option<CueTime> {
+        let start = self.get_index(0)?;
+        let end = self.get_index(1)?;
+        if start.file != end.file {
+            return None;
+        }
+        Some(CueTime::from_frames(
+            end.time.frames.checked_sub(start.time.frames)?,
+        ))
+    }
+}
+
+/// Files and tracks of a cue sheet.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct CueSheet {
+    /// Title of the album.
+    pub title: Option<String>,
+    /// Performer of the album.
+    pub performer: Option<String>,
+    /// File names in the order they are listed.
+    pub files: Vec<String>,
+    /// Tracks in the order they are listed.
+    pub tracks: Vec<CueTrack>,
+}
+
+impl CueSheet {
+    /// Read and parse a cue sheet.
+    pub(crate) fn read(path: &Path) -> Result<Self, IoError> {
+        let bytes = read_bytes(path)?;
+        Ok(Self::parse(&decode_text(&bytes)))
+    }
+
+    /// Find the `.cue` files in a directory, sorted by path.
+    pub(crate) fn find(dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+        let mut paths = DirectoryReader::new().with_extension("cue").read(dir)?;
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Parse the text of a cue sheet.
+    ///
+    /// Commands that are not needed to check or split the files and tracks are ignored, as are
+    /// malformed lines.
+    #[must_use]
+    pub(crate) fn parse(text: &str) -> Self {
+        let mut sheet = Self::default();
+        for line in text.lines() {
+            let line = line.trim().trim_start_matches('\u{feff}');
+            let (command, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            sheet.parse_command(&command.to_uppercase(), value.trim());
+        }
+        sheet
+    }
+
+    fn parse_command(&mut self, command: &str, value: &str) {
+        match command {
+            "FILE" => {
+                if let Some(name) = parse_file_name(value) {
+                    self.files.push(name);
+                }
+            }
+            "TRACK" => {
+                let mut parts = value.split_whitespace();
+                if let Some(number) = parts.next().and_then(|number| number.parse().ok()) {
+                    self.tracks.push(CueTrack {
+                        number,
+                        data_type: parts.next().unwrap_or_default().to_owned(),
+                        ..CueTrack::default()
+                    });
+                }
+            }
+            "TITLE" => {
+                let title = parse_text(value);
+                match self.tracks.last_mut() {
+                    Some(track) => track.title = title,
+                    None => self.title = title,
+                }
+            }
+            "PERFORMER" => {
+                let performer = parse_text(value);
+                match self.tracks.last_mut() {
+                    Some(track) => track.performer = performer,
+                    None => self.performer = performer,
+                }
+            }
+            "FLAGS" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.pre_emphasis = value
+                        .split_whitespace()
+                        .any(|flag| flag.eq_ignore_ascii_case("PRE"));
+                }
+            }
+            "PREGAP" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.pregap = value.parse().ok();
+                }
+            }
+            "INDEX" => {
+                let file = self.files.len().saturating_sub(1);
+                if let Some(track) = self.tracks.last_mut()
+                    && let Some((number, time)) = value.split_once(char::is_whitespace)
+                    && let Ok(number) = number.parse()
+                    && let Ok(time) = time.parse()
+                {
+                    track.indexes.push(CueIndex { number, file, time });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Tracks that contain audio.
+    pub(crate) fn get_audio_tracks(&self) -> impl Iterator<Item = &CueTrack> {
+        self.tracks.iter().filter(|track| track.is_audio())
+    }
+
+    /// Names of the files that contain an audio track flagged with pre-emphasis.
+    ///
+    /// A track is in the file of its `INDEX 01`.
+    #[must_use]
+    pub(crate) fn get_pre_emphasis_files(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = self
+            .get_audio_tracks()
+            .filter(|track| track.pre_emphasis)
+            .filter_map(|track| track.get_index(1).or_else(|| track.indexes.last()))
+            .filter_map(|index| self.files.get(index.file))
+            .map(String::as_str)
+            .collect();
+        files.dedup();
+        files
+    }
+
+    /// Check if the cue sheet is of a single image file containing every track.
+    #[must_use]
+    pub(crate) fn is_image(&self) -> bool {
+        self.files.len() == 1 && self.get_audio_tracks().count() > 1
+    }
+}
+
+/// Get the name of a file of a cue sheet without the directory or extension.
+///
+/// EAC cue sheets refer to `.wav` files so the stem is compared to the stem of each FLAC.
+#[must_use]
+pub(crate) fn get_file_stem(name: &str) -> &str {
+    let name = name.rsplit(['\\', '/']).next().unwrap_or(name);
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+/// Get the text of a `TITLE` or `PERFORMER` command, which may be quoted.
+fn parse_text(value: &str) -> Option<String> {
+    let text = value
+        .strip_prefix('"')
+        .map_or(value, |quoted| {
+            quoted.rsplit_once('"').map_or(quoted, |(text, _)| text)
+        })
+        .trim();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_owned())
+    }
+}
+
+/// Get the file name of a `FILE` command, which may be quoted, without its type.
+fn parse_file_name(value: &str) -> Option<String> {
+    let name = if let Some(quoted) = value.strip_prefix('"') {
+        quoted.split_once('"').map(|(name, _)| name)?
+    } else {
+        value
+            .rsplit_once(char::is_whitespace)
+            .map_or(value, |(name, _)| name)
+    };
+    let name = name.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_owned())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// Vorbis comment of a FLAC image that embeds its cue sheet.
+///
+/// The cue sheet describes the whole image so it is excluded from each track.
+pub(crate) const CUESHEET_TAG: &str = "CUESHEET";
+
+/// A track of a single file FLAC image split at the `INDEX 01` points of its cue sheet.
+///
+/// - Each track runs from its `INDEX 01` to the `INDEX 01` of the next track so gaps are
+///   appended to the previous track
+/// - Audio before the `INDEX 01` of the first track, such as hidden track one audio, is not
+///   included in any track
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageTrack {
+    /// Track number.
+    pub number: u32,
+    /// Number of audio tracks in the image.
+    pub total: u32,
+    /// Samples of the image in the track.
+    pub range: SampleRange,
+    /// Title of the track.
+    pub title: Option<String>,
+    /// Performer of the track, or of the album if the track has none.
+    pub performer: Option<String>,
+    /// Title of the album.
+    pub album: Option<String>,
+    /// Is the `PRE` flag of the track set?
+    pub pre_emphasis: bool,
+}
+
+impl ImageTrack {
+    /// Get the tracks of a cue sheet of an image with `sample_rate`.
+    ///
+    /// Returns an empty [`Vec`] if the cue sheet is not of an image, or if any audio track has
+    /// no `INDEX 01` or starts before the previous track.
+    #[must_use]
+    pub(crate) fn from_sheet(sheet: &CueSheet, sample_rate: u32) -> Vec<Self> {
+        if !sheet.is_image() {
+            return Vec::new();
+        }
+        let mut starts = Vec::new();
+        for track in sheet.get_audio_tracks() {
+            let Some(index) = track.get_index(1) else {
+                return Vec::new();
+            };
+            let start = get_sample(index.time, sample_rate);
+            if starts.last().is_some_and(|previous| *previous >= start) {
+                return Vec::new();
+            }
+            starts.push(start);
+        }
+        let total = u32::try_from(starts.len()).unwrap_or(u32::MAX);
+        let ends = starts.iter().skip(1).copied().map(Some).chain([None]);
+        sheet
+            .get_audio_tracks()
+            .zip(starts.iter().copied().zip(ends))
+            .map(|(track, (start, end))| Self {
+                number: track.number,
+                total,
+                range: SampleRange { start, end },
+                title: track.title.clone(),
+                performer: track.performer.clone().or_else(|| sheet.performer.clone()),
+                album: sheet.title.clone(),
+                pre_emphasis: track.pre_emphasis,
+            })
+            .collect()
+    }
+
+    /// File name of the track without an extension.
+    ///
+    /// Formatted as `{number} {title}` with the number padded to two digits.
+    #[must_use]
+    pub(crate) fn get_file_name(&self) -> String {
+        match &self.title {
+            Some(title) => {
+                let title = Sanitizer::name().execute(title.clone()).output;
+                format!("{:02} {title}", self.number)
+            }
+            None => format!("{:02}", self.number),
+        }
+    }
+
+    /// Vorbis comments of the track that replace those of the image.
+    #[must_use]
+    pub(crate) fn get_comments(&self) -> Vec<(&'static str, String)> {
+        let mut comments = vec![
+            ("TRACKNUMBER", self.number.to_string()),
+            ("TRACKTOTAL", self.total.to_string()),
+        ];
+        if let Some(title) = &self.title {
+            comments.push(("TITLE", title.clone()));
+        }
+        if let Some(performer) = &self.performer {
+            comments.push(("ARTIST", performer.clone()));
+        }
+        comments
+    }
+}
+
+/// Get the first sample of a cue sheet time at `sample_rate`.
+///
+/// Cue sheet frames are 1/75 of a second so the position is exact for every common sample rate.
+#[expect(clippy::integer_division, reason = "exact for common sample rates")]
+fn get_sample(time: CueTime, sample_rate: u32) -> u64 {
+    u64::from(time.frames) * u64::from(sample_rate) / u64::from(FRAMES_PER_SECOND)
+}
@@ -0,0 +1,11 @@
+//! Parse cue sheets of CD rips and split FLAC images into tracks.
+
+pub(crate) use cue_sheet::*;
+pub(crate) use image_track::*;
+pub(crate) use pre_emphasis::*;
+
+mod cue_sheet;
+mod image_track;
+mod pre_emphasis;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// Vorbis comment that flags a FLAC as pre-emphasized.
+pub(crate) const PRE_EMPHASIS_TAG: &str = "PRE_EMPHASIS";
+
+/// Set [`FlacFile::pre_emphasis`] of each FLAC that is flagged with pre-emphasis.
+///
+/// - Flagged by a [`PRE_EMPHASIS_TAG`] that is not `0`, `no`, `false` or `off`
+/// - Or by the `PRE` flag of a track in a cue sheet in the same directory
+/// - Or for a track of a FLAC image by the `PRE` flag of that track
+///
+/// The cue sheets of each directory are read once rather than for every FLAC.
+pub(crate) fn resolve_pre_emphasis(flacs: &mut [FlacFile]) {
+    let mut flagged: HashMap<PathBuf, Vec<String>> = HashMap::new();
+    for flac in flacs {
+        flac.pre_emphasis = if let Some(track) = &flac.image_track {
+            track.pre_emphasis || has_pre_emphasis_tag(&flac.path)
+        } else {
+            let dir = flac.path.parent().unwrap_or(&flac.path).to_path_buf();
+            let files = flagged
+                .entry(dir)
+                .or_insert_with_key(|dir| get_pre_emphasis_files(dir));
+            has_pre_emphasis_tag(&flac.path) || files.contains(&flac.file_name)
+        };
+    }
+}
+
+/// Check if a FLAC has a [`PRE_EMPHASIS_TAG`] that is enabled.
+#[must_use]
+pub(crate) fn has_pre_emphasis_tag(path: &Path) -> bool {
+    match get_vorbis_comment(path, PRE_EMPHASIS_TAG) {
+        Ok(Some(value)) => !matches!(
+            value.trim().to_lowercase().as_str(),
+            "" | "0" | "no" | "false" | "off"
+        ),
+        Ok(None) => false,
+        Err(failure) => {
+            warn!("{}", failure.render());
+            false
+        }
+    }
+}
+
+/// Get the stem of each file with a pre-emphasized track in the cue sheets of a directory.
+fn get_pre_emphasis_files(dir: &Path) -> Vec<String> {
+    let paths = match CueSheet::find(dir) {
+        Ok(paths) => paths,
+        Err(error) => {
+            warn!("Failed to read cue sheets of {}: {error}", dir.display());
+            return Vec::new();
+        }
+    };
+    paths
+        .iter()
+        .filter(|path| path.parent() == Some(dir))
+        .filter_map(|path| match CueSheet::read(path) {
+            Ok(sheet) => Some(sheet),
+            Err(error) => {
+                warn!("Failed to read cue sheet {}: {error}", path.display());
+                None
+            }
+        })
+        .flat_map(|sheet| {
+            sheet
+                .get_pre_emphasis_files()
+                .into_iter()
+                .map(|name| get_file_stem(name).to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
@@ -0,0 +1,149 @@
+use crate::testing_prelude::*;
+
+/// EAC cue sheet with gaps appended to the previous track, hidden track one audio and
+/// pre-emphasis on track 2.
+const EAC_CUE: &str = "REM GENRE Rock
+REM DATE 1985
+PERFORMER \"Artist\"
+TITLE \"Album\"
+FILE \"01 - First.wav\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"First\"
+    INDEX 00 00:00:00
+    INDEX 01 00:32:15
+  TRACK 02 AUDIO
+    TITLE \"Second\"
+    FLAGS DCP PRE
+    INDEX 00 04:02:50
+FILE \"02 - Second.wav\" WAVE
+    INDEX 01 00:00:00
+";
+
+#[test]
+fn cue_sheet_parse_eac() {
+    // Act
+    let sheet = CueSheet::parse(EAC_CUE);
+
+    // Assert
+    assert_eq!(sheet.files, vec!["01 - First.wav", "02 - Second.wav"]);
+    assert_eq!(sheet.tracks.len(), 2);
+    let first = sheet.tracks.first().expect("should have track 1");
+    assert_eq!(first.number, 1);
+    assert!(first.is_audio());
+    assert!(!first.pre_emphasis);
+    assert_eq!(first.get_gap(), Some(CueTime::from_frames(32 * 75 + 15)));
+    let second = sheet.tracks.get(1).expect("should have track 2");
+    assert!(second.pre_emphasis);
+    assert_eq!(
+        second.indexes,
+        vec![
+            CueIndex {
+                number: 0,
+                file: 0,
+                time: CueTime::from_frames((4 * 60 + 2) * 75 + 50),
+            },
+            CueIndex {
+                number: 1,
+                file: 1,
+                time: CueTime::default(),
+            },
+        ]
+    );
+    assert_eq!(second.get_gap(), None);
+    assert!(!sheet.is_image());
+}
+
+#[test]
+fn cue_sheet_get_pre_emphasis_files() {
+    // Arrange
+    let sheet = CueSheet::parse(EAC_CUE);
+
+    // Act
+    let files = sheet.get_pre_emphasis_files();
+
+    // Assert
+    assert_eq!(files, vec!["02 - Second.wav"]);
+}
+
+#[test]
+fn get_file_stem_strips_directory_and_extension() {
+    // Act
+    let windows = get_file_stem("C:\\Rips\\01 - First.wav");
+    let unix = get_file_stem("rips/01 - First.flac");
+    let bare = get_file_stem("01 - First");
+
+    // Assert
+    assert_eq!(windows, "01 - First");
+    assert_eq!(unix, "01 - First");
+    assert_eq!(bare, "01 - First");
+}
+
+#[test]
+fn cue_sheet_parse_image() {
+    // Arrange
+    let text = "FILE Album.flac WAVE\n  TRACK 01 AUDIO\n    PREGAP 00:02:00\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    INDEX 01 03:10:00\n";
+
+    // Act
+    let sheet = CueSheet::parse(text);
+
+    // Assert
+    assert_eq!(sheet.files, vec!["Album.flac"]);
+    assert_eq!(
+        sheet.tracks.first().and_then(|track| track.pregap),
+        Some(CueTime::from_frames(150))
+    );
+    assert!(sheet.is_image());
+}
+
+#[test]
+fn cue_sheet_parse_data_track() {
+    // Arrange
+    let text = "FILE \"01.wav\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\nFILE \"data.bin\" BINARY\n  TRACK 02 MODE1/2352\n    INDEX 01 00:00:00\n";
+
+    // Act
+    let sheet = CueSheet::parse(text);
+
+    // Assert
+    assert_eq!(sheet.tracks.len(), 2);
+    assert_eq!(sheet.get_audio_tracks().count(), 1);
+}
+
+#[test]
+fn cue_time_parse_and_display() {
+    // Act
+    let time: Result<CueTime, ()> = "04:02:50".parse();
+
+    // Assert
+    let time = time.expect("should parse");
+    assert_eq!(time.frames, (4 * 60 + 2) * 75 + 50);
+    assert_eq!(time.to_string(), "04:02:50");
+    assert!("04:60:00".parse::<CueTime>().is_err());
+    assert!("04:02:75".parse::<CueTime>().is_err());
+    assert!("04:02".parse::<CueTime>().is_err());
+}
+
+#[test]
+fn cue_time_from_samples() {
+    // Act
+    let time = CueTime::from_samples(44_100 * 3 + 588 * 2 + 100, 44_100);
+
+    // Assert
+    assert_eq!(time, CueTime::from_frames(3 * 75 + 2));
+}
+
+#[test]
+fn cue_sheet_parse_title_and_performer() {
+    // Act
+    let sheet = CueSheet::parse(EAC_CUE);
+
+    // Assert
+    assert_eq!(sheet.title.as_deref(), Some("Album"));
+    assert_eq!(sheet.performer.as_deref(), Some("Artist"));
+    let titles: Vec<Option<&str>> = sheet
+        .tracks
+        .iter()
+        .map(|track| track.title.as_deref())
+        .collect();
+    assert_eq!(titles, vec![Some("First"), Some("Second")]);
+    assert!(sheet.tracks.iter().all(|track| track.performer.is_none()));
+}
@@ -0,0 +1,131 @@
+use crate::testing_prelude::*;
+
+/// Cue sheet of an image with a gap before track 2 and an untitled track 3.
+const IMAGE_CUE: &str = "PERFORMER \"Artist\"
+TITLE \"Album\"
+FILE \"Artist - Album.wav\" WAVE
+  TRACK 01 AUDIO
+    TITLE \"First\"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE \"Second: Part 1\"
+    PERFORMER \"Guest\"
+    FLAGS PRE
+    INDEX 00 03:10:00
+    INDEX 01 03:12:00
+  TRACK 03 AUDIO
+    INDEX 01 05:00:01
+";
+
+#[test]
+fn image_track_from_sheet() {
+    // Arrange
+    let sheet = CueSheet::parse(IMAGE_CUE);
+
+    // Act
+    let tracks = ImageTrack::from_sheet(&sheet, 44_100);
+
+    // Assert
+    assert_eq!(
+        tracks,
+        vec![
+            ImageTrack {
+                number: 1,
+                total: 3,
+                range: SampleRange {
+                    start: 0,
+                    end: Some(192 * 44_100),
+                },
+                title: Some("First".to_owned()),
+                performer: Some("Artist".to_owned()),
+                album: Some("Album".to_owned()),
+                pre_emphasis: false,
+            },
+            ImageTrack {
+                number: 2,
+                total: 3,
+                range: SampleRange {
+                    start: 192 * 44_100,
+                    end: Some(300 * 44_100 + 588),
+                },
+                title: Some("Second: Part 1".to_owned()),
+                performer: Some("Guest".to_owned()),
+                album: Some("Album".to_owned()),
+                pre_emphasis: true,
+            },
+            ImageTrack {
+                number: 3,
+                total: 3,
+                range: SampleRange {
+                    start: 300 * 44_100 + 588,
+                    end: None,
+                },
+                title: None,
+                performer: Some("Artist".to_owned()),
+                album: Some("Album".to_owned()),
+                pre_emphasis: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn image_track_from_sheet_not_image() {
+    // Arrange
+    let text = "FILE \"01.wav\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\nFILE \"02.wav\" WAVE\n  TRACK 02 AUDIO\n    INDEX 01 00:00:00\n";
+    let sheet = CueSheet::parse(text);
+
+    // Act
+    let tracks = ImageTrack::from_sheet(&sheet, 44_100);
+
+    // Assert
+    assert!(tracks.is_empty());
+}
+
+#[test]
+fn image_track_from_sheet_unordered_indexes() {
+    // Arrange
+    let text = "FILE \"Album.wav\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 01:00:00\n  TRACK 02 AUDIO\n    INDEX 01 00:30:00\n";
+    let sheet = CueSheet::parse(text);
+
+    // Act
+    let tracks = ImageTrack::from_sheet(&sheet, 44_100);
+
+    // Assert
+    assert!(tracks.is_empty());
+}
+
+#[test]
+fn image_track_get_file_name() {
+    // Arrange
+    let sheet = CueSheet::parse(IMAGE_CUE);
+    let tracks = ImageTrack::from_sheet(&sheet, 44_100);
+
+    // Act
+    let names: Vec<String> = tracks.iter().map(ImageTrack::get_file_name).collect();
+
+    // Assert
+    assert_eq!(names, vec!["01 First", "02 Second Part 1", "03"]);
+}
+
+#[test]
+fn image_track_get_comments() {
+    // Arrange
+    let sheet = CueSheet::parse(IMAGE_CUE);
+    let tracks = ImageTrack::from_sheet(&sheet, 44_100);
+    let track = tracks.get(1).expect("should have track 2");
+
+    // Act
+    let comments = track.get_comments();
+
+    // Assert
+    assert_eq!(
+        comments,
+        vec![
+            ("TRACKNUMBER", "2".to_owned()),
+            ("TRACKTOTAL", "3".to_owned()),
+            ("TITLE", "Second: Part 1".to_owned()),
+            ("ARTIST", "Guest".to_owned()),
+        ]
+    );
+}
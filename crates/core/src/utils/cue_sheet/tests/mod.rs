@@ -0,0 +1,3 @@
+mod cue_sheet_tests;
+mod image_track_tests;
+mod pre_emphasis_tests;
@@ -0,0 +1,59 @@
+use crate::testing_prelude::*;
+
+/// Cue sheet with gaps appended to the previous track and pre-emphasis on track 2.
+const CUE: &str = "FILE \"01 - First.wav\" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    FLAGS PRE
+    INDEX 00 00:00:40
+FILE \"02 - Second.wav\" WAVE
+    INDEX 01 00:00:00
+";
+
+#[test]
+fn resolve_pre_emphasis_cue_flag() {
+    // Arrange
+    let dir = TempDirectory::create("resolve_pre_emphasis_cue_flag");
+    let first = write_flac(&dir, "01 - First");
+    let second = write_flac(&dir, "02 - Second");
+    write(dir.join("Album.cue"), CUE).expect("should write cue");
+
+    let mut flacs = vec![first, second];
+
+    // Act
+    resolve_pre_emphasis(&mut flacs);
+
+    // Assert
+    let output: Vec<bool> = flacs.iter().map(|flac| flac.pre_emphasis).collect();
+    assert_eq!(output, vec![false, true], "only track 2 is flagged");
+}
+
+#[test]
+fn resolve_pre_emphasis_tag() {
+    // Arrange
+    let dir = TempDirectory::create("resolve_pre_emphasis_tag");
+    let enabled = write_flac(&dir, "01 - Enabled");
+    let disabled = write_flac(&dir, "02 - Disabled");
+    insert_vorbis_comments_into_flac(&enabled.path, vec![(PRE_EMPHASIS_TAG, "1".to_owned())])
+        .expect("should write tags");
+    insert_vorbis_comments_into_flac(&disabled.path, vec![(PRE_EMPHASIS_TAG, "no".to_owned())])
+        .expect("should write tags");
+
+    let mut flacs = vec![enabled, disabled];
+
+    // Act
+    resolve_pre_emphasis(&mut flacs);
+
+    // Assert
+    let output: Vec<bool> = flacs.iter().map(|flac| flac.pre_emphasis).collect();
+    assert_eq!(output, vec![true, false]);
+}
+
+/// Encode a short 16-bit 44.1 kHz FLAC of silence.
+fn write_flac(dir: &Path, name: &str) -> FlacFile {
+    PcmFlacGenerator::new()
+        .with_filename(format!("{name}.flac"))
+        .with_samples(vec![0; 588 * 2])
+        .generate_flac_file(dir)
+}
@@ -1,5 +1,51 @@
 use crate::prelude::*;
 
+/// How the edition of a group torrent relates to the edition of the source.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum EditionMatch {
+    /// Same [`EditionKey`] as the source.
+    Exact,
+    /// The [`EditionKey`] of the source is less specific so it is a possible duplicate.
+    LessSpecific,
+    /// The [`EditionKey`] of the source is less specific but `allow_less_specific` is set.
+    AllowedLessSpecific,
+    /// A different edition.
+    Different,
+}
+
+impl EditionMatch {
+    /// Whether the formats of the torrent count as existing formats of the source.
+    #[must_use]
+    pub(crate) fn is_existing(self) -> bool {
+        matches!(self, Self::Exact | Self::LessSpecific)
+    }
+}
+
+impl Display for EditionMatch {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let description = match self {
+            Self::Exact => "Same edition",
+            Self::LessSpecific => "Possible duplicate as source edition is less specific",
+            Self::AllowedLessSpecific => {
+                "Ignored as source edition is less specific and allow_less_specific is set"
+            }
+            Self::Different => "Different edition",
+        };
+        write!(formatter, "{description}")
+    }
+}
+
+/// A group torrent and how it matched the edition of the source.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ExistingFormatMatch {
+    /// ID of the group torrent.
+    pub id: u32,
+    /// Format of the group torrent, if it is a known [`ExistingFormat`].
+    pub format: Option<ExistingFormat>,
+    /// How the edition matched the source.
+    pub edition: EditionMatch,
+}
+
 /// Determine which formats already exist for a given release.
 #[injectable]
 pub struct ExistingFormatProvider {
@@ -20,25 +66,60 @@ impl ExistingFormatProvider {
         source_torrent: &Torrent,
         group_torrents: &[Torrent],
     ) -> BTreeSet<ExistingFormat> {
-        let source_key = EditionKey::from_torrent(source_torrent);
-        let mut existing: BTreeSet<ExistingFormat> = group_torrents
-            .iter()
-            .filter(|&other| EditionKey::from_torrent(other) == source_key)
-            .filter_map(ExistingFormat::from_torrent)
-            .collect();
-        if !self.options.allow_less_specific {
-            let possible_dupes: BTreeSet<ExistingFormat> = group_torrents
-                .iter()
-                .filter(|&other| source_key.is_less_specific_than(&EditionKey::from_torrent(other)))
-                .filter_map(ExistingFormat::from_torrent)
-                .collect();
-            for format in &possible_dupes {
-                if !existing.contains(format) {
-                    debug!("{} {format} as a possible duplicate", "Excluding".bold());
+        let mut existing = BTreeSet::new();
+        let mut possible_dupes = BTreeSet::new();
+        for matched in self.explain(source_torrent, group_torrents) {
+            let Some(format) = matched.format else {
+                continue;
+            };
+            match matched.edition {
+                EditionMatch::Exact => {
+                    existing.insert(format);
+                }
+                EditionMatch::LessSpecific => {
+                    possible_dupes.insert(format);
                 }
+                EditionMatch::AllowedLessSpecific | EditionMatch::Different => {}
             }
-            existing.extend(possible_dupes);
         }
+        for format in &possible_dupes {
+            if !existing.contains(format) {
+                debug!("{} {format} as a possible duplicate", "Excluding".bold());
+            }
+        }
+        existing.extend(possible_dupes);
         existing
     }
+
+    /// Explain how each torrent in the group matched the edition of the source.
+    ///
+    /// Torrents are returned in the order of the group.
+    #[must_use]
+    pub(crate) fn explain(
+        &self,
+        source_torrent: &Torrent,
+        group_torrents: &[Torrent],
+    ) -> Vec<ExistingFormatMatch> {
+        let source_key = EditionKey::from_torrent(source_torrent);
+        group_torrents
+            .iter()
+            .map(|other| ExistingFormatMatch {
+                id: other.id,
+                format: ExistingFormat::from_torrent(other),
+                edition: self.match_edition(&source_key, &EditionKey::from_torrent(other)),
+            })
+            .collect()
+    }
+
+    fn match_edition(&self, source_key: &EditionKey, other_key: &EditionKey) -> EditionMatch {
+        if source_key == other_key {
+            EditionMatch::Exact
+        } else if !source_key.is_less_specific_than(other_key) {
+            EditionMatch::Different
+        } else if self.options.allow_less_specific {
+            EditionMatch::AllowedLessSpecific
+        } else {
+            EditionMatch::LessSpecific
+        }
+    }
 }
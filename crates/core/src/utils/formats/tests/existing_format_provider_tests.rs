@@ -123,6 +123,60 @@ fn existing_format_provider_get_excludes_different_year() {
     assert!(!output.contains(&ExistingFormat::_320));
 }
 
+#[test]
+fn existing_format_provider_explain_each_group_torrent() {
+    // Arrange
+    let source = Torrent {
+        id: 1,
+        remaster_record_label: String::new(),
+        ..Torrent::mock()
+    };
+    let exact = Torrent {
+        id: 2,
+        format: Format::MP3,
+        encoding: Quality::_320,
+        remaster_record_label: String::new(),
+        ..Torrent::mock()
+    };
+    let less_specific = Torrent {
+        id: 3,
+        format: Format::MP3,
+        encoding: Quality::V0,
+        ..Torrent::mock()
+    };
+    let different = Torrent {
+        id: 4,
+        remaster_year: Some(1999),
+        ..Torrent::mock()
+    };
+    let group_torrents = vec![source.clone(), exact, less_specific, different];
+
+    // Act
+    let output = create_provider(false).explain(&source, &group_torrents);
+    let allowed = create_provider(true).explain(&source, &group_torrents);
+
+    // Assert
+    let editions: Vec<EditionMatch> = output.iter().map(|matched| matched.edition).collect();
+    assert_eq!(
+        editions,
+        vec![
+            EditionMatch::Exact,
+            EditionMatch::Exact,
+            EditionMatch::LessSpecific,
+            EditionMatch::Different,
+        ]
+    );
+    assert_eq!(
+        output.get(2).and_then(|matched| matched.format),
+        Some(ExistingFormat::V0)
+    );
+    assert_eq!(
+        allowed.get(2).map(|matched| matched.edition),
+        Some(EditionMatch::AllowedLessSpecific)
+    );
+    assert!(!EditionMatch::AllowedLessSpecific.is_existing());
+}
+
 fn create_provider(allow_less_specific: bool) -> ExistingFormatProvider {
     ExistingFormatProvider {
         options: Ref::new(TargetOptions {
@@ -24,14 +24,49 @@ impl Collector {
         collection
     }
 
-    /// Create [`FlacFile`] for each `.flac` file in a directory with [`DiscContext`] attached.
+    /// Create [`FlacFile`] for each track in a directory.
+    ///
+    /// Same as [`Collector::get_flacs`] except a single file FLAC image with a cue sheet is
+    /// replaced by a [`FlacFile`] for each track of the cue sheet, and the pre-emphasis flag of
+    /// each track is resolved.
+    #[must_use]
+    pub fn get_tracks(source_dir: &PathBuf) -> Vec<FlacFile> {
+        let flacs = Self::get_flacs(source_dir);
+        let mut tracks = Vec::with_capacity(flacs.len());
+        for flac in flacs {
+            let image_tracks = get_image_tracks(&flac);
+            if image_tracks.is_empty() {
+                tracks.push(flac);
+                continue;
+            }
+            debug!(
+                "{} {} into {} tracks",
+                "Splitting".bold(),
+                flac.path.display(),
+                image_tracks.len()
+            );
+            for track in image_tracks {
+                tracks.push(FlacFile::new_image_track(
+                    flac.path.clone(),
+                    source_dir,
+                    track,
+                ));
+            }
+        }
+        resolve_pre_emphasis(&mut tracks);
+        tracks
+    }
+
+    /// Create [`FlacFile`] for each track in a directory with [`DiscContext`] attached.
     ///
     /// This variant computes disc context (multi-disc detection, track padding) and
     /// attaches it to each flac. Use this when you need disc/track information for
     /// renaming or validation.
+    ///
+    /// FLAC images are split into tracks as with [`Collector::get_tracks`].
     #[must_use]
     pub fn get_flacs_with_context(source_dir: &PathBuf) -> Vec<FlacFile> {
-        let mut flacs = Self::get_flacs(source_dir);
+        let mut flacs = Self::get_tracks(source_dir);
         let context = DiscContext::from_flacs(&flacs);
         for flac in &mut flacs {
             flac.disc_context = Some(context.clone());
@@ -82,3 +117,69 @@ impl Collector {
         collection
     }
 }
+
+/// Get the tracks of a FLAC if it is an image with a cue sheet in the same directory.
+///
+/// The cue sheet must list only the FLAC, matched by file stem as EAC cue sheets refer to
+/// `.wav` files. Returns an empty [`Vec`] if the FLAC is not an image or can't be read.
+///
+/// Audio before `INDEX 01` of track 1 is not part of any track so a warning is logged if it
+/// is discarded.
+fn get_image_tracks(flac: &FlacFile) -> Vec<ImageTrack> {
+    let Some(dir) = flac.path.parent() else {
+        return Vec::new();
+    };
+    let paths = match CueSheet::find(dir) {
+        Ok(paths) => paths,
+        Err(error) => {
+            warn!("Failed to read cue sheets of {}: {error}", dir.display());
+            return Vec::new();
+        }
+    };
+    let Some(sheet) = paths
+        .iter()
+        .filter(|path| path.parent() == Some(dir))
+        .filter_map(|path| CueSheet::read(path).ok())
+        .find(|sheet| {
+            sheet.is_image()
+                && sheet
+                    .files
+                    .first()
+                    .is_some_and(|name| get_file_stem(name) == flac.file_name)
+        })
+    else {
+        return Vec::new();
+    };
+    let info = match flac.get_stream_info() {
+        Ok(info) => info,
+        Err(error) => {
+            warn!("Failed to read FLAC image {}: {error}", flac.path.display());
+            return Vec::new();
+        }
+    };
+    let tracks = ImageTrack::from_sheet(&sheet, info.sample_rate);
+    if tracks.is_empty() {
+        warn!(
+            "Not splitting {} as the cue sheet has invalid indexes",
+            flac.path.display()
+        );
+    }
+    let total = info.samples.unwrap_or(u64::MAX);
+    if tracks.iter().any(|track| track.range.start >= total) {
+        warn!(
+            "Not splitting {} as the cue sheet is longer than the image",
+            flac.path.display()
+        );
+        return Vec::new();
+    }
+    if let Some(first) = tracks.first()
+        && first.range.start > 0
+    {
+        warn!(
+            "Discarding {} of hidden track one audio before track 1 of {}",
+            CueTime::from_samples(first.range.start, info.sample_rate),
+            flac.path.display()
+        );
+    }
+    tracks
+}
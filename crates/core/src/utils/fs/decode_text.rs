@@ -0,0 +1,19 @@
+use chardetng::{EncodingDetector, Iso2022JpDetection, Utf8Detection};
+use encoding_rs::Encoding;
+
+/// Decode the bytes of a rip log, cue sheet or other text file of unknown encoding.
+///
+/// - EAC writes UTF-16 with a byte order mark so it is decoded by the BOM
+/// - Otherwise the encoding is detected by `chardetng`
+#[must_use]
+pub(crate) fn decode_text(bytes: &[u8]) -> String {
+    let encoding = if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        encoding
+    } else {
+        let mut detector = EncodingDetector::new(Iso2022JpDetection::Deny);
+        detector.feed(bytes, true);
+        detector.guess(None, Utf8Detection::Allow)
+    };
+    let (text, _, _) = encoding.decode(bytes);
+    text.into_owned()
+}
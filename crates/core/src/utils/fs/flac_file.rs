@@ -3,6 +3,7 @@ use claxon::Error as ClaxonError;
 use claxon::FlacReader;
 use claxon::metadata::StreamInfo;
 use lofty::id3::v2::Id3v2Tag;
+use lofty::prelude::{Accessor, ItemKey};
 use lofty::tag::Tag;
 use once_cell::sync::OnceCell;
 
@@ -31,6 +32,16 @@ pub struct FlacFile {
     ///
     /// Set once after collection
     pub disc_context: Option<DiscContext>,
+
+    /// Track of a FLAC image if the file is split by its cue sheet.
+    ///
+    /// `path` is the image so every track of the image shares it.
+    pub image_track: Option<ImageTrack>,
+
+    /// Is the track flagged with pre-emphasis?
+    ///
+    /// Set once after collection by [`resolve_pre_emphasis`].
+    pub pre_emphasis: bool,
 }
 
 impl FlacFile {
@@ -55,13 +66,53 @@ impl FlacFile {
             vorbis_tags: OnceCell::new(),
             id3_tags: OnceCell::new(),
             disc_context: None,
+            image_track: None,
+            pre_emphasis: false,
+        }
+    }
+
+    /// Create a [`FlacFile`] for a track of a FLAC image.
+    ///
+    /// The file name is taken from the track rather than the image.
+    #[must_use]
+    pub fn new_image_track(path: PathBuf, source_dir: &PathBuf, track: ImageTrack) -> Self {
+        let mut flac = Self::new(path, source_dir);
+        flac.file_name = track.get_file_name();
+        flac.image_track = Some(track);
+        flac
+    }
+
+    /// Samples of the file to decode, or `None` for the whole file.
+    #[must_use]
+    pub fn get_range(&self) -> Option<SampleRange> {
+        self.image_track.as_ref().map(|track| track.range)
+    }
+
+    /// Path that identifies the track.
+    ///
+    /// For a track of a FLAC image this is the path the track would have if the image was
+    /// split, otherwise it is the path of the file.
+    #[must_use]
+    pub fn get_track_path(&self) -> PathBuf {
+        if self.image_track.is_some() {
+            self.path.with_file_name(format!("{}.flac", self.file_name))
+        } else {
+            self.path.clone()
         }
     }
 
     /// Get cached raw Vorbis tags without any conversion.
+    ///
+    /// Tags of a track of a FLAC image are those of the image with the track number, title
+    /// and artist of the track.
     pub fn vorbis_tags(&self) -> Result<&Tag, Failure<TranscodeAction>> {
         self.vorbis_tags.get_or_try_init(|| {
-            get_vorbis_tags(self).map_err(Failure::wrap(TranscodeAction::GetTags))
+            let mut tags =
+                get_vorbis_tags(self).map_err(Failure::wrap(TranscodeAction::GetTags))?;
+            if let Some(track) = &self.image_track {
+                apply_image_track(&mut tags, track);
+            }
+            Ok(tags)
         })
     }
 
@@ -85,9 +136,33 @@ impl FlacFile {
     }
 
     /// FLAC stream info containing sample rate, channels, and bit depth.
+    ///
+    /// The number of samples of a track of a FLAC image is the length of the track.
     pub fn get_stream_info(&self) -> Result<StreamInfo, ClaxonError> {
         let reader = FlacReader::open(&self.path)?;
-        Ok(reader.streaminfo())
+        let mut info = reader.streaminfo();
+        if let Some(range) = self.get_range() {
+            info.samples = range.get_length(info.samples);
+        }
+        Ok(info)
+    }
+}
+
+/// Replace the tags of a FLAC image with those of one of its tracks.
+fn apply_image_track(tags: &mut Tag, track: &ImageTrack) {
+    tags.remove_key(ItemKey::TrackTitle);
+    tags.set_track(track.number);
+    tags.set_track_total(track.total);
+    if let Some(title) = &track.title {
+        tags.set_title(title.clone());
+    }
+    if let Some(performer) = &track.performer {
+        tags.set_artist(performer.clone());
+    }
+    if tags.album().is_none()
+        && let Some(album) = &track.album
+    {
+        tags.set_album(album.clone());
     }
 }
 
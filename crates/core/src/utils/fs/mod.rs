@@ -3,6 +3,7 @@
 pub(crate) use additional_file::*;
 pub(crate) use collector::*;
 pub(crate) use copy_dir::*;
+pub(crate) use decode_text::*;
 pub(crate) use directory_reader::*;
 pub(crate) use expand_tilde::*;
 pub(crate) use flac_file::*;
@@ -14,6 +15,7 @@ pub(crate) use tags_action::*;
 mod additional_file;
 mod collector;
 mod copy_dir;
+mod decode_text;
 mod directory_reader;
 mod expand_tilde;
 mod flac_file;
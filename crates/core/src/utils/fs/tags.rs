@@ -6,6 +6,8 @@ use lofty::error::LoftyError;
 use lofty::file::{AudioFile, TaggedFileExt};
 use lofty::flac::FlacFile as LoftyFlacFile;
 use lofty::id3::v2::{Frame, FrameId, Id3v2Tag};
+use lofty::ogg::{OggPictureStorage, VorbisComments};
+use lofty::picture::Picture;
 use lofty::prelude::TagExt;
 use lofty::probe::Probe;
 use lofty::tag::ItemKey::TrackNumber;
@@ -179,6 +181,7 @@ pub(crate) fn vorbis_keys(names: &[String]) -> Vec<ItemKey> {
 /// - Uses the native [`VorbisComments`](lofty::ogg::VorbisComments) type
 /// - Preserves all Vorbis comment keys including those without an [`ItemKey`] mapping
 ///   (e.g. `SYNCEDLYRICS`, `DISCOGS_*`)
+/// - Embedded pictures are kept
 /// - Only writes the file if at least one tag was actually removed
 pub(crate) fn exclude_vorbis_comments_from_flac(
     path: &Path,
@@ -202,25 +205,188 @@ pub(crate) fn exclude_vorbis_comments_from_flac(
     if !removed_any {
         return Ok(());
     }
-    vorbis
-        .save_to_path(path, WriteOptions::default())
+    drop(file);
+    // Saved through the FLAC file so its PICTURE blocks are written back
+    flac.save_to_path(path, WriteOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::WriteTags, path))?;
+    Ok(())
+}
+
+/// Insert Vorbis comments into a FLAC file on disk.
+///
+/// - Existing comments with the same name are replaced
+/// - Embedded pictures are kept
+/// - Does nothing if there are no comments to insert
+pub(crate) fn insert_vorbis_comments_into_flac(
+    path: &Path,
+    comments: Vec<(&str, String)>,
+) -> Result<(), Failure<TagsAction>> {
+    if comments.is_empty() {
+        return Ok(());
+    }
+    let mut file = File::open(path).map_err(Failure::wrap_with_path(TagsAction::OpenFile, path))?;
+    let mut flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::ReadTags, path))?;
+    drop(file);
+    let mut vorbis = flac.vorbis_comments_mut().cloned().unwrap_or_default();
+    for (key, value) in comments {
+        vorbis.insert(key.to_owned(), value);
+    }
+    // Saved through the FLAC file so its PICTURE blocks are written back
+    flac.set_vorbis_comments(vorbis);
+    flac.save_to_path(path, WriteOptions::default())
         .map_err(Failure::wrap_with_path(TagsAction::WriteTags, path))?;
     Ok(())
 }
 
+/// Replace the embedded pictures of a FLAC file on disk.
+///
+/// An empty Vorbis comment block is added if there is none so existing pictures are removed.
+pub(crate) fn replace_flac_pictures(
+    path: &Path,
+    pictures: Vec<Picture>,
+) -> Result<(), Failure<TagsAction>> {
+    let mut file = File::open(path).map_err(Failure::wrap_with_path(TagsAction::OpenFile, path))?;
+    let mut flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::ReadTags, path))?;
+    drop(file);
+    if flac.vorbis_comments().is_none() {
+        flac.set_vorbis_comments(VorbisComments::default());
+    }
+    flac.remove_pictures();
+    for picture in pictures {
+        flac.insert_picture(picture, None)
+            .map_err(Failure::wrap_with_path(TagsAction::WriteTags, path))?;
+    }
+    flac.save_to_path(path, WriteOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::WriteTags, path))?;
+    Ok(())
+}
+
+/// Replace the `APIC` frames of an [`Id3v2Tag`] with `pictures`.
+pub(crate) fn replace_id3v2_pictures(tags: &mut Id3v2Tag, pictures: Vec<Picture>) {
+    tags.retain(|frame| !matches!(frame, Frame::Picture(_)));
+    for picture in pictures {
+        tags.insert_picture(picture);
+    }
+}
+
+/// Read the first value of a Vorbis comment from a FLAC file on disk.
+///
+/// - Uses the native [`VorbisComments`](lofty::ogg::VorbisComments) type so keys without an
+///   [`ItemKey`] mapping such as `PRE_EMPHASIS` can be read
+/// - Returns `None` if the FLAC has no Vorbis comments or no comment named `key`
+pub(crate) fn get_vorbis_comment(
+    path: &Path,
+    key: &str,
+) -> Result<Option<String>, Failure<TagsAction>> {
+    let mut file = File::open(path).map_err(Failure::wrap_with_path(TagsAction::OpenFile, path))?;
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::ReadTags, path))?;
+    Ok(flac
+        .vorbis_comments()
+        .and_then(|vorbis| vorbis.get(key))
+        .map(str::to_owned))
+}
+
+/// Get every Vorbis comment of a FLAC file on disk as key-value pairs.
+///
+/// - Uses the native [`VorbisComments`](lofty::ogg::VorbisComments) type so all values of a
+///   key are kept, in order
+/// - Returns an empty list if the file has no Vorbis comments
+pub(crate) fn get_all_vorbis_comments(
+    path: &Path,
+) -> Result<Vec<(String, String)>, Failure<TagsAction>> {
+    let mut file = File::open(path).map_err(Failure::wrap_with_path(TagsAction::OpenFile, path))?;
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::ReadTags, path))?;
+    Ok(flac
+        .vorbis_comments()
+        .map(|vorbis| {
+            vorbis
+                .items()
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Copy the Vorbis comments of one FLAC file to another on disk.
+///
+/// - Uses the native [`VorbisComments`](lofty::ogg::VorbisComments) type so all keys are kept
+/// - Does nothing if the source has no Vorbis comments
+pub(crate) fn copy_vorbis_comments(
+    source: &Path,
+    target: &Path,
+) -> Result<(), Failure<TagsAction>> {
+    let mut file =
+        File::open(source).map_err(Failure::wrap_with_path(TagsAction::OpenFile, source))?;
+    let flac = LoftyFlacFile::read_from(&mut file, ParseOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::ReadTags, source))?;
+    let Some(vorbis) = flac.vorbis_comments() else {
+        return Ok(());
+    };
+    vorbis
+        .save_to_path(target, WriteOptions::default())
+        .map_err(Failure::wrap_with_path(TagsAction::WriteTags, target))?;
+    Ok(())
+}
+
 /// Convert a generic [`Tag`] to [`Id3v2Tag`] and save with deterministic frame ordering.
 ///
 /// lofty 0.23's `Tag` to `Id3v2Tag` conversion collects frames into `HashSet`/`HashMap`,
 /// producing correct frames in non-deterministic order. Sorting by frame ID before writing
 /// ensures stable binary output.
-pub(crate) fn save_id3v2_deterministic(id3: Id3v2Tag, path: &Path) -> Result<(), LoftyError> {
+///
+/// Tags are written as `ID3v2.3` or `ID3v2.4` according to `version`.
+pub(crate) fn save_id3v2_deterministic(
+    id3: Id3v2Tag,
+    path: &Path,
+    version: Id3Version,
+) -> Result<(), LoftyError> {
     let mut frames: Vec<Frame<'static>> = id3.into_iter().collect();
     frames.sort_by_key(frame_sort_key);
     let mut sorted = Id3v2Tag::new();
     for frame in frames {
         sorted.insert(frame);
     }
-    sorted.save_to_path(path, WriteOptions::default())
+    sorted.save_to_path(path, version.get_write_options())
+}
+
+/// Get the sorted keys of frames in `expected` that are missing from `actual`.
+///
+/// - Frames are matched by the same key used to order frames in [`save_id3v2_deterministic`]
+/// - Text frames must also have the same value, other frames only need to be present
+pub(crate) fn get_missing_id3v2_frames(expected: &Id3v2Tag, actual: &Id3v2Tag) -> Vec<String> {
+    let actual: BTreeMap<String, &Frame<'_>> = actual
+        .into_iter()
+        .map(|frame| (frame_sort_key(frame), frame))
+        .collect();
+    expected
+        .into_iter()
+        .filter_map(|frame| {
+            let key = frame_sort_key(frame);
+            let is_match = match (frame, actual.get(&key)) {
+                (Frame::Text(expected), Some(Frame::Text(actual))) => {
+                    expected.value == actual.value
+                }
+                (Frame::UserText(expected), Some(Frame::UserText(actual))) => {
+                    expected.content == actual.content
+                }
+                (_, found) => found.is_some(),
+            };
+            (!is_match).then(|| key.replace('\0', ":"))
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Get a readable key of an `ID3v2` frame such as `TPE1` or `TXXX:CATALOGNUMBER`.
+///
+/// Keys are unique within a tag, matching the order of [`save_id3v2_deterministic`].
+pub(crate) fn get_id3v2_frame_key(frame: &Frame<'_>) -> String {
+    frame_sort_key(frame).replace('\0', ":")
 }
 
 /// Deterministic sort key for an `ID3v2` frame.
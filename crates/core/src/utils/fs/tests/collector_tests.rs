@@ -1,4 +1,5 @@
 use crate::testing_prelude::*;
+use lofty::prelude::Accessor;
 
 #[test]
 fn collector_collect_flacs_missing_directory() {
@@ -30,3 +31,70 @@ fn collector_collect_flacs_empty_directory() {
         })
     );
 }
+
+#[test]
+fn collector_get_tracks_splits_image() {
+    // Arrange
+    let dir = TempDirectory::create("collector_get_tracks_splits_image");
+    let path = write_image(&dir);
+    insert_vorbis_comments_into_flac(
+        &path,
+        vec![
+            ("ALBUM", "Album".to_owned()),
+            ("ARTIST", "Artist".to_owned()),
+            ("TITLE", "Album".to_owned()),
+        ],
+    )
+    .expect("should write tags");
+    write(
+        dir.join("Album.cue"),
+        "FILE \"Album.wav\" WAVE\n  TRACK 01 AUDIO\n    TITLE \"First\"\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    TITLE \"Second\"\n    PERFORMER \"Guest\"\n    INDEX 01 00:00:10\n",
+    )
+    .expect("should write cue");
+
+    // Act
+    let tracks = Collector::get_tracks(&dir.to_path_buf());
+
+    // Assert
+    let names: Vec<&str> = tracks.iter().map(|flac| flac.file_name.as_str()).collect();
+    assert_eq!(names, vec!["01 First", "02 Second"]);
+    assert!(tracks.iter().all(|flac| flac.path == path));
+    let second = tracks.get(1).expect("should have track 2");
+    let tags = second.id3_tags().expect("should read tags");
+    assert_eq!(tags.track(), Some(2));
+    assert_eq!(tags.title().as_deref(), Some("Second"));
+    assert_eq!(tags.artist().as_deref(), Some("Guest"));
+    assert_eq!(tags.album().as_deref(), Some("Album"));
+    let info = second.get_stream_info().expect("should read stream info");
+    assert_eq!(info.samples, Some(40 * 588 - 10 * 588));
+    assert_eq!(second.get_track_path(), dir.join("02 Second.flac"));
+}
+
+#[test]
+fn collector_get_tracks_ignores_unmatched_cue() {
+    // Arrange
+    let dir = TempDirectory::create("collector_get_tracks_ignores_unmatched_cue");
+    let path = write_image(&dir);
+    write(
+        dir.join("Other.cue"),
+        "FILE \"Other.wav\" WAVE\n  TRACK 01 AUDIO\n    INDEX 01 00:00:00\n  TRACK 02 AUDIO\n    INDEX 01 00:00:10\n",
+    )
+    .expect("should write cue");
+
+    // Act
+    let tracks = Collector::get_tracks(&dir.to_path_buf());
+
+    // Assert
+    assert_eq!(tracks.len(), 1);
+    let track = tracks.first().expect("should have the image");
+    assert_eq!(track.path, path);
+    assert!(track.image_track.is_none());
+}
+
+/// Encode a 16-bit 44.1 kHz FLAC image of 40 cue sheet frames.
+fn write_image(dir: &Path) -> PathBuf {
+    PcmFlacGenerator::new()
+        .with_filename("Album.flac")
+        .with_samples(vec![0; 40 * 588 * 2])
+        .generate(dir)
+}
@@ -0,0 +1,31 @@
+use crate::testing_prelude::*;
+
+/// Cue sheet with non-ASCII text.
+const CUE_SHEET: &str = "PERFORMER \"Björk\"\nTITLE \"Homogenic\"\nFILE \"01 - Hunter.wav\" WAVE\n";
+
+#[test]
+fn decode_text_utf16() {
+    // Arrange
+    let mut bytes = vec![0xFF, 0xFE];
+    for unit in CUE_SHEET.encode_utf16() {
+        bytes.extend_from_slice(&unit.to_le_bytes());
+    }
+
+    // Act
+    let text = decode_text(&bytes);
+
+    // Assert
+    assert_eq!(text, CUE_SHEET);
+}
+
+#[test]
+fn decode_text_windows_1252() {
+    // Arrange
+    let bytes = b"Exact Audio Copy V1.6\n\nBj\xF6rk / Homogenic\n";
+
+    // Act
+    let text = decode_text(bytes);
+
+    // Assert
+    assert_eq!(text, "Exact Audio Copy V1.6\n\nBjörk / Homogenic\n");
+}
@@ -1,4 +1,5 @@
 mod collector_tests;
 mod copy_dir_tests;
+mod decode_text_tests;
 mod path_manager_tests;
 mod tags_tests;
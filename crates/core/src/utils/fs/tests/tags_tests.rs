@@ -1,4 +1,6 @@
 use crate::testing_prelude::*;
+use lofty::id3::v2::Id3v2Tag;
+use lofty::tag::Accessor;
 
 #[test]
 fn get_numeric_from_slash_total_format_valid() {
@@ -57,6 +59,54 @@ fn get_numeric_from_of_total_format_invalid() {
     assert_eq!(get_numeric_from_of_total_format("of 10"), None);
 }
 
+#[test]
+fn get_missing_id3v2_frames_identical() {
+    // Arrange
+    let mut expected = Id3v2Tag::new();
+    expected.set_title("Title".to_owned());
+    expected.set_artist("Artist".to_owned());
+    let actual = expected.clone();
+
+    // Act
+    let missing = get_missing_id3v2_frames(&expected, &actual);
+
+    // Assert
+    assert!(missing.is_empty());
+}
+
+#[test]
+fn get_missing_id3v2_frames_missing_and_altered() {
+    // Arrange
+    let mut expected = Id3v2Tag::new();
+    expected.set_title("Title".to_owned());
+    expected.set_artist("Artist".to_owned());
+    expected.set_album("Album".to_owned());
+    let mut actual = Id3v2Tag::new();
+    actual.set_title("Title".to_owned());
+    actual.set_artist("Other".to_owned());
+
+    // Act
+    let missing = get_missing_id3v2_frames(&expected, &actual);
+
+    // Assert
+    assert_eq!(missing, vec!["TALB".to_owned(), "TPE1".to_owned()]);
+}
+
+#[test]
+fn get_missing_id3v2_frames_ignores_extra_frames() {
+    // Arrange
+    let mut expected = Id3v2Tag::new();
+    expected.set_title("Title".to_owned());
+    let mut actual = expected.clone();
+    actual.set_genre("Genre".to_owned());
+
+    // Act
+    let missing = get_missing_id3v2_frames(&expected, &actual);
+
+    // Assert
+    assert!(missing.is_empty());
+}
+
 /// Regression test for lofty 0.23.3 bug where `save_to_path` left stale bytes
 /// when the re-encoded metadata was smaller than the original.
 ///
@@ -1,7 +1,10 @@
 //! Core utilities for error handling, file operations, naming, and format conversion.
 
 pub(crate) use app_info::*;
+pub(crate) use audio::*;
+pub(crate) use checksum_manifest::*;
 pub(crate) use cross::*;
+pub(crate) use cue_sheet::*;
 pub(crate) use diagnostic_ext::*;
 pub(crate) use diff::*;
 pub(crate) use formats::*;
@@ -15,6 +18,7 @@ pub(crate) use naming::*;
 pub(crate) use platform::*;
 pub(crate) use process::*;
 pub(crate) use report::*;
+pub(crate) use rip_log::*;
 pub(crate) use source::*;
 pub(crate) use table::*;
 #[cfg(test)]
@@ -22,7 +26,10 @@ pub(crate) use testing::*;
 pub(crate) use torrent::*;
 
 mod app_info;
+mod audio;
+mod checksum_manifest;
 mod cross;
+mod cue_sheet;
 mod diagnostic_ext;
 mod diff;
 mod formats;
@@ -36,6 +43,7 @@ mod naming;
 mod platform;
 mod process;
 mod report;
+mod rip_log;
 mod source;
 mod table;
 #[cfg(test)]
@@ -0,0 +1,95 @@
+use similar::TextDiff;
+use unicode_normalization::UnicodeNormalization;
+
+/// Longest HTML entity that is decoded, excluding `&` and `;`.
+const MAX_ENTITY_LENGTH: usize = 8;
+
+/// Decode the HTML entities that indexers return in names, such as `&amp;` and `&#39;`.
+///
+/// Unknown or malformed entities are left as is.
+#[must_use]
+pub(crate) fn decode_html_entities(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        let (before, after) = rest.split_at(start);
+        output.push_str(before);
+        let decoded = after
+            .get(1..)
+            .and_then(|entity| entity.split_once(';'))
+            .filter(|(entity, _)| entity.len() <= MAX_ENTITY_LENGTH)
+            .and_then(|(entity, remainder)| Some((decode_entity(entity)?, remainder)));
+        if let Some((char, remainder)) = decoded {
+            output.push(char);
+            rest = remainder;
+        } else {
+            output.push('&');
+            rest = after.get(1..).unwrap_or_default();
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let number = entity.strip_prefix('#')?;
+            let code = if let Some(hex) = number
+                .strip_prefix('x')
+                .or_else(|| number.strip_prefix('X'))
+            {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                number.parse().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Normalize a name for fuzzy matching.
+///
+/// - Decodes HTML entities
+/// - Applies Unicode NFC normalization and lowercases
+/// - Replaces `&` and `+` with `and`
+/// - Removes punctuation and collapses whitespace
+#[must_use]
+pub(crate) fn normalize_name(text: &str) -> String {
+    let text: String = decode_html_entities(text)
+        .nfc()
+        .collect::<String>()
+        .to_lowercase();
+    let text = text.replace(['&', '+'], " and ");
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|word| word.chars().filter(|char| char.is_alphanumeric()).collect())
+        .filter(|word: &String| !word.is_empty())
+        .collect();
+    words.join(" ")
+}
+
+/// Similarity of two names after [`normalize_name`], from `0.0` to `1.0`.
+#[must_use]
+pub(crate) fn get_name_similarity(a: &str, b: &str) -> f64 {
+    let a = normalize_name(a);
+    let b = normalize_name(b);
+    if a == b {
+        return 1.0;
+    }
+    f64::from(TextDiff::from_chars(a.as_str(), b.as_str()).ratio())
+}
+
+/// Check if `name` contains every word of `part` in order after [`normalize_name`].
+#[must_use]
+pub(crate) fn contains_name(name: &str, part: &str) -> bool {
+    let name = format!(" {} ", normalize_name(name));
+    let part = normalize_name(part);
+    !part.is_empty() && name.contains(&format!(" {part} "))
+}
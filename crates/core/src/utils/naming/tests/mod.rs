@@ -1,2 +1,3 @@
 mod humanize_tests;
+mod name_match_tests;
 mod sanitizer_tests;
@@ -0,0 +1,53 @@
+use crate::testing_prelude::*;
+
+#[test]
+fn decode_html_entities_named_and_numeric() {
+    // Act
+    let output =
+        decode_html_entities("Simon &amp; Garfunkel &#39;Live&#x27; &quot;1982&quot; AT&T &bogus;");
+
+    // Assert
+    assert_eq!(output, "Simon & Garfunkel 'Live' \"1982\" AT&T &bogus;");
+}
+
+#[test]
+fn normalize_name_punctuation_and_case() {
+    // Act
+    let output = normalize_name("  Simon &amp; Garfunkel: The Concert   in Central Park!  ");
+
+    // Assert
+    assert_eq!(output, "simon and garfunkel the concert in central park");
+}
+
+#[test]
+fn normalize_name_nfc() {
+    // Arrange
+    let composed = "Beyonc\u{e9}";
+    let decomposed = "Beyonce\u{301}";
+
+    // Act
+    let similarity = get_name_similarity(composed, decomposed);
+
+    // Assert
+    assert!((similarity - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn get_name_similarity_typo() {
+    // Act
+    let similar = get_name_similarity("The Dark Side of the Moon", "Dark Side of The Moon");
+    let different = get_name_similarity("The Dark Side of the Moon", "Wish You Were Here");
+
+    // Assert
+    assert!(similar > 0.8, "similar: {similar}");
+    assert!(different < 0.5, "different: {different}");
+}
+
+#[test]
+fn contains_name_whole_words() {
+    // Assert
+    assert!(contains_name("Artist feat. Guest", "Artist"));
+    assert!(contains_name("A &amp; B", "B"));
+    assert!(!contains_name("Artistry", "Artist"));
+    assert!(!contains_name("Artist", ""));
+}
@@ -24,6 +24,44 @@ async fn report_renderer_render_single_no_tags_issue() {
     assert_snapshot!(output);
 }
 
+#[tokio::test]
+async fn report_renderer_render_log_issues() {
+    // Arrange
+    let mut builder = HostBuilder::new();
+    let _ = builder.with_options(SharedOptions {
+        indexer_url: "https://example.com".to_owned(),
+        ..SharedOptions::mock()
+    });
+    let host = builder.expect_build();
+    let renderer = host.services.get_required::<ReportRenderer>();
+    let source = Source::mock();
+    let path = PathBuf::from("/content/Artist - Album [2024]/Artist - Album.log");
+    let issues = vec![
+        SourceIssue::LogScore {
+            path: path.clone(),
+            score: 50,
+            deductions: vec![
+                "CRC mismatch on 1 track".to_owned(),
+                "Read errors on 1 track".to_owned(),
+            ],
+        },
+        SourceIssue::CrcMismatch {
+            path,
+            track: 2,
+            test: "11111111".to_owned(),
+            copy: "22222222".to_owned(),
+        },
+    ];
+
+    // Act
+    let output = renderer
+        .render(&source, &issues)
+        .expect("render should succeed");
+
+    // Assert
+    assert_snapshot!(output);
+}
+
 #[tokio::test]
 async fn report_renderer_render_multiple_issue_types() {
     // Arrange
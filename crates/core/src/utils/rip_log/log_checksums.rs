@@ -0,0 +1,135 @@
+use crate::prelude::*;
+use lofty::tag::ItemKey;
+
+/// Checksums of a track in a rip log that the matching FLAC is expected to have.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LogChecksums {
+    /// Path of the rip log.
+    pub log: PathBuf,
+    /// Track number in the rip log.
+    pub track: u32,
+    /// Is this the first track of the disc?
+    pub is_first: bool,
+    /// Is this the last track of the disc?
+    pub is_last: bool,
+    /// CRC32 of the copy pass.
+    pub crc32: Option<u32>,
+    /// Were null samples included in the CRC32?
+    pub null_samples: bool,
+    /// `AccurateRip` v1 checksum.
+    pub accurate_rip_v1: Option<u32>,
+    /// `AccurateRip` v2 checksum.
+    pub accurate_rip_v2: Option<u32>,
+}
+
+impl LogChecksums {
+    /// Match each track of a rip log to a FLAC in the directory of the log.
+    ///
+    /// - A FLAC matches if its file name is the same as the track file name in the log
+    /// - Otherwise a FLAC matches if it is the only one with the track number
+    /// - Range rips and tracks without a match are skipped
+    #[must_use]
+    pub(crate) fn from_log(
+        path: &Path,
+        log: &RipLog,
+        flacs: &[FlacFile],
+    ) -> Vec<(PathBuf, LogChecksums)> {
+        if log.is_range_rip() {
+            trace!("Skipping checksums of range rip {}", path.display());
+            return Vec::new();
+        }
+        let dir = path.parent().unwrap_or(path);
+        let candidates: Vec<&FlacFile> = flacs
+            .iter()
+            .filter(|flac| flac.path.starts_with(dir))
+            .collect();
+        let first = log.tracks.iter().map(|track| track.number).min();
+        let last = log.tracks.iter().map(|track| track.number).max();
+        let mut output = Vec::new();
+        for track in &log.tracks {
+            if track.copy_crc.is_none()
+                && track.accurate_rip_v1.is_none()
+                && track.accurate_rip_v2.is_none()
+            {
+                continue;
+            }
+            let Some(flac) = find_flac(&candidates, track) else {
+                trace!(
+                    "Skipping checksums of track {} in {}: no matching FLAC",
+                    track.number,
+                    path.display()
+                );
+                continue;
+            };
+            let checksums = LogChecksums {
+                log: path.to_path_buf(),
+                track: track.number,
+                is_first: Some(track.number) == first,
+                is_last: Some(track.number) == last,
+                crc32: track.copy_crc,
+                null_samples: log.ripper == Ripper::Xld || log.null_samples != Some(false),
+                accurate_rip_v1: track.accurate_rip_v1,
+                accurate_rip_v2: track.accurate_rip_v2,
+            };
+            output.push((flac.path.clone(), checksums));
+        }
+        output
+    }
+
+    /// Compare with the checksums calculated from a FLAC.
+    ///
+    /// Returns a [`SourceIssue::ChecksumMismatch`] for the first checksum that differs.
+    #[must_use]
+    pub(crate) fn compare(&self, path: &Path, actual: &RipChecksums) -> Option<SourceIssue> {
+        let crc32 = if self.null_samples {
+            actual.crc32
+        } else {
+            actual.crc32_skip_zero
+        };
+        let checks = [
+            ("CRC32", self.crc32, crc32),
+            (
+                "AccurateRip v1",
+                self.accurate_rip_v1,
+                actual.accurate_rip_v1,
+            ),
+            (
+                "AccurateRip v2",
+                self.accurate_rip_v2,
+                actual.accurate_rip_v2,
+            ),
+        ];
+        let (checksum, expected, actual) = checks
+            .into_iter()
+            .find(|(_, expected, actual)| expected.is_some_and(|expected| expected != *actual))?;
+        Some(SourceIssue::ChecksumMismatch {
+            path: path.to_path_buf(),
+            log: self.log.clone(),
+            checksum: checksum.to_owned(),
+            expected: format!("{:08X}", expected.unwrap_or_default()),
+            actual: format!("{actual:08X}"),
+        })
+    }
+}
+
+fn find_flac<'a>(candidates: &[&'a FlacFile], track: &RipLogTrack) -> Option<&'a FlacFile> {
+    if let Some(filename) = &track.filename
+        && let Some(flac) = candidates.iter().find(|flac| &flac.file_name == filename)
+    {
+        return Some(flac);
+    }
+    let mut numbered = candidates
+        .iter()
+        .filter(|flac| get_track_number(flac) == Some(track.number));
+    let flac = numbered.next()?;
+    if numbered.next().is_some() {
+        return None;
+    }
+    Some(flac)
+}
+
+fn get_track_number(flac: &FlacFile) -> Option<u32> {
+    let tags = flac.vorbis_tags().ok()?;
+    let track = tags.get_string(ItemKey::TrackNumber)?;
+    track.split('/').next()?.trim().parse().ok()
+}
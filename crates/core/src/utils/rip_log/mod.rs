@@ -0,0 +1,11 @@
+//! Parse and score EAC and XLD rip logs.
+
+pub(crate) use log_checksums::*;
+pub(crate) use rip_log::*;
+pub(crate) use rip_log_score::*;
+
+mod log_checksums;
+mod rip_log;
+mod rip_log_score;
+#[cfg(test)]
+mod tests;
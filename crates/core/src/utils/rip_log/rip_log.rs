@@ -0,0 +1,321 @@
+use crate::prelude::*;
+use std::fs::read as read_bytes;
+
+/// Heading of each track section in EAC and XLD logs.
+static TRACK_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^Track\s+(\d+)$").expect("regex should compile"));
+
+/// `AccurateRip` checksum of a track in EAC logs.
+static ACCURATE_RIP_CRC_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([0-9A-Fa-f]{8})\]").expect("regex should compile"));
+
+/// Confidence of an accurate rip in EAC and XLD logs.
+static CONFIDENCE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"confidence (\d+)").expect("regex should compile"));
+
+/// Application that produced a rip log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Ripper {
+    /// Exact Audio Copy.
+    Eac,
+    /// X Lossless Decoder.
+    Xld,
+}
+
+/// `AccurateRip` result of a track.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum AccurateRipStatus {
+    /// Matched the `AccurateRip` database with the number of matching submissions.
+    Accurate { confidence: u32 },
+    /// Present in the `AccurateRip` database but did not match.
+    Inaccurate,
+    /// Not present in the `AccurateRip` database.
+    NotPresent,
+}
+
+/// Results of a single track in a rip log.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct RipLogTrack {
+    /// Track number, or `0` for a range rip.
+    pub number: u32,
+    /// File name of the track without the directory or extension.
+    pub filename: Option<String>,
+    /// CRC32 of the test pass.
+    pub test_crc: Option<u32>,
+    /// CRC32 of the copy pass.
+    pub copy_crc: Option<u32>,
+    /// `AccurateRip` result.
+    pub accurate_rip: Option<AccurateRipStatus>,
+    /// `AccurateRip` v1 checksum.
+    pub accurate_rip_v1: Option<u32>,
+    /// `AccurateRip` v2 checksum.
+    pub accurate_rip_v2: Option<u32>,
+    /// Suspicious positions, read errors and damaged sectors.
+    pub errors: u32,
+}
+
+impl RipLogTrack {
+    /// Check if both passes were read and their CRCs differ.
+    #[must_use]
+    pub(crate) fn is_crc_mismatch(&self) -> bool {
+        matches!((self.test_crc, self.copy_crc), (Some(test), Some(copy)) if test != copy)
+    }
+}
+
+/// Settings and results extracted from an EAC or XLD rip log.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct RipLog {
+    /// Application that produced the log.
+    pub ripper: Ripper,
+    /// Model of the drive.
+    pub drive: Option<String>,
+    /// Read offset correction in samples.
+    pub read_offset: Option<i32>,
+    /// Read mode of EAC or ripper mode of XLD.
+    pub read_mode: Option<String>,
+    /// Was the drive audio cache defeated?
+    pub defeat_audio_cache: Option<bool>,
+    /// Were C2 error pointers used?
+    pub c2_pointers: Option<bool>,
+    /// Gap handling of EAC or gap status of XLD.
+    pub gap_handling: Option<String>,
+    /// Were null samples included in the CRCs?
+    pub null_samples: Option<bool>,
+    /// Results of each track.
+    pub tracks: Vec<RipLogTrack>,
+    /// Log checksum of EAC or signature of XLD.
+    pub checksum: Option<String>,
+}
+
+impl RipLog {
+    /// Read and parse a rip log.
+    ///
+    /// Returns `None` if the file is not an EAC or XLD log.
+    pub(crate) fn read(path: &Path) -> Result<Option<Self>, IoError> {
+        let bytes = read_bytes(path)?;
+        Ok(Self::parse(&decode_text(&bytes)))
+    }
+
+    /// Find the `.log` files in a directory, sorted by path.
+    pub(crate) fn find(dir: &Path) -> Result<Vec<PathBuf>, IoError> {
+        let mut paths = DirectoryReader::new().with_extension("log").read(dir)?;
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Parse the text of a rip log.
+    ///
+    /// Returns `None` if the text is not an EAC or XLD log.
+    #[must_use]
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let ripper = get_ripper(text)?;
+        let mut log = Self {
+            ripper,
+            drive: None,
+            read_offset: None,
+            read_mode: None,
+            defeat_audio_cache: None,
+            c2_pointers: None,
+            gap_handling: None,
+            null_samples: None,
+            tracks: Vec::new(),
+            checksum: None,
+        };
+        let mut lines = text.lines().map(str::trim);
+        while let Some(line) = lines.next() {
+            if let Some(number) = TRACK_PATTERN
+                .captures(line)
+                .and_then(|captures| captures.get(1))
+                .and_then(|number| number.as_str().parse().ok())
+            {
+                log.tracks.push(RipLogTrack {
+                    number,
+                    ..RipLogTrack::default()
+                });
+            } else if line == "-----BEGIN XLD SIGNATURE-----" {
+                log.checksum = lines.next().map(str::to_owned);
+            } else if let Some(checksum) = line
+                .strip_prefix("==== Log checksum")
+                .and_then(|rest| rest.strip_suffix("===="))
+            {
+                log.checksum = Some(checksum.trim().to_owned());
+            } else {
+                log.parse_line(line);
+            }
+        }
+        Some(log)
+    }
+
+    fn parse_line(&mut self, line: &str) {
+        if let Some(crc) = line.strip_prefix("Test CRC") {
+            self.get_track().test_crc = parse_crc(crc);
+        } else if let Some(crc) = line.strip_prefix("Copy CRC") {
+            self.get_track().copy_crc = parse_crc(crc);
+        } else if let Some(filename) = line.strip_prefix("Filename") {
+            if let Some(track) = self.tracks.last_mut() {
+                track.filename = parse_filename(filename);
+            }
+        } else if let Some(status) = parse_accurate_rip(line) {
+            if let Some(track) = self.tracks.last_mut()
+                && let Some(crc) = ACCURATE_RIP_CRC_PATTERN
+                    .captures(line)
+                    .and_then(|captures| captures.get(1))
+                    .and_then(|crc| parse_crc(crc.as_str()))
+            {
+                if line.contains("(AR v2)") {
+                    track.accurate_rip_v2 = Some(crc);
+                } else {
+                    track.accurate_rip_v1 = Some(crc);
+                }
+            }
+            // XLD reports v1 and v2 separately so a match of either is kept
+            if let Some(track) = self.tracks.last_mut()
+                && !matches!(track.accurate_rip, Some(AccurateRipStatus::Accurate { .. }))
+            {
+                track.accurate_rip = Some(status);
+            }
+        } else if line.starts_with("Suspicious position") {
+            self.get_track().errors += 1;
+        } else if let Some((key, value)) = line.split_once(':') {
+            self.parse_setting(key.trim(), value.trim());
+        }
+    }
+
+    fn parse_setting(&mut self, key: &str, value: &str) {
+        match key {
+            "Used drive" => {
+                let drive = value.split("Adapter:").next().unwrap_or(value);
+                self.drive = Some(drive.trim().to_owned());
+            }
+            "Read mode" | "Ripper mode" => {
+                if value.contains("disable cache") {
+                    self.defeat_audio_cache = Some(true);
+                }
+                if value.contains("NO C2") {
+                    self.c2_pointers = Some(false);
+                }
+                self.read_mode = Some(value.to_owned());
+            }
+            "Defeat audio cache" | "Disable audio cache" => {
+                self.defeat_audio_cache = Some(is_enabled(value));
+            }
+            "Make use of C2 pointers" => self.c2_pointers = Some(is_enabled(value)),
+            "Read offset correction" | "Combined read/write offset correction" => {
+                self.read_offset = value.parse().ok();
+            }
+            "Gap handling" | "Gap status" => self.gap_handling = Some(value.to_owned()),
+            "Null samples used in CRC calculations" => self.null_samples = Some(is_enabled(value)),
+            "CRC32 hash (test run)" => self.get_track().test_crc = parse_crc(value),
+            "CRC32 hash" => self.get_track().copy_crc = parse_crc(value),
+            "AccurateRip v1 signature" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.accurate_rip_v1 = parse_crc(value);
+                }
+            }
+            "AccurateRip v2 signature" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.accurate_rip_v2 = parse_crc(value);
+                }
+            }
+            "Read error"
+            | "Skipped (treated as error)"
+            | "Inconsistency in error sectors"
+            | "Damaged sector count" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.errors += value.parse::<u32>().unwrap_or_default();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the current track, starting a range rip if there is none.
+    fn get_track(&mut self) -> &mut RipLogTrack {
+        if self.tracks.is_empty() {
+            self.tracks.push(RipLogTrack::default());
+        }
+        self.tracks.last_mut().expect("tracks should not be empty")
+    }
+
+    /// Check if the log is of a single range rather than individual tracks.
+    #[must_use]
+    pub(crate) fn is_range_rip(&self) -> bool {
+        self.tracks.iter().any(|track| track.number == 0)
+    }
+
+    /// Check if the read mode is secure.
+    ///
+    /// - EAC must be in `Secure` mode
+    /// - XLD must use the `XLD Secure Ripper` or `CDParanoia` in paranoia mode
+    #[must_use]
+    pub(crate) fn is_secure(&self) -> bool {
+        let Some(mode) = &self.read_mode else {
+            return false;
+        };
+        match self.ripper {
+            Ripper::Eac => mode.starts_with("Secure"),
+            Ripper::Xld => mode.contains("Secure") || mode.contains("Paranoia mode"),
+        }
+    }
+
+    /// Check if gaps were detected rather than assumed.
+    #[must_use]
+    pub(crate) fn is_gap_detected(&self) -> bool {
+        self.gap_handling
+            .as_ref()
+            .is_some_and(|gaps| !gaps.starts_with("Not"))
+    }
+}
+
+fn get_ripper(text: &str) -> Option<Ripper> {
+    let header = text.lines().map(str::trim).find(|line| !line.is_empty())?;
+    if header.starts_with("Exact Audio Copy") || header.starts_with("EAC extraction logfile") {
+        Some(Ripper::Eac)
+    } else if header.starts_with("X Lossless Decoder")
+        || header.starts_with("XLD extraction logfile")
+    {
+        Some(Ripper::Xld)
+    } else {
+        None
+    }
+}
+
+fn parse_crc(value: &str) -> Option<u32> {
+    u32::from_str_radix(value.trim(), 16).ok()
+}
+
+/// Get the file stem of a path on Windows or Unix.
+fn parse_filename(value: &str) -> Option<String> {
+    let path = value.trim().trim_start_matches(':').trim();
+    let name = path.rsplit(['\\', '/']).next()?;
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    if stem.is_empty() {
+        None
+    } else {
+        Some(stem.to_owned())
+    }
+}
+
+fn parse_accurate_rip(line: &str) -> Option<AccurateRipStatus> {
+    let line = line.trim_start_matches("->");
+    if line.starts_with("Accurately ripped") {
+        let confidence = CONFIDENCE_PATTERN
+            .captures(line)
+            .and_then(|captures| captures.get(1))
+            .and_then(|confidence| confidence.as_str().parse().ok())
+            .unwrap_or_default();
+        Some(AccurateRipStatus::Accurate { confidence })
+    } else if line.starts_with("Cannot be verified as accurate")
+        || line.starts_with("Rip may not be accurate")
+    {
+        Some(AccurateRipStatus::Inaccurate)
+    } else if line.starts_with("Track not present in AccurateRip database") {
+        Some(AccurateRipStatus::NotPresent)
+    } else {
+        None
+    }
+}
+
+fn is_enabled(value: &str) -> bool {
+    matches!(value.to_lowercase().as_str(), "yes" | "ok")
+}
@@ -0,0 +1,142 @@
+use crate::prelude::*;
+
+/// Score of a rip log without deductions.
+pub(crate) const MAX_LOG_SCORE: u32 = 100;
+
+/// Reason a rip log scores less than [`MAX_LOG_SCORE`].
+///
+/// Weights follow the log checker of the tracker.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum LogDeduction {
+    /// Ripped in burst or another insecure mode.
+    NotSecure,
+    /// The drive audio cache was not defeated.
+    AudioCacheNotDefeated,
+    /// C2 error pointers were used.
+    C2Pointers,
+    /// Read offset correction is not set.
+    NoReadOffset,
+    /// Gaps were not detected.
+    GapsNotDetected,
+    /// Rip of a range rather than individual tracks.
+    RangeRip,
+    /// Test and copy was not used.
+    NoTestAndCopy,
+    /// Number of tracks with a test CRC that differs from the copy CRC.
+    CrcMismatch(usize),
+    /// Number of tracks with suspicious positions or read errors.
+    ReadErrors(usize),
+    /// The log has no checksum or signature.
+    NoChecksum,
+}
+
+impl LogDeduction {
+    /// Points deducted from [`MAX_LOG_SCORE`].
+    #[must_use]
+    pub(crate) fn get_points(self) -> u32 {
+        match self {
+            LogDeduction::NotSecure | LogDeduction::ReadErrors(_) => 20,
+            LogDeduction::AudioCacheNotDefeated
+            | LogDeduction::C2Pointers
+            | LogDeduction::GapsNotDetected
+            | LogDeduction::NoTestAndCopy => 10,
+            LogDeduction::NoReadOffset => 5,
+            LogDeduction::RangeRip | LogDeduction::CrcMismatch(_) => 30,
+            LogDeduction::NoChecksum => 15,
+        }
+    }
+}
+
+impl Display for LogDeduction {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            LogDeduction::NotSecure => write!(formatter, "Rip was not done in secure mode"),
+            LogDeduction::AudioCacheNotDefeated => write!(formatter, "Audio cache not defeated"),
+            LogDeduction::C2Pointers => write!(formatter, "C2 pointers were used"),
+            LogDeduction::NoReadOffset => write!(formatter, "Read offset correction not set"),
+            LogDeduction::GapsNotDetected => write!(formatter, "Gaps not detected"),
+            LogDeduction::RangeRip => write!(formatter, "Range rip"),
+            LogDeduction::NoTestAndCopy => write!(formatter, "Test and copy was not used"),
+            LogDeduction::CrcMismatch(count) => {
+                write!(formatter, "CRC mismatch on {}", pluralize(*count, "track"))
+            }
+            LogDeduction::ReadErrors(count) => {
+                write!(formatter, "Read errors on {}", pluralize(*count, "track"))
+            }
+            LogDeduction::NoChecksum => write!(formatter, "No log checksum"),
+        }
+    }
+}
+
+/// Score of a rip log with the reasons for each deduction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct LogScore {
+    /// Score from `0` to [`MAX_LOG_SCORE`].
+    pub score: u32,
+    /// Reasons for the deductions.
+    pub deductions: Vec<LogDeduction>,
+}
+
+impl LogScore {
+    /// Score a rip log.
+    #[must_use]
+    pub(crate) fn from_log(log: &RipLog) -> Self {
+        let deductions = get_deductions(log);
+        let total: u32 = deductions
+            .iter()
+            .map(|deduction| deduction.get_points())
+            .sum();
+        Self {
+            score: MAX_LOG_SCORE.saturating_sub(total),
+            deductions,
+        }
+    }
+}
+
+fn get_deductions(log: &RipLog) -> Vec<LogDeduction> {
+    let mut deductions = Vec::new();
+    if !log.is_secure() {
+        deductions.push(LogDeduction::NotSecure);
+    }
+    if log.defeat_audio_cache != Some(true) {
+        deductions.push(LogDeduction::AudioCacheNotDefeated);
+    }
+    if log.c2_pointers == Some(true) {
+        deductions.push(LogDeduction::C2Pointers);
+    }
+    if log.read_offset.is_none() {
+        deductions.push(LogDeduction::NoReadOffset);
+    }
+    if log.is_range_rip() {
+        deductions.push(LogDeduction::RangeRip);
+    } else if !log.is_gap_detected() {
+        deductions.push(LogDeduction::GapsNotDetected);
+    }
+    if log.tracks.iter().any(|track| track.test_crc.is_none()) {
+        deductions.push(LogDeduction::NoTestAndCopy);
+    }
+    let mismatches = log
+        .tracks
+        .iter()
+        .filter(|track| track.is_crc_mismatch())
+        .count();
+    if mismatches > 0 {
+        deductions.push(LogDeduction::CrcMismatch(mismatches));
+    }
+    let errors = log.tracks.iter().filter(|track| track.errors > 0).count();
+    if errors > 0 {
+        deductions.push(LogDeduction::ReadErrors(errors));
+    }
+    if log.checksum.is_none() {
+        deductions.push(LogDeduction::NoChecksum);
+    }
+    deductions
+}
+
+fn pluralize(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{count} {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
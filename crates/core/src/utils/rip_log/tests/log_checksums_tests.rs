@@ -0,0 +1,111 @@
+use super::rip_log_tests::EAC_LOG;
+use crate::testing_prelude::*;
+
+/// Source directory of the FLACs and log.
+const SOURCE_DIR: &str = "/content/Artist - Album";
+
+fn get_flacs() -> Vec<FlacFile> {
+    let source_dir = PathBuf::from(SOURCE_DIR);
+    ["01 - Track.flac", "02 - Track.flac", "CD2/01 - Track.flac"]
+        .iter()
+        .map(|name| FlacFile::new(source_dir.join(name), &source_dir))
+        .collect()
+}
+
+#[test]
+fn log_checksums_from_log_matches_file_names() {
+    // Arrange
+    let log = RipLog::parse(EAC_LOG).expect("should parse");
+    let path = PathBuf::from(SOURCE_DIR).join("Album.log");
+
+    // Act
+    let output = LogChecksums::from_log(&path, &log, &get_flacs());
+
+    // Assert
+    let source_dir = PathBuf::from(SOURCE_DIR);
+    assert_eq!(
+        output,
+        vec![
+            (
+                source_dir.join("01 - Track.flac"),
+                LogChecksums {
+                    log: path.clone(),
+                    track: 1,
+                    is_first: true,
+                    is_last: false,
+                    crc32: Some(0x8A3B_1C2D),
+                    null_samples: true,
+                    accurate_rip_v1: None,
+                    accurate_rip_v2: Some(0xAB12_CD34),
+                },
+            ),
+            (
+                source_dir.join("02 - Track.flac"),
+                LogChecksums {
+                    log: path,
+                    track: 2,
+                    is_first: false,
+                    is_last: true,
+                    crc32: Some(0x2222_2222),
+                    null_samples: true,
+                    accurate_rip_v1: None,
+                    accurate_rip_v2: Some(0x1234_5678),
+                },
+            ),
+        ]
+    );
+}
+
+#[test]
+fn log_checksums_from_log_only_matches_log_directory() {
+    // Arrange
+    let log = RipLog::parse(EAC_LOG).expect("should parse");
+    let path = PathBuf::from(SOURCE_DIR).join("CD2").join("Album.log");
+
+    // Act
+    let output = LogChecksums::from_log(&path, &log, &get_flacs());
+
+    // Assert
+    let paths: Vec<PathBuf> = output.into_iter().map(|(path, _)| path).collect();
+    assert_eq!(
+        paths,
+        vec![PathBuf::from(SOURCE_DIR).join("CD2/01 - Track.flac")]
+    );
+}
+
+#[test]
+fn log_checksums_compare() {
+    // Arrange
+    let checksums = LogChecksums {
+        log: PathBuf::from("/content/Album.log"),
+        track: 2,
+        is_first: false,
+        is_last: false,
+        crc32: Some(0x1111_1111),
+        null_samples: false,
+        accurate_rip_v1: None,
+        accurate_rip_v2: Some(0x3333_3333),
+    };
+    let actual = RipChecksums {
+        crc32: 0x2222_2222,
+        crc32_skip_zero: 0x1111_1111,
+        accurate_rip_v1: 0x4444_4444,
+        accurate_rip_v2: 0x5555_5555,
+    };
+    let path = PathBuf::from("/content/02 - Track.flac");
+
+    // Act
+    let issue = checksums.compare(&path, &actual);
+
+    // Assert
+    assert_eq!(
+        issue,
+        Some(SourceIssue::ChecksumMismatch {
+            path,
+            log: PathBuf::from("/content/Album.log"),
+            checksum: "AccurateRip v2".to_owned(),
+            expected: "33333333".to_owned(),
+            actual: "55555555".to_owned(),
+        })
+    );
+}
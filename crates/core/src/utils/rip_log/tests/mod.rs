@@ -0,0 +1,3 @@
+mod log_checksums_tests;
+mod rip_log_score_tests;
+mod rip_log_tests;
@@ -0,0 +1,92 @@
+use super::rip_log_tests::{EAC_LOG, XLD_LOG};
+use crate::testing_prelude::*;
+
+#[test]
+fn log_score_xld_perfect() {
+    // Arrange
+    let log = RipLog::parse(XLD_LOG).expect("should parse");
+
+    // Act
+    let score = LogScore::from_log(&log);
+
+    // Assert
+    assert_eq!(score.score, MAX_LOG_SCORE);
+    assert!(score.deductions.is_empty());
+}
+
+#[test]
+fn log_score_eac_errors() {
+    // Arrange
+    let log = RipLog::parse(EAC_LOG).expect("should parse");
+
+    // Act
+    let score = LogScore::from_log(&log);
+
+    // Assert
+    assert_eq!(
+        score.deductions,
+        vec![LogDeduction::CrcMismatch(1), LogDeduction::ReadErrors(1)]
+    );
+    assert_eq!(score.score, 50);
+}
+
+#[test]
+fn log_score_insecure_settings() {
+    // Arrange
+    let text = EAC_LOG
+        .replace(
+            "Read mode               : Secure",
+            "Read mode               : Burst",
+        )
+        .replace(
+            "Defeat audio cache      : Yes",
+            "Defeat audio cache      : No",
+        )
+        .replace(
+            "Make use of C2 pointers : No",
+            "Make use of C2 pointers : Yes",
+        )
+        .replace("Read offset correction                      : 30\n", "")
+        .replace(
+            "Appended to previous track",
+            "Not detected, thus appended to previous track",
+        )
+        .replace("     Test CRC 8A3B1C2D\n", "")
+        .replace("==== Log checksum", "");
+    let log = RipLog::parse(&text).expect("should parse");
+
+    // Act
+    let score = LogScore::from_log(&log);
+
+    // Assert
+    let deductions: Vec<String> = score.deductions.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        deductions,
+        vec![
+            "Rip was not done in secure mode",
+            "Audio cache not defeated",
+            "C2 pointers were used",
+            "Read offset correction not set",
+            "Gaps not detected",
+            "Test and copy was not used",
+            "CRC mismatch on 1 track",
+            "Read errors on 1 track",
+            "No log checksum",
+        ]
+    );
+    assert_eq!(score.score, 0);
+}
+
+#[test]
+fn log_score_range_rip() {
+    // Arrange
+    let text = XLD_LOG.replace("Track 01\n", "");
+    let log = RipLog::parse(&text).expect("should parse");
+
+    // Act
+    let score = LogScore::from_log(&log);
+
+    // Assert
+    assert_eq!(score.deductions, vec![LogDeduction::RangeRip]);
+    assert_eq!(score.score, 70);
+}
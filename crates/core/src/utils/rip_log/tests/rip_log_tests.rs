@@ -0,0 +1,225 @@
+use crate::testing_prelude::*;
+
+/// EAC log of two tracks with a CRC mismatch and a suspicious position on track 2.
+pub(crate) const EAC_LOG: &str = "Exact Audio Copy V1.6 from 23. October 2020
+
+EAC extraction logfile from 1. January 2021, 12:00
+
+Artist / Album
+
+Used drive  : PLEXTOR DVDR   PX-716A   Adapter: 1  ID: 0
+
+Read mode               : Secure
+Utilize accurate stream : Yes
+Defeat audio cache      : Yes
+Make use of C2 pointers : No
+
+Read offset correction                      : 30
+Overread into Lead-In and Lead-Out          : No
+Fill up missing offset samples with silence : Yes
+Delete leading and trailing silent blocks   : No
+Null samples used in CRC calculations       : Yes
+Used interface                              : Native Win32 interface for Win NT & 2000
+Gap handling                                : Appended to previous track
+
+Track  1
+
+     Filename C:\\Rips\\01 - Track.wav
+
+     Pre-gap length  0:00:02.00
+
+     Peak level 98.0 %
+     Extraction speed 4.5 X
+     Track quality 100.0 %
+     Test CRC 8A3B1C2D
+     Copy CRC 8A3B1C2D
+     Accurately ripped (confidence 5)  [AB12CD34]  (AR v2)
+     Copy OK
+
+Track  2
+
+     Filename C:\\Rips\\02 - Track.wav
+
+     Suspicious position 0:02:20
+
+     Peak level 87.5 %
+     Extraction speed 2.1 X
+     Track quality 97.3 %
+     Test CRC 11111111
+     Copy CRC 22222222
+     Cannot be verified as accurate (confidence 3)  [12345678], AccurateRip returned [87654321]  (AR v2)
+     Copy finished
+
+There were errors
+
+End of status report
+
+==== Log checksum 7F6D1C2B3A4E5F60718293A4B5C6D7E8F9011223344556677889900AABBCCDDE ====
+";
+
+/// XLD log of a single accurately ripped track.
+pub(crate) const XLD_LOG: &str = "X Lossless Decoder version 20230627 (155.2)
+
+XLD extraction logfile from 2021-01-01 12:00:00 +0000
+
+Artist / Album
+
+Used drive : PIONEER BD-RW   BDR-XD05 (revision 1.10)
+Media type : Pressed CD
+
+Ripper mode             : XLD Secure Ripper
+Disable audio cache     : OK
+Make use of C2 pointers : NO
+Read offset correction  : 667
+Max retry count         : 20
+Gap status              : Analyzed, Appended
+
+AccurateRip Summary
+    Track 01 : OK (A1+A2, v1 confidence 5/8, v2 confidence 3/8)
+
+Track 01
+    Filename : /Users/user/Music/01 - Track.flac
+    Pre-gap length : 00:02:00
+
+    CRC32 hash (test run)  : 8A3B1C2D
+    CRC32 hash             : 8A3B1C2D
+    CRC32 hash (skip zero) : 1A2B3C4D
+    AccurateRip v1 signature : 0A1B2C3D
+        ->Rip may not be accurate.
+    AccurateRip v2 signature : 4E5F6A7B
+        ->Accurately ripped (v2, confidence 3/8)
+    Statistics
+        Read error                           : 0
+        Jitter error (maybe fixed)           : 0
+        Retry sector count                   : 0
+        Damaged sector count                 : 0
+
+No errors occurred
+
+End of status report
+
+-----BEGIN XLD SIGNATURE-----
+ABCDEFGHIJKLMNOPQRSTUVWXYZ
+-----END XLD SIGNATURE-----
+";
+
+#[test]
+fn rip_log_parse_eac() {
+    // Arrange
+    // Act
+    let log = RipLog::parse(EAC_LOG).expect("should parse");
+
+    // Assert
+    assert_eq!(log.ripper, Ripper::Eac);
+    assert_eq!(log.drive.as_deref(), Some("PLEXTOR DVDR   PX-716A"));
+    assert_eq!(log.read_offset, Some(30));
+    assert_eq!(log.read_mode.as_deref(), Some("Secure"));
+    assert_eq!(log.defeat_audio_cache, Some(true));
+    assert_eq!(log.c2_pointers, Some(false));
+    assert_eq!(log.null_samples, Some(true));
+    assert_eq!(
+        log.gap_handling.as_deref(),
+        Some("Appended to previous track")
+    );
+    assert_eq!(
+        log.checksum.as_deref(),
+        Some("7F6D1C2B3A4E5F60718293A4B5C6D7E8F9011223344556677889900AABBCCDDE")
+    );
+    assert_eq!(
+        log.tracks,
+        vec![
+            RipLogTrack {
+                number: 1,
+                filename: Some("01 - Track".to_owned()),
+                test_crc: Some(0x8A3B_1C2D),
+                copy_crc: Some(0x8A3B_1C2D),
+                accurate_rip: Some(AccurateRipStatus::Accurate { confidence: 5 }),
+                accurate_rip_v1: None,
+                accurate_rip_v2: Some(0xAB12_CD34),
+                errors: 0,
+            },
+            RipLogTrack {
+                number: 2,
+                filename: Some("02 - Track".to_owned()),
+                test_crc: Some(0x1111_1111),
+                copy_crc: Some(0x2222_2222),
+                accurate_rip: Some(AccurateRipStatus::Inaccurate),
+                accurate_rip_v1: None,
+                accurate_rip_v2: Some(0x1234_5678),
+                errors: 1,
+            },
+        ]
+    );
+    assert!(log.is_secure());
+    assert!(log.is_gap_detected());
+    assert!(!log.is_range_rip());
+}
+
+#[test]
+fn rip_log_parse_xld() {
+    // Arrange
+    // Act
+    let log = RipLog::parse(XLD_LOG).expect("should parse");
+
+    // Assert
+    assert_eq!(log.ripper, Ripper::Xld);
+    assert_eq!(
+        log.drive.as_deref(),
+        Some("PIONEER BD-RW   BDR-XD05 (revision 1.10)")
+    );
+    assert_eq!(log.read_offset, Some(667));
+    assert_eq!(log.defeat_audio_cache, Some(true));
+    assert_eq!(log.c2_pointers, Some(false));
+    assert_eq!(log.checksum.as_deref(), Some("ABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+    assert_eq!(
+        log.tracks,
+        vec![RipLogTrack {
+            number: 1,
+            filename: Some("01 - Track".to_owned()),
+            test_crc: Some(0x8A3B_1C2D),
+            copy_crc: Some(0x8A3B_1C2D),
+            accurate_rip: Some(AccurateRipStatus::Accurate { confidence: 3 }),
+            accurate_rip_v1: Some(0x0A1B_2C3D),
+            accurate_rip_v2: Some(0x4E5F_6A7B),
+            errors: 0,
+        }]
+    );
+    assert!(log.is_secure());
+    assert!(log.is_gap_detected());
+}
+
+#[test]
+fn rip_log_parse_range_rip() {
+    // Arrange
+    let text = "Exact Audio Copy V1.0 beta 3 from 29. August 2011
+
+Read mode               : Burst
+
+Range status and errors
+
+Selected range
+
+     Test CRC 8A3B1C2D
+     Copy CRC 8A3B1C2D
+";
+
+    // Act
+    let log = RipLog::parse(text).expect("should parse");
+
+    // Assert
+    assert!(log.is_range_rip());
+    assert!(!log.is_secure());
+    assert_eq!(log.tracks.len(), 1);
+}
+
+#[test]
+fn rip_log_parse_unknown() {
+    // Arrange
+    let text = "dBpoweramp Release 17 Digital Audio Extraction Log\n";
+
+    // Act
+    let log = RipLog::parse(text);
+
+    // Assert
+    assert_eq!(log, None);
+}
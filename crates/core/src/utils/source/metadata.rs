@@ -2,7 +2,8 @@ use crate::prelude::*;
 
 const MAX_ARTISTS: usize = 2;
 const UNKNOWN_ARTIST: &str = "Unknown Artist";
-const VARIOUS_ARTISTS: &str = "Various Artists";
+/// Artist of a source with too many main artists to name.
+pub(crate) const VARIOUS_ARTISTS: &str = "Various Artists";
 
 /// Album metadata extracted from the API.
 #[derive(Clone, Debug)]
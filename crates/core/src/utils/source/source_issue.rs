@@ -146,6 +146,167 @@ pub enum SourceIssue {
         path: PathBuf,
         count: u32,
     },
+    /// Low-order bits of every sample are zero.
+    ///
+    /// The FLAC is labeled with a higher bit depth than it was mastered at.
+    PaddedBitDepth {
+        path: PathBuf,
+        bit_depth: u32,
+        effective: u32,
+    },
+    /// No content above the Nyquist frequency of a CD or DAT master.
+    ///
+    /// The FLAC was upsampled from 44.1 or 48 kHz.
+    Upsampled {
+        path: PathBuf,
+        rate: u32,
+    },
+    /// Lowpass shelf typical of an MP3 or AAC encoder.
+    ///
+    /// The FLAC was likely transcoded from a lossy source.
+    PossibleLossyMaster {
+        path: PathBuf,
+        cutoff: u32,
+    },
+    /// MQA encoded FLAC.
+    ///
+    /// Detected by an MQA Vorbis comment or the MQA sync word in the low bits of the samples.
+    Mqa {
+        path: PathBuf,
+        details: String,
+    },
+    /// EAC or XLD rip log scored less than 100.
+    ///
+    /// The settings or results of the rip are below the standard of the tracker.
+    LogScore {
+        path: PathBuf,
+        score: u32,
+        deductions: Vec<String>,
+    },
+    /// Test and copy CRCs of a track in a rip log differ.
+    ///
+    /// The two reads of the track did not match so the rip may contain errors.
+    CrcMismatch {
+        path: PathBuf,
+        track: u32,
+        test: String,
+        copy: String,
+    },
+    /// Checksum calculated from the audio of a FLAC differs from its rip log.
+    ///
+    /// The FLAC was re-encoded or edited after ripping.
+    ChecksumMismatch {
+        path: PathBuf,
+        log: PathBuf,
+        checksum: String,
+        expected: String,
+        actual: String,
+    },
+    /// File referenced by a cue sheet has no matching FLAC.
+    CueMissingFile {
+        path: PathBuf,
+        file: String,
+    },
+    /// Number of audio tracks in a cue sheet differs from the FLACs in its directory.
+    CueTrackCount {
+        path: PathBuf,
+        expected: usize,
+        actual: usize,
+    },
+    /// Index of a cue sheet is beyond the end of the FLAC of its file.
+    ///
+    /// The gaps of the cue sheet do not match how the tracks were split.
+    CueIndexBeyondEnd {
+        path: PathBuf,
+        track: u32,
+        index: u32,
+        time: String,
+        file: String,
+        duration: String,
+    },
+    /// Cue sheet has audio before track 1 that was not ripped.
+    HiddenTrack {
+        path: PathBuf,
+        length: String,
+    },
+    /// FLAC image split by its cue sheet is not 16-bit CD audio.
+    ImageBitDepth {
+        path: PathBuf,
+        bit_depth: u32,
+    },
+    /// Tracks of a cue sheet, or a FLAC by its tag, are flagged with pre-emphasis.
+    ///
+    /// The audio must be de-emphasized before transcoding.
+    PreEmphasis {
+        path: PathBuf,
+        tracks: Vec<u32>,
+    },
+    /// File referenced by a checksum manifest does not exist.
+    ManifestMissingFile {
+        path: PathBuf,
+        file: String,
+    },
+    /// Checksum of a file differs from its checksum manifest.
+    ///
+    /// The file was edited or corrupted after the manifest was created.
+    ManifestMismatch {
+        path: PathBuf,
+        manifest: PathBuf,
+        checksum: String,
+        expected: String,
+        actual: String,
+    },
+    /// Tag has different values across the FLACs of the album.
+    InconsistentTags {
+        tag: String,
+        values: Vec<String>,
+    },
+    /// Track number of a disc is tagged on more than one FLAC.
+    DuplicateTrackNumber {
+        disc: u32,
+        track: u32,
+        paths: Vec<PathBuf>,
+    },
+    /// Track numbers of a disc have gaps.
+    ///
+    /// The tags are wrong or the tracks are missing.
+    MissingTrackNumbers {
+        disc: u32,
+        tracks: Vec<u32>,
+    },
+    /// Track total of a disc differs from its number of FLACs.
+    TrackTotal {
+        disc: u32,
+        total: u32,
+        actual: usize,
+    },
+    /// Disc total differs from the number of discs with FLACs.
+    DiscTotal {
+        total: u32,
+        actual: usize,
+    },
+    /// Discs up to the highest disc number have no FLACs.
+    ///
+    /// The disc numbers are wrong or the discs are missing.
+    MissingDiscs {
+        discs: Vec<u32>,
+        disc_count: u32,
+    },
+    /// Tag differs from the metadata of the indexer.
+    ///
+    /// Transcodes with tags that don't match the indexer are likely to be reported.
+    TagMismatch {
+        path: PathBuf,
+        tag: String,
+        expected: String,
+        actual: String,
+        similarity: u32,
+    },
+    /// Bit depth or sample rate differs across the FLACs of the album.
+    MixedFormat {
+        property: String,
+        values: Vec<u32>,
+    },
     Error {
         domain: String,
         details: String,
@@ -269,6 +430,190 @@ impl SourceIssue {
             MissingMd5 { path } => {
                 format!("Missing MD5 signature{}", format_path(path, styled))
             }
+            PaddedBitDepth {
+                path,
+                bit_depth,
+                effective,
+            } => {
+                format!(
+                    "Padded bit depth: {bit_depth} bit with {effective} bits used{}",
+                    format_path(path, styled)
+                )
+            }
+            Upsampled { path, rate } => {
+                format!(
+                    "Upsampled: {rate} Hz with no content above 24 kHz{}",
+                    format_path(path, styled)
+                )
+            }
+            PossibleLossyMaster { path, cutoff } => {
+                format!(
+                    "Possible lossy master: lowpass at {:.1} kHz{}",
+                    f64::from(*cutoff) / 1000.0,
+                    format_path(path, styled)
+                )
+            }
+            Mqa { path, details } => {
+                format!("MQA encoded: {details}{}", format_path(path, styled))
+            }
+            LogScore {
+                path,
+                score,
+                deductions,
+            } => {
+                format!(
+                    "Log scored {score}: {}{}",
+                    deductions.join(", "),
+                    format_path(path, styled)
+                )
+            }
+            CrcMismatch {
+                path,
+                track,
+                test,
+                copy,
+            } => {
+                format!(
+                    "Test CRC {test} does not match copy CRC {copy} for track {track}{}",
+                    format_path(path, styled)
+                )
+            }
+            ChecksumMismatch {
+                path,
+                log,
+                checksum,
+                expected,
+                actual,
+            } => {
+                let log = log.file_name().unwrap_or_default().to_string_lossy();
+                format!(
+                    "{checksum} {actual} does not match {expected} in {log}{}",
+                    format_path(path, styled)
+                )
+            }
+            CueMissingFile { path, file } => {
+                format!(
+                    "Cue sheet references {file} which has no matching FLAC{}",
+                    format_path(path, styled)
+                )
+            }
+            CueTrackCount {
+                path,
+                expected,
+                actual,
+            } => {
+                format!(
+                    "Cue sheet has {expected} audio tracks but {actual} FLACs were found{}",
+                    format_path(path, styled)
+                )
+            }
+            CueIndexBeyondEnd {
+                path,
+                track,
+                index,
+                time,
+                file,
+                duration,
+            } => {
+                format!(
+                    "INDEX {index:02} of track {track} at {time} is beyond the end of {file} ({duration}){}",
+                    format_path(path, styled)
+                )
+            }
+            HiddenTrack { path, length } => {
+                format!(
+                    "Hidden track one audio of {length} before track 1 was not ripped{}",
+                    format_path(path, styled)
+                )
+            }
+            ImageBitDepth { path, bit_depth } => {
+                format!(
+                    "FLAC image is {bit_depth}-bit but only 16-bit images can be split{}",
+                    format_path(path, styled)
+                )
+            }
+            PreEmphasis { path, tracks } if tracks.is_empty() => {
+                format!(
+                    "Pre-emphasis is flagged so de-emphasis is required{}",
+                    format_path(path, styled)
+                )
+            }
+            PreEmphasis { path, tracks } => {
+                let noun = if tracks.len() == 1 { "track" } else { "tracks" };
+                format!(
+                    "Pre-emphasis is flagged on {noun} {} so de-emphasis is required{}",
+                    join_numbers(tracks),
+                    format_path(path, styled)
+                )
+            }
+            ManifestMissingFile { path, file } => {
+                format!(
+                    "Checksum manifest references {file} which does not exist{}",
+                    format_path(path, styled)
+                )
+            }
+            ManifestMismatch {
+                path,
+                manifest,
+                checksum,
+                expected,
+                actual,
+            } => {
+                let manifest = manifest.file_name().unwrap_or_default().to_string_lossy();
+                format!(
+                    "{checksum} {actual} does not match {expected} in {manifest}{}",
+                    format_path(path, styled)
+                )
+            }
+            InconsistentTags { tag, values } => {
+                format!("Inconsistent {tag} tags: {}", values.join(", "))
+            }
+            DuplicateTrackNumber { disc, track, paths } => {
+                format!(
+                    "Track {track} of disc {disc} is tagged on {} FLACs",
+                    paths.len()
+                )
+            }
+            MissingTrackNumbers { disc, tracks } => {
+                let noun = if tracks.len() == 1 { "track" } else { "tracks" };
+                format!("Disc {disc} is missing {noun} {}", join_numbers(tracks))
+            }
+            TrackTotal {
+                disc,
+                total,
+                actual,
+            } => {
+                format!("Track total of disc {disc} is {total} but {actual} FLACs were found")
+            }
+            DiscTotal { total, actual } => {
+                format!("Disc total is {total} but {actual} discs were found")
+            }
+            MissingDiscs { discs, disc_count } => {
+                let (noun, verb) = if discs.len() == 1 {
+                    ("Disc", "has")
+                } else {
+                    ("Discs", "have")
+                };
+                format!(
+                    "{noun} {} of {disc_count} {verb} no FLACs",
+                    join_numbers(discs)
+                )
+            }
+            TagMismatch {
+                path,
+                tag,
+                expected,
+                actual,
+                similarity,
+            } => {
+                format!(
+                    "Tag mismatch: {tag} \"{actual}\" is {similarity}% similar to \"{expected}\" on the indexer{}",
+                    format_path(path, styled)
+                )
+            }
+            MixedFormat { property, values } => {
+                format!("Mixed {property} across tracks: {}", join_numbers(values))
+            }
             Error { domain, details } => format!("A {domain} error occurred:\n{details}"),
             Other(details) => details.clone(),
         }
@@ -285,16 +630,36 @@ impl SourceIssue {
                 | SourceIssue::MissingMd5 { .. }
                 | SourceIssue::UnnecessaryDirectory { .. }
                 | SourceIssue::SampleRate { .. }
+                | SourceIssue::PaddedBitDepth { .. }
+                | SourceIssue::Upsampled { .. }
+                | SourceIssue::LogScore { .. }
+                | SourceIssue::CrcMismatch { .. }
+                | SourceIssue::ChecksumMismatch { .. }
+                | SourceIssue::InconsistentTags { .. }
+                | SourceIssue::DuplicateTrackNumber { .. }
+                | SourceIssue::TrackTotal { .. }
+                | SourceIssue::DiscTotal { .. }
         )
     }
 
     /// Suggested tracker report type for this issue.
     pub(crate) fn report_type(&self) -> Option<&'static str> {
         match self {
-            SourceIssue::NoTags { .. } | SourceIssue::MissingTags { .. } => Some("Bad Tags"),
-            SourceIssue::FlacError { .. } | SourceIssue::SampleRate { .. } => Some("Mislabeled"),
+            SourceIssue::NoTags { .. }
+            | SourceIssue::MissingTags { .. }
+            | SourceIssue::InconsistentTags { .. }
+            | SourceIssue::DuplicateTrackNumber { .. }
+            | SourceIssue::TrackTotal { .. }
+            | SourceIssue::DiscTotal { .. } => Some("Bad Tags"),
+            SourceIssue::FlacError { .. }
+            | SourceIssue::SampleRate { .. }
+            | SourceIssue::PaddedBitDepth { .. }
+            | SourceIssue::Upsampled { .. } => Some("Mislabeled"),
             SourceIssue::DecodeError { .. } | SourceIssue::MissingMd5 { .. } => Some("Other"),
-            SourceIssue::UnnecessaryDirectory { .. } => Some("Trumpable"),
+            SourceIssue::UnnecessaryDirectory { .. }
+            | SourceIssue::LogScore { .. }
+            | SourceIssue::CrcMismatch { .. }
+            | SourceIssue::ChecksumMismatch { .. } => Some("Trumpable"),
             _ => None,
         }
     }
@@ -308,8 +673,27 @@ impl SourceIssue {
             | SourceIssue::FlacError { path, .. }
             | SourceIssue::DecodeError { path, .. }
             | SourceIssue::MissingMd5 { path }
-            | SourceIssue::SampleRate { path, .. } => vec![path.as_path()],
+            | SourceIssue::SampleRate { path, .. }
+            | SourceIssue::PaddedBitDepth { path, .. }
+            | SourceIssue::Upsampled { path, .. }
+            | SourceIssue::PossibleLossyMaster { path, .. }
+            | SourceIssue::Mqa { path, .. }
+            | SourceIssue::LogScore { path, .. }
+            | SourceIssue::CrcMismatch { path, .. }
+            | SourceIssue::ChecksumMismatch { path, .. }
+            | SourceIssue::CueMissingFile { path, .. }
+            | SourceIssue::CueTrackCount { path, .. }
+            | SourceIssue::CueIndexBeyondEnd { path, .. }
+            | SourceIssue::HiddenTrack { path, .. }
+            | SourceIssue::ImageBitDepth { path, .. }
+            | SourceIssue::PreEmphasis { path, .. }
+            | SourceIssue::ManifestMissingFile { path, .. }
+            | SourceIssue::ManifestMismatch { path, .. }
+            | SourceIssue::TagMismatch { path, .. } => vec![path.as_path()],
             SourceIssue::UnnecessaryDirectory { prefix } => vec![prefix.as_path()],
+            SourceIssue::DuplicateTrackNumber { paths, .. } => {
+                paths.iter().map(PathBuf::as_path).collect()
+            }
             _ => Vec::new(),
         }
     }
@@ -334,6 +718,11 @@ pub(crate) enum PathStyle {
     None,
 }
 /// Format path as `": /path"`, styled or plain, or empty for [`PathStyle::None`].
+fn join_numbers(numbers: &[u32]) -> String {
+    let numbers: Vec<String> = numbers.iter().map(ToString::to_string).collect();
+    numbers.join(", ")
+}
+
 fn format_path(path: &Path, path_style: PathStyle) -> String {
     match path_style {
         PathStyle::None => String::new(),
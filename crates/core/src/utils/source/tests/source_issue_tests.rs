@@ -230,6 +230,7 @@ fn source_issue_provider_deprecated() -> Result<(), YamlError> {
 }
 
 #[test]
+#[expect(clippy::too_many_lines, reason = "flat enumeration of variants")]
 fn source_issue_is_reportable() {
     let reportable = [
         SourceIssue::NoTags {
@@ -257,6 +258,51 @@ fn source_issue_is_reportable() {
             path: PathBuf::from("/d.flac"),
             rate: 192_000,
         },
+        SourceIssue::PaddedBitDepth {
+            path: PathBuf::from("/d.flac"),
+            bit_depth: 24,
+            effective: 16,
+        },
+        SourceIssue::Upsampled {
+            path: PathBuf::from("/d.flac"),
+            rate: 96_000,
+        },
+        SourceIssue::LogScore {
+            path: PathBuf::from("/d.log"),
+            score: 85,
+            deductions: vec!["No log checksum".to_owned()],
+        },
+        SourceIssue::CrcMismatch {
+            path: PathBuf::from("/d.log"),
+            track: 2,
+            test: "11111111".to_owned(),
+            copy: "22222222".to_owned(),
+        },
+        SourceIssue::ChecksumMismatch {
+            path: PathBuf::from("/d.flac"),
+            log: PathBuf::from("/d.log"),
+            checksum: "CRC32".to_owned(),
+            expected: "11111111".to_owned(),
+            actual: "22222222".to_owned(),
+        },
+        SourceIssue::InconsistentTags {
+            tag: "album".to_owned(),
+            values: vec!["A".to_owned(), "B".to_owned()],
+        },
+        SourceIssue::DuplicateTrackNumber {
+            disc: 1,
+            track: 2,
+            paths: vec![PathBuf::from("/f.flac"), PathBuf::from("/g.flac")],
+        },
+        SourceIssue::TrackTotal {
+            disc: 1,
+            total: 12,
+            actual: 11,
+        },
+        SourceIssue::DiscTotal {
+            total: 2,
+            actual: 1,
+        },
     ];
     let not_reportable = [
         SourceIssue::NotFound,
@@ -273,6 +319,61 @@ fn source_issue_is_reportable() {
             path: PathBuf::from("/e.flac"),
             tags: vec!["track_number".to_owned()],
         },
+        SourceIssue::PossibleLossyMaster {
+            path: PathBuf::from("/e.flac"),
+            cutoff: 16_000,
+        },
+        SourceIssue::CueMissingFile {
+            path: PathBuf::from("/e.cue"),
+            file: "01.wav".to_owned(),
+        },
+        SourceIssue::CueTrackCount {
+            path: PathBuf::from("/e.cue"),
+            expected: 12,
+            actual: 11,
+        },
+        SourceIssue::HiddenTrack {
+            path: PathBuf::from("/e.cue"),
+            length: "00:32:15".to_owned(),
+        },
+        SourceIssue::PreEmphasis {
+            path: PathBuf::from("/e.cue"),
+            tracks: vec![1],
+        },
+        SourceIssue::ManifestMissingFile {
+            path: PathBuf::from("/e.md5"),
+            file: "01.flac".to_owned(),
+        },
+        SourceIssue::ManifestMismatch {
+            path: PathBuf::from("/e.flac"),
+            manifest: PathBuf::from("/e.sfv"),
+            checksum: "CRC32".to_owned(),
+            expected: "11111111".to_owned(),
+            actual: "22222222".to_owned(),
+        },
+        SourceIssue::MissingTrackNumbers {
+            disc: 1,
+            tracks: vec![3],
+        },
+        SourceIssue::MissingDiscs {
+            discs: vec![2],
+            disc_count: 3,
+        },
+        SourceIssue::MixedFormat {
+            property: "bit depth".to_owned(),
+            values: vec![16, 24],
+        },
+        SourceIssue::TagMismatch {
+            path: PathBuf::from("/e.flac"),
+            tag: "album".to_owned(),
+            expected: "A".to_owned(),
+            actual: "B".to_owned(),
+            similarity: 0,
+        },
+        SourceIssue::Mqa {
+            path: PathBuf::from("/e.flac"),
+            details: "MQAENCODER tag".to_owned(),
+        },
     ];
     for issue in reportable {
         assert!(issue.is_reportable(), "expected reportable: {issue:?}");
@@ -377,6 +478,171 @@ fn source_issue_render_invalid_torrent() {
     );
 }
 
+#[test]
+fn source_issue_render_cue_issues() {
+    // Arrange
+    let issues = [
+        SourceIssue::CueIndexBeyondEnd {
+            path: PathBuf::from("/a.cue"),
+            track: 3,
+            index: 0,
+            time: "04:02:50".to_owned(),
+            file: "02.wav".to_owned(),
+            duration: "03:59:12".to_owned(),
+        },
+        SourceIssue::PreEmphasis {
+            path: PathBuf::from("/a.cue"),
+            tracks: vec![1, 3],
+        },
+        SourceIssue::ImageBitDepth {
+            path: PathBuf::from("/a.flac"),
+            bit_depth: 24,
+        },
+    ];
+
+    // Act
+    let output: Vec<String> = issues
+        .iter()
+        .map(|issue| issue.render(PathStyle::None))
+        .collect();
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![
+            "INDEX 00 of track 3 at 04:02:50 is beyond the end of 02.wav (03:59:12)",
+            "Pre-emphasis is flagged on tracks 1, 3 so de-emphasis is required",
+            "FLAC image is 24-bit but only 16-bit images can be split",
+        ]
+    );
+}
+
+#[test]
+fn source_issue_render_manifest_issues() {
+    // Arrange
+    let issues = [
+        SourceIssue::ManifestMissingFile {
+            path: PathBuf::from("/a.ffp"),
+            file: "CD2/01.flac".to_owned(),
+        },
+        SourceIssue::ManifestMismatch {
+            path: PathBuf::from("/01.flac"),
+            manifest: PathBuf::from("/a.sfv"),
+            checksum: "CRC32".to_owned(),
+            expected: "11111111".to_owned(),
+            actual: "22222222".to_owned(),
+        },
+    ];
+
+    // Act
+    let output: Vec<String> = issues
+        .iter()
+        .map(|issue| issue.render(PathStyle::None))
+        .collect();
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![
+            "Checksum manifest references CD2/01.flac which does not exist",
+            "CRC32 22222222 does not match 11111111 in a.sfv",
+        ]
+    );
+}
+
+#[test]
+fn source_issue_render_album_issues() {
+    // Arrange
+    let issues = [
+        SourceIssue::InconsistentTags {
+            tag: "album artist".to_owned(),
+            values: vec!["A".to_owned(), "B".to_owned()],
+        },
+        SourceIssue::DuplicateTrackNumber {
+            disc: 1,
+            track: 2,
+            paths: vec![PathBuf::from("/02.flac"), PathBuf::from("/02 (1).flac")],
+        },
+        SourceIssue::MissingTrackNumbers {
+            disc: 2,
+            tracks: vec![3, 4],
+        },
+        SourceIssue::TrackTotal {
+            disc: 1,
+            total: 12,
+            actual: 11,
+        },
+        SourceIssue::DiscTotal {
+            total: 2,
+            actual: 1,
+        },
+        SourceIssue::MissingDiscs {
+            discs: vec![2],
+            disc_count: 3,
+        },
+        SourceIssue::MixedFormat {
+            property: "bit depth".to_owned(),
+            values: vec![16, 24],
+        },
+    ];
+
+    // Act
+    let output: Vec<String> = issues
+        .iter()
+        .map(|issue| issue.render(PathStyle::None))
+        .collect();
+
+    // Assert
+    assert_eq!(
+        output,
+        vec![
+            "Inconsistent album artist tags: A, B",
+            "Track 2 of disc 1 is tagged on 2 FLACs",
+            "Disc 2 is missing tracks 3, 4",
+            "Track total of disc 1 is 12 but 11 FLACs were found",
+            "Disc total is 2 but 1 discs were found",
+            "Disc 2 of 3 has no FLACs",
+            "Mixed bit depth across tracks: 16, 24",
+        ]
+    );
+}
+
+#[test]
+fn source_issue_render_tag_mismatch() {
+    // Arrange
+    let issue = SourceIssue::TagMismatch {
+        path: PathBuf::from("/01.flac"),
+        tag: "album".to_owned(),
+        expected: "Bridge Over Troubled Water".to_owned(),
+        actual: "Bookends".to_owned(),
+        similarity: 24,
+    };
+
+    // Act
+    let output = issue.render(PathStyle::None);
+
+    // Assert
+    assert_eq!(
+        output,
+        "Tag mismatch: album \"Bookends\" is 24% similar to \"Bridge Over Troubled Water\" on the indexer"
+    );
+}
+
+#[test]
+fn source_issue_render_mqa() {
+    // Arrange
+    let issue = SourceIssue::Mqa {
+        path: PathBuf::from("/01.flac"),
+        details: "sync word in bit 15".to_owned(),
+    };
+
+    // Act
+    let output = issue.render(PathStyle::None);
+
+    // Assert
+    assert_eq!(output, "MQA encoded: sync word in bit 15");
+}
+
 fn report_sample_issues() -> Vec<SourceIssue> {
     vec![
         SourceIssue::NoTags {
@@ -409,6 +675,37 @@ fn report_sample_issues() -> Vec<SourceIssue> {
             path: PathBuf::from("/a.flac"),
             rate: 192_000,
         },
+        SourceIssue::PaddedBitDepth {
+            path: PathBuf::from("/a.flac"),
+            bit_depth: 24,
+            effective: 16,
+        },
+        SourceIssue::Upsampled {
+            path: PathBuf::from("/a.flac"),
+            rate: 96_000,
+        },
+        SourceIssue::PossibleLossyMaster {
+            path: PathBuf::from("/a.flac"),
+            cutoff: 16_000,
+        },
+        SourceIssue::LogScore {
+            path: PathBuf::from("/a.log"),
+            score: 85,
+            deductions: vec!["No log checksum".to_owned()],
+        },
+        SourceIssue::CrcMismatch {
+            path: PathBuf::from("/a.log"),
+            track: 2,
+            test: "11111111".to_owned(),
+            copy: "22222222".to_owned(),
+        },
+        SourceIssue::ChecksumMismatch {
+            path: PathBuf::from("/a.flac"),
+            log: PathBuf::from("/a.log"),
+            checksum: "CRC32".to_owned(),
+            expected: "11111111".to_owned(),
+            actual: "22222222".to_owned(),
+        },
         SourceIssue::UnnecessaryDirectory {
             prefix: PathBuf::from("CD1"),
         },
@@ -3,6 +3,8 @@
 pub(crate) use fs::*;
 #[cfg(test)]
 pub(crate) use latin1::*;
+#[cfg(test)]
+pub(crate) use pcm::*;
 pub(crate) use samples::*;
 #[cfg(test)]
 pub(crate) use snapshots::*;
@@ -15,6 +17,8 @@ pub(crate) use torrent_builder::*;
 mod fs;
 #[cfg(test)]
 mod latin1;
+#[cfg(test)]
+mod pcm;
 mod samples;
 #[cfg(test)]
 mod snapshot_macros;
@@ -0,0 +1,172 @@
+use crate::testing_prelude::*;
+use lofty::config::WriteOptions;
+use lofty::ogg::VorbisComments;
+use lofty::prelude::TagExt;
+use std::io::Cursor;
+
+/// Interleaved 16-bit PCM audio held in memory.
+pub(crate) struct ResampledPcm {
+    /// Number of interleaved channels.
+    pub channels: u32,
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Interleaved 16-bit samples.
+    pub samples: Vec<i16>,
+}
+
+/// Encode 16-bit PCM to FLAC in memory with a [`FlacEncoder`].
+pub(crate) fn encode_flac(pcm: &ResampledPcm) -> Result<Vec<u8>, Failure<TranscodeAction>> {
+    let mut encoder = FlacEncoder::new(Cursor::new(Vec::new()), pcm.channels, pcm.sample_rate)?;
+    encoder.write(&pcm.samples)?;
+    Ok(encoder.finish()?.into_inner())
+}
+
+/// Decode a range of a FLAC to 16-bit PCM in memory with a [`FlacDecoder`].
+pub(crate) fn decode_flac(
+    path: &Path,
+    range: Option<SampleRange>,
+) -> Result<ResampledPcm, Failure<TranscodeAction>> {
+    let mut decoder = FlacDecoder::open(path)?.with_range(range);
+    let info = decoder.stream_info();
+    let mut samples = Vec::new();
+    decoder.for_each_chunk(|chunk| {
+        samples.extend_from_slice(chunk);
+        true
+    })?;
+    Ok(ResampledPcm {
+        channels: info.channels,
+        sample_rate: info.sample_rate,
+        samples,
+    })
+}
+
+/// Builder for writing a FLAC of 16-bit PCM encoded in process with a [`FlacEncoder`].
+///
+/// Unlike [`FlacGenerator`] no external binaries are required, and the exact samples are set.
+///
+/// # Example
+/// ```ignore
+/// let path = PcmFlacGenerator::new()
+///     .with_filename("01 - Track.flac")
+///     .with_samples(samples)
+///     .with_vorbis_tag("TITLE", "Track")
+///     .generate(&dir);
+/// ```
+#[derive(Clone, Debug)]
+pub(crate) struct PcmFlacGenerator {
+    channels: u32,
+    sample_rate: u32,
+    samples: Option<Vec<i16>>,
+    filename: String,
+    tags: Vec<(String, String)>,
+}
+
+impl Default for PcmFlacGenerator {
+    fn default() -> Self {
+        Self {
+            channels: Self::DEFAULT_CHANNELS,
+            sample_rate: Self::DEFAULT_SAMPLE_RATE,
+            samples: None,
+            filename: Self::DEFAULT_FILENAME.to_owned(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl PcmFlacGenerator {
+    // Default values
+    const DEFAULT_CHANNELS: u32 = 2;
+    const DEFAULT_SAMPLE_RATE: u32 = 44_100;
+    const DEFAULT_FRAMES: usize = 1_000;
+    const DEFAULT_FILENAME: &'static str = "track.flac";
+
+    /// Create a new PCM FLAC generator.
+    ///
+    /// Defaults:
+    /// - Channels: 2 (stereo)
+    /// - Sample rate: 44100 Hz
+    /// - Samples: 1000 frames of silence
+    /// - Filename: "track.flac"
+    /// - No Vorbis comments
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of channels (default: 2).
+    #[must_use]
+    pub(crate) fn with_channels(mut self, channels: u32) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Set the sample rate in Hz (default: 44100).
+    #[must_use]
+    pub(crate) fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Set the interleaved samples (default: 1000 frames of silence).
+    #[must_use]
+    pub(crate) fn with_samples(mut self, samples: Vec<i16>) -> Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// Set the filename (default: "track.flac").
+    #[must_use]
+    pub(crate) fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = filename.into();
+        self
+    }
+
+    /// Add a Vorbis comment tag.
+    ///
+    /// The same key may be added more than once for a multi-value tag.
+    #[must_use]
+    pub(crate) fn with_vorbis_tag(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.tags.push((key.into(), value.into()));
+        self
+    }
+
+    /// Write the FLAC to `dir` and return its path.
+    ///
+    /// # Panics
+    /// If the FLAC can't be encoded or written.
+    pub(crate) fn generate(&self, dir: &Path) -> PathBuf {
+        let samples = self.samples.clone().unwrap_or_else(|| {
+            let channels = usize::try_from(self.channels).expect("channels should fit in usize");
+            vec![0; Self::DEFAULT_FRAMES * channels]
+        });
+        let pcm = ResampledPcm {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            samples,
+        };
+        let path = dir.join(&self.filename);
+        write(&path, encode_flac(&pcm).expect("should encode FLAC")).expect("should write FLAC");
+        if !self.tags.is_empty() {
+            let mut vorbis = VorbisComments::default();
+            for (key, value) in &self.tags {
+                vorbis.push(key.clone(), value.clone());
+            }
+            vorbis
+                .save_to_path(&path, WriteOptions::default())
+                .expect("should write tags");
+        }
+        path
+    }
+
+    /// Write the FLAC to `dir` and return it as a [`FlacFile`] of a source in `dir`.
+    ///
+    /// # Panics
+    /// If the FLAC can't be encoded or written.
+    pub(crate) fn generate_flac_file(&self, dir: &Path) -> FlacFile {
+        FlacFile::new(self.generate(dir), &dir.to_path_buf())
+    }
+}
@@ -1,16 +1,21 @@
 //! Snapshot macros for deterministic testing across platforms.
 
 /// Resolve the path to the stored `.snap` file for the calling test function.
+///
+/// Optionally resolve a named snapshot in the same module instead.
 macro_rules! resolve_snap_path {
     () => {{
-        let workspace = insta::_get_workspace_root!();
         let function_name = insta::_function_name!();
         let snap_name = function_name
             .rsplit("::")
             .next()
             .expect("function name should have segments");
+        resolve_snap_path!(snap_name)
+    }};
+    ($name:expr) => {{
+        let workspace = insta::_get_workspace_root!();
         let module_prefix = module_path!().replace("::", "__");
-        let snap_filename = format!("{module_prefix}__{snap_name}.snap");
+        let snap_filename = format!("{module_prefix}__{}.snap", $name);
         workspace
             .join(
                 std::path::Path::new(file!())
@@ -27,6 +32,10 @@ macro_rules! resolve_snap_path {
 /// When `CAESURA_DETERMINISTIC_TESTS` is set, returns the snapshot unchanged for
 /// exact matching. Otherwise, patches SHA-256 and file-size fields from the
 /// stored `.snap` file so only structural differences cause failures.
+///
+/// If a snapshot name is given the fields are always patched from that stored snapshot. This
+/// allows output from an alternative implementation to be compared structurally against the
+/// snapshot of the reference implementation.
 macro_rules! normalize_snapshots {
     ($snapshot:expr) => {
         if is_deterministic() {
@@ -38,6 +47,12 @@ macro_rules! normalize_snapshots {
             files
         }
     };
+    ($snapshot:expr, $name:expr) => {{
+        let mut files = $snapshot;
+        let snap_path = resolve_snap_path!($name);
+        crate::utils::patch_platform_dependent_fields(&mut files, &snap_path);
+        files
+    }};
 }
 
 /// Assert a string snapshot, or verify line count when deterministic tests are disabled.
@@ -238,6 +238,12 @@ fn expand_file_options() {
     assert_snapshot!(expand_options_from_file(source));
 }
 
+#[test]
+fn expand_id3_options() {
+    let source = include_str!("../../core/src/options/id3_options.rs");
+    assert_snapshot!(expand_options_from_file(source));
+}
+
 #[test]
 fn expand_runner_options() {
     let source = include_str!("../../core/src/options/runner_options.rs");